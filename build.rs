@@ -17,28 +17,27 @@ fn main() {
         .expect("Failed to create include directory");
 
     // Configure cbindgen
-    let config = cbindgen::Config {
-        language: cbindgen::Language::C,
-        cpp_compat: true,
-        header: Some(String::from(
-            "/* Deoxys Core FFI - C API for iOS Integration */\n\
-             /* Copyright (c) 2025 Axiom Hive. All rights reserved. */\n\
-             /* \n\
-              * This header provides a C-compatible interface to the Deoxys RIK engine.\n\
-              * Safe to use from Swift, Objective-C, and C/C++.\n\
-              */"
-        )),
-        include_guard: Some(String::from("DEOXYS_CORE_H")),
-        autogen_warning: Some(String::from(
-            "/* Warning: This file is auto-generated by cbindgen. Do not edit manually. */"
-        )),
-        tab_width: 4,
-        line_length: 100,
-        documentation: true,
-        documentation_style: cbindgen::DocumentationStyle::C99,
-        style: cbindgen::Style::Both,
-        ..Default::default()
-    };
+    // `Config` has private fields, so start from the default and assign.
+    let mut config = cbindgen::Config::default();
+    config.language = cbindgen::Language::C;
+    config.cpp_compat = true;
+    config.header = Some(String::from(
+        "/* Deoxys Core FFI - C API for iOS Integration */\n\
+         /* Copyright (c) 2025 Axiom Hive. All rights reserved. */\n\
+         /* \n\
+          * This header provides a C-compatible interface to the Deoxys RIK engine.\n\
+          * Safe to use from Swift, Objective-C, and C/C++.\n\
+          */"
+    ));
+    config.include_guard = Some(String::from("DEOXYS_CORE_H"));
+    config.autogen_warning = Some(String::from(
+        "/* Warning: This file is auto-generated by cbindgen. Do not edit manually. */"
+    ));
+    config.tab_width = 4;
+    config.line_length = 100;
+    config.documentation = true;
+    config.documentation_style = cbindgen::DocumentationStyle::C99;
+    config.style = cbindgen::Style::Both;
 
     cbindgen::Builder::new()
         .with_crate(crate_dir)
@@ -0,0 +1,159 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Signed operator decisions gating cycle execution.
+//!
+//! Before a supervised cycle runs, the engine publishes a [`Proposal`]: the
+//! cycle index and a digest of the action it is about to take. An operator
+//! answers with a [`Decision`] signed over both, so an approval cannot be
+//! replayed for another cycle or a different action. The digest also covers
+//! the engine's provenance key and a nonce drawn at start-up, so a decision
+//! does not carry over to another engine or a later run. Approvals are embedded
+//! in the cycle receipt; denials are recorded in the provenance log.
+
+use crate::canonical::{Domain, Encoder};
+use crate::crypto::{ProvenanceError, ProvenanceSigner, ProvenanceVerifier};
+use serde::{Deserialize, Serialize};
+
+/// Operator's answer to a proposal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Ruling {
+    Approve,
+    Deny,
+}
+
+impl Ruling {
+    /// Stable code used in canonical encodings
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Approve => 1,
+            Self::Deny => 2,
+        }
+    }
+}
+
+/// Cycle the engine is about to execute and a digest of its action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Proposal {
+    pub cycle: u64,
+    pub action: [u8; 32],
+}
+
+/// Operator-signed ruling on a proposal
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Decision {
+    pub cycle: u64,
+    /// Proposed action digest, hex
+    pub action: String,
+    pub ruling: Ruling,
+    /// Operator public key, hex
+    pub operator_key: String,
+    pub signature: String,
+}
+
+impl Decision {
+    fn message(cycle: u64, action: &[u8; 32], ruling: Ruling) -> [u8; 32] {
+        Encoder::new(Domain::Decision)
+            .u64(cycle)
+            .bytes(action)
+            .u8(ruling.code())
+            .digest()
+    }
+
+    pub fn sign(operator: &ProvenanceSigner, proposal: &Proposal, ruling: Ruling) -> Self {
+        let message = Self::message(proposal.cycle, &proposal.action, ruling);
+        Self {
+            cycle: proposal.cycle,
+            action: hex::encode(proposal.action),
+            ruling,
+            operator_key: hex::encode(operator.verifying_key().to_bytes()),
+            signature: operator.sign_digest(&message),
+        }
+    }
+
+    /// Check the signature and return the operator's key
+    pub fn verify(&self) -> Result<ProvenanceVerifier, ProvenanceError> {
+        let invalid = ProvenanceError::BadDecision { cycle: self.cycle };
+        let operator = ProvenanceVerifier::from_hex(&self.operator_key).ok_or(invalid.clone())?;
+        let action: [u8; 32] = hex::decode(&self.action)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(invalid.clone())?;
+        let message = Self::message(self.cycle, &action, self.ruling);
+        if operator.verify_digest(&message, &self.signature) {
+            Ok(operator)
+        } else {
+            Err(invalid)
+        }
+    }
+
+    /// Whether this decision answers `proposal`
+    pub fn answers(&self, proposal: &Proposal) -> bool {
+        self.cycle == proposal.cycle && self.action == hex::encode(proposal.action)
+    }
+
+    /// Append every field to a canonical message that embeds this decision
+    pub(crate) fn encode(&self, encoder: Encoder) -> Encoder {
+        encoder
+            .u64(self.cycle)
+            .str(&self.action)
+            .u8(self.ruling.code())
+            .str(&self.operator_key)
+            .str(&self.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decision_bound_to_cycle_and_action() {
+        let operator = ProvenanceSigner::from_seed(&[9; 32]);
+        let proposal = Proposal {
+            cycle: 4,
+            action: [7; 32],
+        };
+        let approval = Decision::sign(&operator, &proposal, Ruling::Approve);
+        assert_eq!(
+            approval.verify().unwrap().fingerprint(),
+            operator.fingerprint()
+        );
+        assert!(approval.answers(&proposal));
+        assert!(!approval.answers(&Proposal {
+            cycle: 5,
+            ..proposal
+        }));
+        assert!(!approval.answers(&Proposal {
+            action: [8; 32],
+            ..proposal
+        }));
+
+        // Altering any signed field invalidates the signature
+        let bad = ProvenanceError::BadDecision { cycle: 4 };
+        let mut replayed = approval.clone();
+        replayed.cycle = 5;
+        assert_eq!(
+            replayed.verify().unwrap_err(),
+            ProvenanceError::BadDecision { cycle: 5 }
+        );
+        let mut flipped = approval.clone();
+        flipped.ruling = Ruling::Deny;
+        assert_eq!(flipped.verify().unwrap_err(), bad);
+        let mut retargeted = approval.clone();
+        retargeted.action = hex::encode([8; 32]);
+        assert_eq!(retargeted.verify().unwrap_err(), bad);
+        let mut impersonated = approval.clone();
+        impersonated.operator_key = hex::encode(
+            ProvenanceSigner::from_seed(&[1; 32])
+                .verifying_key()
+                .to_bytes(),
+        );
+        assert_eq!(impersonated.verify().unwrap_err(), bad);
+        let mut truncated = approval;
+        truncated.action.pop();
+        assert_eq!(truncated.verify().unwrap_err(), bad);
+    }
+}
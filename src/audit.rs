@@ -0,0 +1,651 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Signed audit bundles for offline verification of a range of cycles.
+//!
+//! An [`AuditBundle`] packages what an auditor needs to check cycles
+//! `first_cycle..=last_cycle` without access to the running system:
+//!
+//! - the trusted root provenance key and a snapshot of the configuration
+//! - the rotation and revocation records preceding the exported segment, so
+//!   the signing key can be followed from the root
+//! - the hash-chained provenance log segment holding the receipts, operator
+//!   decisions, quorum certificates and epoch commitments of the range
+//! - a Merkle inclusion proof for every receipt in the range
+//!
+//! The bundle is signed by the provenance key active at export. Log records
+//! travel as their exact on-disk bodies, so the hash chain, receipt
+//! signatures and epoch roots are all checked against the original bytes.
+//! [`AuditBundle::verify`] never fails; it collects what it finds into an
+//! [`AuditReport`] whose [`AuditStatus`] tells valid, invalid and incomplete
+//! bundles apart.
+
+use crate::canonical::{Domain, Encoder};
+use crate::crypto::{write_file, ProvenanceSigner, ProvenanceVerifier, ReceiptRecord};
+use crate::error::{DeoxysError, Result};
+use crate::ledger::{
+    verify_segment, verify_signatures, verify_signatures_from, LogRecord, RecordKind,
+};
+use crate::merkle::{receipt_leaf, EpochCommitment, InclusionProof, MerkleTree};
+use crate::quorum::Governance;
+use crate::rotation::KeyChain;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const BUNDLE_VERSION: u16 = 1;
+
+/// Operator quorum policy in force at export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuorumSnapshot {
+    pub threshold: u64,
+    /// Operator fingerprints
+    pub operators: Vec<String>,
+    pub window_secs: u64,
+}
+
+/// Engine configuration at export, for the auditor's reference
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigSnapshot {
+    /// Operator output bounds, min and max
+    pub bounds: [f64; 2],
+    /// Lyapunov decrease rate, terminal energy and tolerance
+    pub decrease: [f64; 3],
+    /// Zero Entropy Law policy as configured
+    pub entropy: String,
+    /// Response to defective control law designs
+    pub stability: String,
+    pub ckks_parameters: String,
+    /// Receipts per signed epoch
+    pub epoch_length: u64,
+    /// Fingerprints of the operators whose approval gates each cycle
+    pub operators: Vec<String>,
+    pub quorum: Option<QuorumSnapshot>,
+}
+
+impl ConfigSnapshot {
+    fn encode(&self, encoder: Encoder) -> Encoder {
+        let encoder = encoder
+            .f64s(self.bounds)
+            .f64s(self.decrease)
+            .str(&self.entropy)
+            .str(&self.stability)
+            .str(&self.ckks_parameters)
+            .u64(self.epoch_length)
+            .strs(&self.operators);
+        encoder.option(self.quorum.as_ref(), |encoder, quorum| {
+            encoder.u64(quorum.threshold).strs(&quorum.operators).u64(quorum.window_secs)
+        })
+    }
+}
+
+/// Inclusion of the receipt of `cycle` in the tree committed by `epoch`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptProof {
+    pub cycle: u64,
+    pub epoch: u64,
+    pub proof: InclusionProof,
+}
+
+/// Self-contained, signed evidence for a range of cycles
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditBundle {
+    pub version: u16,
+    pub first_cycle: u64,
+    pub last_cycle: u64,
+    /// Unix seconds
+    pub created_at: u64,
+    /// Provenance key the log started with, hex
+    pub root_key: String,
+    pub config: ConfigSnapshot,
+    /// Rotation, revocation, governance and quorum records preceding the
+    /// segment, hex bodies
+    pub key_history: Vec<String>,
+    /// Sequence number of the first segment record
+    pub start: u64,
+    /// Hash of the record preceding the segment, hex
+    pub previous: String,
+    /// Log segment covering the range, hex bodies
+    pub records: Vec<String>,
+    pub proofs: Vec<ReceiptProof>,
+    /// Fingerprint of the provenance key signing the bundle
+    pub signer: String,
+    pub signature: String,
+}
+
+impl AuditBundle {
+    /// Bundle cycles `first..=last` of the run whose receipts form `tree`.
+    /// `records` is the whole log, `root` the key it started with and
+    /// `signer` the active provenance key.
+    pub fn export(
+        records: &[LogRecord],
+        tree: &MerkleTree,
+        first: u64,
+        last: u64,
+        root: &ProvenanceVerifier,
+        config: ConfigSnapshot,
+        signer: &ProvenanceSigner,
+    ) -> Result<Self> {
+        let fail = |reason: String| DeoxysError::config("audit export", reason);
+        if first > last {
+            return Err(fail(format!("empty cycle range {}..={}", first, last)));
+        }
+        let keys = verify_signatures(records, root)?;
+        if keys.active().fingerprint() != signer.fingerprint() {
+            return Err(fail("the signer is not the active provenance key".into()));
+        }
+        let leaves: HashMap<_, _> = (0..tree.len())
+            .filter_map(|index| tree.leaf(index).map(|leaf| (*leaf, index)))
+            .collect();
+        // Receipts of this run, by position in the log
+        let receipts: Vec<(usize, ReceiptRecord, u64)> = records
+            .iter()
+            .enumerate()
+            .filter_map(|(position, record)| {
+                let receipt = record.receipt().ok()?;
+                let leaf = *leaves.get(&receipt_leaf(&receipt))?;
+                Some((position, receipt, leaf))
+            })
+            .collect();
+        let start = receipts
+            .iter()
+            .rev()
+            .find(|(_, receipt, _)| receipt.cycle == first)
+            .map(|(position, _, _)| *position)
+            .ok_or_else(|| fail(format!("cycle {} is not committed in this run", first)))?;
+        let in_range: Vec<_> = receipts
+            .iter()
+            .filter(|(position, receipt, _)| {
+                *position >= start && (first..=last).contains(&receipt.cycle)
+            })
+            .collect();
+        let (last_position, _, last_leaf) = in_range
+            .iter()
+            .find(|(_, receipt, _)| receipt.cycle == last)
+            .ok_or_else(|| fail(format!("cycle {} is not committed in this run", last)))?;
+
+        // The segment runs to the epoch commitment covering the last receipt
+        let epochs: Vec<(usize, EpochCommitment)> = records
+            .iter()
+            .enumerate()
+            .skip(start)
+            .filter_map(|(position, record)| Some((position, record.epoch().ok()?)))
+            .collect();
+        let covering = |position: usize, leaf: u64| {
+            epochs
+                .iter()
+                .find(|(at, epoch)| *at > position && epoch.tree_size > leaf)
+        };
+        let end = covering(*last_position, *last_leaf).map_or(*last_position, |(at, _)| *at);
+        let mut proofs = Vec::new();
+        for (position, receipt, leaf) in &in_range {
+            if let Some((_, epoch)) = covering(*position, *leaf).filter(|(at, _)| *at <= end) {
+                proofs.push(ReceiptProof {
+                    cycle: receipt.cycle,
+                    epoch: epoch.epoch,
+                    proof: tree.inclusion_proof(*leaf, epoch.tree_size)?,
+                });
+            }
+        }
+
+        let hex_body = |record: &LogRecord| hex::encode(record.to_bytes());
+        let mut bundle = Self {
+            version: BUNDLE_VERSION,
+            first_cycle: first,
+            last_cycle: last,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0),
+            root_key: hex::encode(root.verifying_key().to_bytes()),
+            config,
+            key_history: records[..start]
+                .iter()
+                .filter(|r| {
+                    matches!(
+                        r.kind,
+                        RecordKind::Rotation
+                            | RecordKind::Revocation
+                            | RecordKind::Governance
+                            | RecordKind::Quorum
+                    )
+                })
+                .map(hex_body)
+                .collect(),
+            start: records[start].sequence,
+            previous: hex::encode(records[start].previous),
+            records: records[start..=end].iter().map(hex_body).collect(),
+            proofs,
+            signer: signer.fingerprint().to_string(),
+            signature: String::new(),
+        };
+        bundle.signature = signer.sign_digest(&bundle.message());
+        Ok(bundle)
+    }
+
+    /// Digest signed by the exporter: every field except the proofs, which
+    /// are checked against signed epoch roots instead
+    fn message(&self) -> [u8; 32] {
+        let encoder = Encoder::new(Domain::AuditBundle)
+            .u16(self.version)
+            .u64(self.first_cycle)
+            .u64(self.last_cycle)
+            .u64(self.created_at)
+            .str(&self.root_key);
+        self.config
+            .encode(encoder)
+            .strs(&self.key_history)
+            .u64(self.start)
+            .str(&self.previous)
+            .strs(&self.records)
+            .str(&self.signer)
+            .digest()
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|e| DeoxysError::crypto("audit bundle save", e))?;
+        write_file(path.as_ref(), &json, false, "audit bundle save")
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let fail = |reason: String| {
+            DeoxysError::crypto("audit bundle load", format!("{}: {}", path.display(), reason))
+        };
+        let bytes = fs::read(path).map_err(|e| fail(e.to_string()))?;
+        serde_json::from_slice(&bytes).map_err(|e| fail(e.to_string()))
+    }
+
+    /// Check everything the bundle claims. With `pinned_root`, the bundle's
+    /// root key must be that key; otherwise it is trusted as shipped and
+    /// its fingerprint should be compared out of band.
+    pub fn verify(&self, pinned_root: Option<&ProvenanceVerifier>) -> AuditReport {
+        let mut report = AuditReport {
+            first_cycle: self.first_cycle,
+            last_cycle: self.last_cycle,
+            created_at: self.created_at,
+            root: String::new(),
+            signer: self.signer.clone(),
+            config: self.config.clone(),
+            records: self.records.len(),
+            receipts: 0,
+            proven: 0,
+            epochs: 0,
+            rotations: 0,
+            decisions: 0,
+            certificates: 0,
+            failures: Vec::new(),
+            gaps: Vec::new(),
+        };
+        if let Err(failure) = self.check(pinned_root, &mut report) {
+            report.failures.push(failure);
+        }
+        report
+    }
+
+    /// Fill in `report`; a failure that prevents further checks is returned
+    fn check(
+        &self,
+        pinned_root: Option<&ProvenanceVerifier>,
+        report: &mut AuditReport,
+    ) -> std::result::Result<(), String> {
+        if self.version != BUNDLE_VERSION {
+            return Err(format!("unsupported bundle version {}", self.version));
+        }
+        let root = ProvenanceVerifier::from_hex(&self.root_key)
+            .ok_or("root key is not an Ed25519 public key")?;
+        report.root = root.fingerprint().to_string();
+        if let Some(pinned) = pinned_root {
+            if pinned.fingerprint() != root.fingerprint() {
+                return Err(format!(
+                    "root key {} is not the pinned key {}",
+                    root.fingerprint(),
+                    pinned.fingerprint()
+                ));
+            }
+        }
+
+        // Follow the key chain and the operator authority up to the segment
+        let mut keys = KeyChain::new(root);
+        let mut governance = Governance::default();
+        let mut sequence = None;
+        for body in &self.key_history {
+            let record = decode(body)?;
+            if sequence.is_some_and(|s| record.sequence <= s) || record.sequence >= self.start {
+                return Err(format!("key history record {} out of order", record.sequence));
+            }
+            sequence = Some(record.sequence);
+            let applied = match record.kind {
+                RecordKind::Rotation => record
+                    .rotation()
+                    .map_err(|e| e.to_string())
+                    .and_then(|r| keys.rotate(&r).map_err(|e| e.to_string())),
+                RecordKind::Revocation => record
+                    .revocation()
+                    .map_err(|e| e.to_string())
+                    .and_then(|r| keys.revoke(&r).map_err(|e| e.to_string())),
+                RecordKind::Governance => record
+                    .governance()
+                    .map_err(|e| e.to_string())
+                    .and_then(|g| governance.apply(&g, keys.active()).map_err(|e| e.to_string())),
+                RecordKind::Quorum => record
+                    .quorum()
+                    .map_err(|e| e.to_string())
+                    .and_then(|c| {
+                        governance.certify(&c, &keys.keys()[0]).map_err(|e| e.to_string())
+                    }),
+                _ => Err("not a key or governance record".to_string()),
+            };
+            applied.map_err(|e| format!("key history record {}: {}", record.sequence, e))?;
+        }
+
+        // Hash chain and signatures of the segment
+        let records = self
+            .records
+            .iter()
+            .map(|body| decode(body))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let previous: [u8; 32] = hex::decode(&self.previous)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or("previous record hash is malformed")?;
+        verify_segment(&records, self.start, previous).map_err(|e| e.to_string())?;
+        let keys = verify_signatures_from(&records, keys, governance).map_err(|e| e.to_string())?;
+        let active = keys.active();
+        if self.signer != active.fingerprint()
+            || !active.verify_digest(&self.message(), &self.signature)
+        {
+            return Err(format!(
+                "bundle is not signed by the active provenance key {}",
+                active.fingerprint()
+            ));
+        }
+
+        let mut receipts = BTreeMap::new();
+        let mut epochs = Vec::new();
+        for (position, record) in records.iter().enumerate() {
+            match record.kind {
+                RecordKind::Receipt => {
+                    let receipt = record.receipt().map_err(|e| e.to_string())?;
+                    if !(self.first_cycle..=self.last_cycle).contains(&receipt.cycle) {
+                        continue;
+                    }
+                    let cycle = receipt.cycle;
+                    if receipts.insert(cycle, (position, receipt)).is_some() {
+                        return Err(format!("cycle {} appears more than once", cycle));
+                    }
+                }
+                RecordKind::Epoch => {
+                    epochs.push((position, record.epoch().map_err(|e| e.to_string())?));
+                }
+                RecordKind::Rotation | RecordKind::Revocation => report.rotations += 1,
+                RecordKind::Decision => report.decisions += 1,
+                RecordKind::Governance => {}
+                RecordKind::Quorum => report.certificates += 1,
+            }
+        }
+        report.receipts = receipts.len() as u64;
+        report.epochs = epochs.len();
+        let missing = (self.first_cycle..=self.last_cycle)
+            .filter(|cycle| !receipts.contains_key(cycle))
+            .count();
+        if missing > 0 {
+            report.gaps.push(format!(
+                "{} of {} cycles in the range have no receipt in the segment",
+                missing,
+                self.last_cycle - self.first_cycle + 1
+            ));
+        }
+
+        let proofs: HashMap<_, _> = self.proofs.iter().map(|p| (p.cycle, p)).collect();
+        for (cycle, (position, receipt)) in &receipts {
+            if receipt.ckks_parameters != self.config.ckks_parameters {
+                report.failures.push(format!(
+                    "cycle {} ran under CKKS parameters {}, not the configured {}",
+                    cycle, receipt.ckks_parameters, self.config.ckks_parameters
+                ));
+            }
+            if let Some(approval) = &receipt.approval {
+                let operator = ProvenanceVerifier::from_hex(&approval.operator_key);
+                let listed = operator.is_some_and(|operator| {
+                    self.config
+                        .operators
+                        .iter()
+                        .any(|f| f == operator.fingerprint())
+                });
+                if !listed {
+                    report.failures.push(format!(
+                        "cycle {} was approved by an operator outside the configuration",
+                        cycle
+                    ));
+                }
+            }
+            let Some(proof) = proofs.get(cycle) else {
+                report
+                    .gaps
+                    .push(format!("cycle {} has no Merkle inclusion proof", cycle));
+                continue;
+            };
+            let Some((_, epoch)) = epochs
+                .iter()
+                .find(|(at, epoch)| at > position && epoch.epoch == proof.epoch)
+            else {
+                report.gaps.push(format!(
+                    "epoch {} proving cycle {} is not in the segment",
+                    proof.epoch, cycle
+                ));
+                continue;
+            };
+            let included = if proof.proof.tree_size == epoch.tree_size {
+                proof.proof.verify(&receipt_leaf(receipt), &epoch.root)
+            } else {
+                Err(crate::crypto::ProvenanceError::NotIncluded {
+                    leaf: proof.proof.leaf_index,
+                    tree_size: epoch.tree_size,
+                })
+            };
+            match included {
+                Ok(()) => report.proven += 1,
+                Err(e) => report.failures.push(format!("cycle {}: {}", cycle, e)),
+            }
+        }
+        Ok(())
+    }
+}
+
+fn decode(body: &str) -> std::result::Result<LogRecord, String> {
+    hex::decode(body)
+        .map_err(|e| format!("record body is not hex: {}", e))
+        .and_then(|bytes| LogRecord::from_bytes(&bytes).map_err(|e| e.to_string()))
+}
+
+/// Outcome of verifying a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditStatus {
+    /// Every check passed and every cycle in the range is proven
+    Valid,
+    /// Some evidence is wrong: a signature, link, proof or claim failed
+    Invalid,
+    /// Nothing failed, but evidence for part of the range is missing
+    Incomplete,
+}
+
+impl AuditStatus {
+    /// Process exit code of the offline verifier
+    pub fn exit_code(self) -> u8 {
+        match self {
+            Self::Valid => 0,
+            Self::Invalid => 1,
+            Self::Incomplete => 2,
+        }
+    }
+}
+
+/// What [`AuditBundle::verify`] checked and found
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditReport {
+    pub first_cycle: u64,
+    pub last_cycle: u64,
+    pub created_at: u64,
+    /// Root key fingerprint; empty when the key is malformed
+    pub root: String,
+    pub signer: String,
+    pub config: ConfigSnapshot,
+    pub records: usize,
+    /// Receipts found for cycles in the range
+    pub receipts: u64,
+    /// Receipts with a valid inclusion proof
+    pub proven: u64,
+    pub epochs: usize,
+    pub rotations: u64,
+    pub decisions: u64,
+    pub certificates: u64,
+    /// Checks that failed
+    pub failures: Vec<String>,
+    /// Evidence the bundle lacks
+    pub gaps: Vec<String>,
+}
+
+impl AuditReport {
+    pub fn status(&self) -> AuditStatus {
+        if !self.failures.is_empty() {
+            AuditStatus::Invalid
+        } else if !self.gaps.is_empty() {
+            AuditStatus::Incomplete
+        } else {
+            AuditStatus::Valid
+        }
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self.status() {
+            AuditStatus::Valid => "VALID",
+            AuditStatus::Invalid => "INVALID",
+            AuditStatus::Incomplete => "INCOMPLETE",
+        };
+        writeln!(f, "Audit bundle: cycles {}..={}", self.first_cycle, self.last_cycle)?;
+        writeln!(f, "  exported at     {} (unix)", self.created_at)?;
+        writeln!(f, "  root key        {}", self.root)?;
+        writeln!(f, "  signed by       {}", self.signer)?;
+        writeln!(
+            f,
+            "  bounds          [{}, {}]",
+            self.config.bounds[0], self.config.bounds[1]
+        )?;
+        writeln!(f, "  ckks            {}", self.config.ckks_parameters)?;
+        writeln!(f, "  operators       {}", self.config.operators.len())?;
+        if let Some(quorum) = &self.config.quorum {
+            writeln!(
+                f,
+                "  quorum          {} of {} within {}s",
+                quorum.threshold,
+                quorum.operators.len(),
+                quorum.window_secs
+            )?;
+        }
+        writeln!(f, "  log records     {}", self.records)?;
+        writeln!(
+            f,
+            "  receipts        {} found, {} proven in {} epochs",
+            self.receipts, self.proven, self.epochs
+        )?;
+        writeln!(
+            f,
+            "  key changes     {} | decisions {} | quorum certificates {}",
+            self.rotations, self.decisions, self.certificates
+        )?;
+        for failure in &self.failures {
+            writeln!(f, "  FAILED  {}", failure)?;
+        }
+        for gap in &self.gaps {
+            writeln!(f, "  MISSING {}", gap)?;
+        }
+        write!(f, "Result: {}", status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::invariants::DecreaseCondition;
+    use crate::ledger::ProvenanceLog;
+    use crate::rik::RikEngine;
+    use crate::substrate::SovereignState;
+
+    #[tokio::test]
+    async fn test_bundle_verifies_offline_across_rotation() {
+        let dir = std::env::temp_dir().join(format!("deoxys-audit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let root = ProvenanceVerifier::from(&ProvenanceSigner::from_seed(&[1; 32]));
+        let mut engine = RikEngine::new(SovereignState::new("C_EQUALS_XNXALEXIS_ROOT"));
+        engine.set_decrease_condition(DecreaseCondition::new(0.0, 0.5, 0.0).unwrap());
+        engine.set_signer(ProvenanceSigner::from_seed(&[1; 32])).unwrap();
+        let log = ProvenanceLog::open(dir.join("provenance.log")).unwrap();
+        engine.set_provenance_log(log).unwrap();
+        engine.enable_epochs(2).unwrap();
+        for _ in 0..3 {
+            engine.execute_cycle().await.unwrap();
+        }
+        engine
+            .rotate_signer(ProvenanceSigner::from_seed(&[2; 32]))
+            .unwrap();
+        for _ in 0..3 {
+            engine.execute_cycle().await.unwrap();
+        }
+        assert!(engine.export_audit(4, 2, &root).is_err());
+        assert!(engine.export_audit(5, 9, &root).is_err());
+
+        // Spans the rotation; the open epoch is sealed to prove cycle 6
+        let bundle = engine.export_audit(2, 6, &root).unwrap();
+        assert!(bundle.key_history.is_empty());
+        let path = dir.join("bundle.json");
+        bundle.save(&path).unwrap();
+        let bundle = AuditBundle::load(&path).unwrap();
+        let report = bundle.verify(Some(&root));
+        assert_eq!(report.status(), AuditStatus::Valid, "{}", report);
+        assert_eq!((report.receipts, report.proven, report.rotations), (5, 5, 1));
+
+        // After the rotation, the key chain comes from the key history
+        let later = engine.export_audit(5, 6, &root).unwrap();
+        assert_eq!(later.key_history.len(), 1);
+        assert_eq!(later.verify(None).status(), AuditStatus::Valid);
+
+        // Missing evidence leaves the bundle incomplete, not invalid
+        let mut unproven = bundle.clone();
+        unproven.proofs.retain(|proof| proof.cycle != 4);
+        let report = unproven.verify(Some(&root));
+        assert_eq!(report.status(), AuditStatus::Incomplete);
+        assert_eq!(report.gaps, ["cycle 4 has no Merkle inclusion proof"]);
+        let mut widened = bundle.clone();
+        widened.last_cycle = 7;
+        assert_eq!(widened.verify(None).status(), AuditStatus::Invalid);
+
+        // Forged or altered evidence is invalid
+        let other = ProvenanceVerifier::from(&ProvenanceSigner::from_seed(&[3; 32]));
+        assert_eq!(bundle.verify(Some(&other)).status(), AuditStatus::Invalid);
+        let mut reconfigured = bundle.clone();
+        reconfigured.config.bounds = [-5.0, 5.0];
+        assert_eq!(reconfigured.verify(None).status(), AuditStatus::Invalid);
+        let mut tampered = bundle.clone();
+        let body = &mut tampered.records[0];
+        let flipped = if body.ends_with('0') { '1' } else { '0' };
+        body.pop();
+        body.push(flipped);
+        assert_eq!(tampered.verify(None).status(), AuditStatus::Invalid);
+        let mut truncated = bundle.clone();
+        truncated.records.remove(1);
+        assert_eq!(truncated.verify(None).status(), AuditStatus::Invalid);
+        let mut misplaced = bundle;
+        misplaced.proofs[0].proof = misplaced.proofs[1].proof.clone();
+        assert_eq!(misplaced.verify(None).status(), AuditStatus::Invalid);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Operator signing tool for engine proposals. It runs apart from the engine,
+//! which only ever holds the operator's public key.
+//!
+//! ```text
+//! deoxys-approve keygen <operator.pem>
+//! deoxys-approve <operator.pem> <cycle> <action hex> (--approve | --deny) [--out <decision.json>]
+//! ```
+//!
+//! `keygen` writes a new operator key and its public half to
+//! `<operator.pem>.pub`, the file the engine authorizes. The signed decision
+//! is printed as JSON, or written to `--out`.
+
+use deoxys_core::approval::{Decision, Proposal, Ruling};
+use deoxys_core::crypto::ProvenanceSigner;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: deoxys-approve keygen <operator.pem>\n       \
+    deoxys-approve <operator.pem> <cycle> <action hex> (--approve | --deny) \
+    [--out <decision.json>]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("deoxys-approve: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("keygen") {
+        let [_, key] = args.as_slice() else {
+            return Err(USAGE.to_string());
+        };
+        if Path::new(key).exists() {
+            return Err(format!("{} already exists", key));
+        }
+        let operator = ProvenanceSigner::load_or_generate(key).map_err(|e| e.to_string())?;
+        operator.export_public_key(format!("{}.pub", key)).map_err(|e| e.to_string())?;
+        println!("Operator key {} (fingerprint {})", key, operator.fingerprint());
+        return Ok(());
+    }
+
+    let mut positional = Vec::new();
+    let mut ruling = None;
+    let mut out = None;
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--approve" => ruling = Some(Ruling::Approve),
+            "--deny" => ruling = Some(Ruling::Deny),
+            "--out" => out = Some(args.next().ok_or(USAGE)?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ if !arg.starts_with('-') => positional.push(arg),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let ([key, cycle, action], Some(ruling)) = (positional.as_slice(), ruling) else {
+        return Err(USAGE.to_string());
+    };
+    let cycle = cycle
+        .parse()
+        .map_err(|_| format!("{} is not a cycle index", cycle))?;
+    let action = hex::decode(action)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format!("{} is not a 32-byte hex action digest", action))?;
+
+    let operator = ProvenanceSigner::load(key).map_err(|e| e.to_string())?;
+    let decision = Decision::sign(&operator, &Proposal { cycle, action }, ruling);
+    let json = serde_json::to_string_pretty(&decision).map_err(|e| e.to_string())?;
+    match out {
+        Some(path) => std::fs::write(&path, json).map_err(|e| format!("{}: {}", path, e))?,
+        None => println!("{}", json),
+    }
+    Ok(())
+}
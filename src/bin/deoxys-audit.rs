@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Offline verifier for audit bundles.
+//!
+//! ```text
+//! deoxys-audit <bundle.json> [--root <public key file or hex>] [--json]
+//! ```
+//!
+//! Exit codes: 0 valid, 1 invalid, 2 incomplete, 3 the bundle could not be
+//! read or the arguments are wrong.
+
+use deoxys_core::audit::AuditBundle;
+use deoxys_core::crypto::ProvenanceVerifier;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: deoxys-audit <bundle.json> [--root <public key file or hex>] [--json]";
+/// Exit code when no verdict could be reached
+const EXIT_UNREADABLE: u8 = 3;
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => ExitCode::from(code),
+        Err(message) => {
+            eprintln!("deoxys-audit: {}", message);
+            ExitCode::from(EXIT_UNREADABLE)
+        }
+    }
+}
+
+fn run() -> Result<u8, String> {
+    let mut bundle = None;
+    let mut root = None;
+    let mut json = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--root" => root = Some(args.next().ok_or(USAGE)?),
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(0);
+            }
+            _ if bundle.is_none() && !arg.starts_with('-') => bundle = Some(arg),
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    let bundle = AuditBundle::load(bundle.ok_or(USAGE)?).map_err(|e| e.to_string())?;
+    // Pin the root to a key obtained out of band: an exported .pub file or hex
+    let root = root
+        .map(|key| {
+            if Path::new(&key).exists() {
+                ProvenanceVerifier::load(&key).map_err(|e| e.to_string())
+            } else {
+                ProvenanceVerifier::from_hex(&key)
+                    .ok_or_else(|| format!("{} is neither a key file nor a hex public key", key))
+            }
+        })
+        .transpose()?;
+
+    let report = bundle.verify(root.as_ref());
+    if json {
+        let output = serde_json::json!({ "status": report.status(), "report": report });
+        println!("{:#}", output);
+    } else {
+        println!("{}", report);
+        if root.is_none() {
+            println!("Note: root key not pinned; compare its fingerprint out of band");
+        }
+    }
+    Ok(report.status().exit_code())
+}
@@ -0,0 +1,235 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Canonical, domain-separated binary encoding for signed and hashed messages.
+//!
+//! Every message opens with its domain string and the schema version, so a
+//! digest or signature produced for one message type never verifies as
+//! another. Fields follow in a fixed order:
+//!
+//! ```text
+//! message  len u64 | domain | version u16 | field*
+//! u8/u64   fixed width, big-endian
+//! f64      IEEE 754 bits, big-endian; every NaN is 0x7ff8000000000000
+//!          and -0.0 is written as +0.0
+//! bytes    len u64 | bytes (strings as UTF-8)
+//! option   0 for None, 1 followed by the value
+//! seq      count u64 | element*
+//! ```
+//!
+//! Each domain has a fixed field order, and lengths and counts keep field
+//! boundaries unambiguous, so two different messages never share an encoding.
+
+use sha2::{Digest, Sha256};
+
+/// Version of every message layout below; bumped when any of them changes
+pub const SCHEMA_VERSION: u16 = 1;
+
+/// Bit pattern every NaN is encoded as
+pub const CANONICAL_NAN: u64 = 0x7ff8_0000_0000_0000;
+
+/// Message type, written as the first field of every encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Domain {
+    /// Signed cycle message: cycle, previous receipt signature, approval, mode,
+    /// CKKS parameter set, entropy policy, state digest
+    Receipt,
+    /// Merkle leaf committing to a whole receipt record
+    ReceiptRecord,
+    /// Belief state snapshot
+    StateSnapshot,
+    /// Signed Merkle tree head of an epoch
+    Epoch,
+    /// Provenance key hand-over
+    Rotation,
+    /// Provenance key revocation
+    Revocation,
+    /// Configuration change requested by an operator
+    OperatorCommand,
+    /// Cycle about to be executed, awaiting an operator decision; bound to
+    /// the provenance key and the engine run
+    Proposal,
+    /// Escalation reset awaiting an operator decision; bound like a proposal
+    ResetProposal,
+    /// Operator ruling on a proposal
+    Decision,
+    /// Operator signature towards a quorum on a command
+    Endorsement,
+    /// Operators and quorum policy put in force by the engine
+    Governance,
+    /// Integrity tag of an encrypted state envelope
+    WireEnvelope,
+    /// Exported audit bundle of a range of cycles
+    AuditBundle,
+}
+
+impl Domain {
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Receipt => "deoxys/receipt",
+            Self::ReceiptRecord => "deoxys/receipt-record",
+            Self::StateSnapshot => "deoxys/state-snapshot",
+            Self::Epoch => "deoxys/epoch",
+            Self::Rotation => "deoxys/key-rotation",
+            Self::Revocation => "deoxys/key-revocation",
+            Self::OperatorCommand => "deoxys/operator-command",
+            Self::Proposal => "deoxys/cycle-proposal",
+            Self::ResetProposal => "deoxys/reset-proposal",
+            Self::Decision => "deoxys/operator-decision",
+            Self::Endorsement => "deoxys/quorum-endorsement",
+            Self::Governance => "deoxys/governance",
+            Self::WireEnvelope => "deoxys/wire-envelope",
+            Self::AuditBundle => "deoxys/audit-bundle",
+        }
+    }
+}
+
+/// Bits of `value` with NaN payloads and the sign of zero normalized
+pub fn canonical_f64(value: f64) -> u64 {
+    if value.is_nan() {
+        CANONICAL_NAN
+    } else if value == 0.0 {
+        0
+    } else {
+        value.to_bits()
+    }
+}
+
+/// Builder for one canonical message
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    pub fn new(domain: Domain) -> Self {
+        Self { bytes: Vec::new() }
+            .bytes(domain.tag().as_bytes())
+            .u16(SCHEMA_VERSION)
+    }
+
+    pub fn u8(mut self, value: u8) -> Self {
+        self.bytes.push(value);
+        self
+    }
+
+    pub fn u16(mut self, value: u16) -> Self {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub fn u64(mut self, value: u64) -> Self {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub fn f64(self, value: f64) -> Self {
+        self.u64(canonical_f64(value))
+    }
+
+    /// Count-prefixed sequence of strings
+    pub fn strs(self, values: &[String]) -> Self {
+        values.iter().fold(self.u64(values.len() as u64), |encoder, value| encoder.str(value))
+    }
+
+    pub fn f64s<I>(self, values: I) -> Self
+    where
+        I: IntoIterator<Item = f64>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let values = values.into_iter();
+        let count = values.len() as u64;
+        values.fold(self.u64(count), Self::f64)
+    }
+
+    pub fn bytes(mut self, value: &[u8]) -> Self {
+        self = self.u64(value.len() as u64);
+        self.bytes.extend_from_slice(value);
+        self
+    }
+
+    pub fn str(self, value: &str) -> Self {
+        self.bytes(value.as_bytes())
+    }
+
+    pub fn option<T>(self, value: Option<T>, encode: impl FnOnce(Self, T) -> Self) -> Self {
+        match value {
+            None => self.u8(0),
+            Some(value) => encode(self.u8(1), value),
+        }
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// SHA-256 of the encoded message
+    pub fn digest(self) -> [u8; 32] {
+        Sha256::digest(&self.bytes).into()
+    }
+}
+
+/// SHA-256 of the domain header followed by a self-delimiting `body`, for
+/// large messages that already have a fixed binary layout
+pub fn tagged_digest(domain: Domain, body: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(Encoder::new(domain).finish())
+        .chain_update(body)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_float_normalization() {
+        let quiet = f64::from_bits(0x7ff8_0000_0000_0001);
+        let signalling = f64::from_bits(0x7ff0_0000_0000_0001);
+        let negative = f64::from_bits(0xfff8_0000_0000_0000);
+        for nan in [f64::NAN, quiet, signalling, negative] {
+            assert_eq!(canonical_f64(nan), CANONICAL_NAN);
+        }
+        assert_eq!(canonical_f64(-0.0), canonical_f64(0.0));
+        assert_eq!(canonical_f64(-1.5), (-1.5f64).to_bits());
+        assert_ne!(
+            canonical_f64(f64::INFINITY),
+            canonical_f64(f64::NEG_INFINITY)
+        );
+
+        let state = |values: [f64; 2]| Encoder::new(Domain::StateSnapshot).f64s(values).digest();
+        assert_eq!(state([-0.0, f64::NAN]), state([0.0, negative]));
+        assert_ne!(state([0.0, 1.0]), state([1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_layout_and_domain_separation() {
+        let bytes = Encoder::new(Domain::Epoch)
+            .u64(7)
+            .option(None::<u8>, Encoder::u8)
+            .str("ab")
+            .finish();
+        let mut expected = vec![0, 0, 0, 0, 0, 0, 0, 12];
+        expected.extend_from_slice(b"deoxys/epoch");
+        expected.extend_from_slice(&[0, 1]);
+        expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 7]);
+        expected.push(0);
+        expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 2, b'a', b'b']);
+        assert_eq!(bytes, expected);
+
+        // Identical fields under different domains never collide
+        let fields = |domain| Encoder::new(domain).u64(3).u64(4).digest();
+        assert_ne!(fields(Domain::Epoch), fields(Domain::Rotation));
+        assert_ne!(
+            tagged_digest(Domain::WireEnvelope, b"x"),
+            tagged_digest(Domain::Receipt, b"x")
+        );
+        // Length prefixes keep field boundaries unambiguous
+        assert_ne!(
+            Encoder::new(Domain::Revocation).str("ab").str("c").digest(),
+            Encoder::new(Domain::Revocation).str("a").str("bc").digest()
+        );
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Word-size modular arithmetic for NTT-friendly primes below 2^61.
+
+use crate::error::{DeoxysError, Result};
+
+/// Largest supported modulus size; keeps lazy sums below 2^63
+pub const MAX_MODULUS_BITS: u32 = 61;
+
+pub fn add_mod(a: u64, b: u64, q: u64) -> u64 {
+    let s = a + b;
+    if s >= q {
+        s - q
+    } else {
+        s
+    }
+}
+
+pub fn sub_mod(a: u64, b: u64, q: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        a + q - b
+    }
+}
+
+pub fn neg_mod(a: u64, q: u64) -> u64 {
+    if a == 0 {
+        0
+    } else {
+        q - a
+    }
+}
+
+pub fn mul_mod(a: u64, b: u64, q: u64) -> u64 {
+    ((a as u128 * b as u128) % q as u128) as u64
+}
+
+pub fn pow_mod(mut base: u64, mut exp: u64, q: u64) -> u64 {
+    let mut result = 1 % q;
+    base %= q;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, q);
+        }
+        base = mul_mod(base, base, q);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Inverse modulo a prime
+pub fn inv_mod(a: u64, q: u64) -> u64 {
+    pow_mod(a, q - 2, q)
+}
+
+/// Precomputed floor(w * 2^64 / q) for Shoup multiplication by the constant w
+pub fn shoup(w: u64, q: u64) -> u64 {
+    (((w as u128) << 64) / q as u128) as u64
+}
+
+/// a * w mod q using the Shoup precomputation of w
+pub fn mul_shoup(a: u64, w: u64, w_shoup: u64, q: u64) -> u64 {
+    let hi = ((a as u128 * w_shoup as u128) >> 64) as u64;
+    let r = a.wrapping_mul(w).wrapping_sub(hi.wrapping_mul(q));
+    if r >= q {
+        r - q
+    } else {
+        r
+    }
+}
+
+/// Representative of a in (-q/2, q/2]
+pub fn center(a: u64, q: u64) -> i64 {
+    if a > q / 2 {
+        a as i64 - q as i64
+    } else {
+        a as i64
+    }
+}
+
+pub fn reduce_i64(v: i64, q: u64) -> u64 {
+    v.rem_euclid(q as i64) as u64
+}
+
+/// Deterministic Miller-Rabin for 64-bit integers
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for p in WITNESSES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let (mut d, mut r) = (n - 1, 0);
+    while d % 2 == 0 {
+        d /= 2;
+        r += 1;
+    }
+    'witness: for a in WITNESSES {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..r {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// `count` distinct primes q = 1 mod 2n of exactly `bits` bits, largest first,
+/// skipping any in `exclude`
+pub fn ntt_primes(bits: u32, count: usize, n: usize, exclude: &[u64]) -> Result<Vec<u64>> {
+    let two_n = 2 * n as u64;
+    if !(20..=MAX_MODULUS_BITS).contains(&bits) || two_n.trailing_zeros() + 1 >= bits {
+        return Err(DeoxysError::config(
+            "ckks modulus size",
+            format!("{} bits unsupported for ring degree {}", bits, n),
+        ));
+    }
+    let floor = 1u64 << (bits - 1);
+    let mut candidate = ((1u64 << bits) - 1) / two_n * two_n + 1;
+    let mut primes = Vec::with_capacity(count);
+    while primes.len() < count {
+        if candidate < floor {
+            return Err(DeoxysError::config(
+                "ckks modulus size",
+                format!("not enough {}-bit primes for ring degree {}", bits, n),
+            ));
+        }
+        if is_prime(candidate) && !exclude.contains(&candidate) {
+            primes.push(candidate);
+        }
+        candidate -= two_n;
+    }
+    Ok(primes)
+}
+
+/// A primitive 2n-th root of unity modulo a prime q = 1 mod 2n
+pub fn primitive_root(two_n: u64, q: u64) -> u64 {
+    (2..q)
+        .map(|g| pow_mod(g, (q - 1) / two_n, q))
+        .find(|&root| pow_mod(root, two_n / 2, q) == q - 1)
+        .expect("q = 1 mod 2n has a primitive 2n-th root")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_primes_and_roots() {
+        assert!(is_prime((1 << 61) - 1));
+        assert!(!is_prime(3_215_031_751)); // strong pseudoprime to bases 2, 3, 5, 7
+        let primes = ntt_primes(40, 3, 4096, &[]).unwrap();
+        for &q in &primes {
+            assert_eq!(q % 8192, 1);
+            assert_eq!(64 - q.leading_zeros(), 40);
+            let psi = primitive_root(8192, q);
+            assert_eq!(pow_mod(psi, 4096, q), q - 1);
+        }
+        assert_eq!(ntt_primes(40, 1, 4096, &primes[..1]).unwrap()[0], primes[1]);
+        assert!(ntt_primes(62, 1, 4096, &[]).is_err());
+    }
+
+    #[test]
+    fn test_shoup_and_centering() {
+        let q = (1u64 << 59) - 55;
+        let (a, w) = (q - 3, 123_456_789_012_345);
+        assert_eq!(mul_shoup(a, w, shoup(w, q), q), mul_mod(a, w, q));
+        assert_eq!(center(q - 1, q), -1);
+        assert_eq!(reduce_i64(-1, q), q - 1);
+        assert_eq!(mul_mod(inv_mod(w, q), w, q), 1);
+    }
+}
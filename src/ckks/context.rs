@@ -0,0 +1,780 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use super::arith::{add_mod, center, inv_mod, mul_mod, neg_mod, pow_mod, reduce_i64, sub_mod};
+use super::encoder::Encoder;
+use super::keys::{GaloisKeys, KeySwitchKey, PublicKey, RelinKey, SecretKey};
+use super::ntt::NttTable;
+use super::params::CkksParameters;
+use crate::error::{DeoxysError, Result};
+use num_complex::Complex64;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+
+/// RNS residues of one polynomial, each in NTT form, in basis order
+pub(crate) type RnsPoly = Vec<Vec<u64>>;
+
+/// Standard deviation of the discrete Gaussian error distribution
+const ERROR_STDDEV: f64 = 3.2;
+/// Gaussian samples are clipped at six standard deviations
+const ERROR_BOUND: f64 = 6.0 * ERROR_STDDEV;
+/// Relative scale mismatch tolerated when adding ciphertexts
+const SCALE_TOLERANCE: f64 = 1e-4;
+
+/// Encoded, unencrypted polynomial at a given level and scale
+#[derive(Clone, PartialEq)]
+pub struct Plaintext {
+    pub(crate) poly: RnsPoly,
+    pub(crate) level: usize,
+    pub(crate) scale: f64,
+}
+
+/// RLWE ciphertext (c0, c1) decrypting to c0 + c1 * s
+#[derive(Clone, PartialEq)]
+pub struct Ciphertext {
+    pub(crate) c0: RnsPoly,
+    pub(crate) c1: RnsPoly,
+    pub(crate) level: usize,
+    pub(crate) scale: f64,
+}
+
+impl Plaintext {
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl Ciphertext {
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl fmt::Debug for Plaintext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Plaintext {{ level: {}, scale: {} }}",
+            self.level, self.scale
+        )
+    }
+}
+
+impl fmt::Debug for Ciphertext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Ciphertext {{ level: {}, scale: {} }}",
+            self.level, self.scale
+        )
+    }
+}
+
+/// Parameters with their NTT tables and encoder; performs every CKKS operation
+pub struct CkksContext {
+    params: CkksParameters,
+    /// q_0..q_L followed by the special prime P
+    tables: Vec<NttTable>,
+    encoder: Encoder,
+}
+
+impl CkksContext {
+    pub fn new(params: CkksParameters) -> Self {
+        let n = params.degree();
+        let tables = params
+            .moduli()
+            .iter()
+            .chain(std::iter::once(&params.special_modulus()))
+            .map(|&q| NttTable::new(n, q))
+            .collect();
+        Self {
+            encoder: Encoder::new(n),
+            tables,
+            params,
+        }
+    }
+
+    pub fn params(&self) -> &CkksParameters {
+        &self.params
+    }
+
+    pub(crate) fn special_index(&self) -> usize {
+        self.params.max_level() + 1
+    }
+
+    /// Table indices of a polynomial at `level`, optionally extended by P
+    pub(crate) fn basis(&self, level: usize, special: bool) -> Vec<usize> {
+        let mut basis: Vec<usize> = (0..=level).collect();
+        if special {
+            basis.push(self.special_index());
+        }
+        basis
+    }
+
+    pub(crate) fn modulus(&self, index: usize) -> u64 {
+        self.tables[index].modulus()
+    }
+
+    pub(crate) fn rng() -> StdRng {
+        StdRng::from_entropy()
+    }
+
+    pub(crate) fn sample_ternary(&self, rng: &mut StdRng) -> Vec<i64> {
+        (0..self.params.degree())
+            .map(|_| rng.gen_range(-1..=1))
+            .collect()
+    }
+
+    pub(crate) fn sample_error(&self, rng: &mut StdRng) -> Vec<i64> {
+        (0..self.params.degree())
+            .map(|_| loop {
+                // Box-Muller; rejection keeps the support bounded
+                let (u1, u2): (f64, f64) = (rng.gen_range(f64::EPSILON..1.0), rng.gen());
+                let x = ERROR_STDDEV * (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos();
+                if x.abs() <= ERROR_BOUND {
+                    break x.round() as i64;
+                }
+            })
+            .collect()
+    }
+
+    pub(crate) fn sample_uniform(&self, rng: &mut StdRng, basis: &[usize]) -> RnsPoly {
+        basis
+            .iter()
+            .map(|&i| {
+                let q = self.modulus(i);
+                (0..self.params.degree())
+                    .map(|_| rng.gen_range(0..q))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Reduce signed coefficients into each basis modulus and transform to NTT form
+    pub(crate) fn lift(&self, coeffs: &[i64], basis: &[usize]) -> RnsPoly {
+        basis
+            .iter()
+            .map(|&i| {
+                let table = &self.tables[i];
+                let q = table.modulus();
+                let mut residue: Vec<u64> = coeffs.iter().map(|&c| reduce_i64(c, q)).collect();
+                table.forward(&mut residue);
+                residue
+            })
+            .collect()
+    }
+
+    pub(crate) fn add_poly(&self, a: &RnsPoly, b: &RnsPoly, basis: &[usize]) -> RnsPoly {
+        self.pointwise(a, b, basis, add_mod)
+    }
+
+    pub(crate) fn sub_poly(&self, a: &RnsPoly, b: &RnsPoly, basis: &[usize]) -> RnsPoly {
+        self.pointwise(a, b, basis, sub_mod)
+    }
+
+    pub(crate) fn mul_poly(&self, a: &RnsPoly, b: &RnsPoly, basis: &[usize]) -> RnsPoly {
+        self.pointwise(a, b, basis, mul_mod)
+    }
+
+    fn pointwise(
+        &self,
+        a: &RnsPoly,
+        b: &RnsPoly,
+        basis: &[usize],
+        op: fn(u64, u64, u64) -> u64,
+    ) -> RnsPoly {
+        a.iter()
+            .zip(b)
+            .zip(basis)
+            .map(|((x, y), &i)| {
+                let q = self.modulus(i);
+                x.iter().zip(y).map(|(&u, &v)| op(u, v, q)).collect()
+            })
+            .collect()
+    }
+
+    /// Galois element 5^steps mod 2n rotating the slots left by `steps`
+    pub fn galois_element(&self, steps: i64) -> u64 {
+        let slots = self.params.slots() as i64;
+        pow_mod(
+            5,
+            steps.rem_euclid(slots) as u64,
+            2 * self.params.degree() as u64,
+        )
+    }
+
+    /// Distinct Galois elements for the non-trivial rotations among `steps`
+    pub fn rotation_elements(&self, steps: &[i64]) -> Vec<u64> {
+        let slots = self.params.slots() as i64;
+        let mut elements: Vec<u64> = steps
+            .iter()
+            .filter(|&&k| k.rem_euclid(slots) != 0)
+            .map(|&k| self.galois_element(k))
+            .collect();
+        elements.sort_unstable();
+        elements.dedup();
+        elements
+    }
+
+    /// Galois element 2n - 1 conjugating every slot
+    pub fn conjugation_element(&self) -> u64 {
+        2 * self.params.degree() as u64 - 1
+    }
+
+    /// Apply X -> X^g to each residue of `poly` (NTT form in and out)
+    pub(crate) fn automorphism(&self, poly: &RnsPoly, basis: &[usize], g: u64) -> RnsPoly {
+        let n = self.params.degree();
+        let two_n = 2 * n as u64;
+        poly.iter()
+            .zip(basis)
+            .map(|(residue, &i)| {
+                let table = &self.tables[i];
+                let q = table.modulus();
+                let mut coeffs = residue.clone();
+                table.inverse(&mut coeffs);
+                let mut permuted = vec![0u64; n];
+                for (k, &c) in coeffs.iter().enumerate() {
+                    let target = (k as u64 * g) % two_n;
+                    // X^(n + j) = -X^j
+                    if target < n as u64 {
+                        permuted[target as usize] = c;
+                    } else {
+                        permuted[(target - n as u64) as usize] = neg_mod(c, q);
+                    }
+                }
+                table.forward(&mut permuted);
+                permuted
+            })
+            .collect()
+    }
+
+    /// Divide by the modulus of the last residue (table `last`) with rounding,
+    /// dropping that residue: rescale for q_l, mod-down for P
+    fn divide_by_last(&self, poly: &mut RnsPoly, last: usize) {
+        let mut top = poly.pop().expect("polynomial has residues");
+        let top_table = &self.tables[last];
+        let top_q = top_table.modulus();
+        top_table.inverse(&mut top);
+        for (j, residue) in poly.iter_mut().enumerate() {
+            let table = &self.tables[j];
+            let q = table.modulus();
+            let mut lifted: Vec<u64> = top
+                .iter()
+                .map(|&c| reduce_i64(center(c, top_q), q))
+                .collect();
+            table.forward(&mut lifted);
+            let inv = inv_mod(top_q % q, q);
+            for (x, &y) in residue.iter_mut().zip(&lifted) {
+                *x = mul_mod(sub_mod(*x, y, q), inv, q);
+            }
+        }
+    }
+
+    /// Hybrid key switching: returns (k0, k1) at `level` with
+    /// k0 + k1 * s ~= d * s' for the key's source secret s'
+    pub(crate) fn key_switch(
+        &self,
+        d: &RnsPoly,
+        level: usize,
+        key: &KeySwitchKey,
+    ) -> (RnsPoly, RnsPoly) {
+        let n = self.params.degree();
+        let basis = self.basis(level, true);
+        let mut acc0 = vec![vec![0u64; n]; basis.len()];
+        let mut acc1 = vec![vec![0u64; n]; basis.len()];
+        for (i, digit_ntt) in d.iter().enumerate().take(level + 1) {
+            let mut digit = digit_ntt.clone();
+            self.tables[i].inverse(&mut digit);
+            let (kb, ka) = &key.components[i];
+            for (slot, &t) in basis.iter().enumerate() {
+                let table = &self.tables[t];
+                let q = table.modulus();
+                let lifted = if t == i {
+                    digit_ntt.clone()
+                } else {
+                    let mut r: Vec<u64> = digit.iter().map(|&c| c % q).collect();
+                    table.forward(&mut r);
+                    r
+                };
+                for k in 0..n {
+                    acc0[slot][k] = add_mod(acc0[slot][k], mul_mod(lifted[k], kb[t][k], q), q);
+                    acc1[slot][k] = add_mod(acc1[slot][k], mul_mod(lifted[k], ka[t][k], q), q);
+                }
+            }
+        }
+        let special = self.special_index();
+        self.divide_by_last(&mut acc0, special);
+        self.divide_by_last(&mut acc1, special);
+        (acc0, acc1)
+    }
+
+    pub fn encode(&self, values: &[f64], level: usize, scale: f64) -> Result<Plaintext> {
+        let values: Vec<Complex64> = values.iter().map(|&v| Complex64::new(v, 0.0)).collect();
+        self.encode_complex(&values, level, scale)
+    }
+
+    /// Encode up to n/2 slot values as round(scale * m) at `level`
+    pub fn encode_complex(
+        &self,
+        values: &[Complex64],
+        level: usize,
+        scale: f64,
+    ) -> Result<Plaintext> {
+        if values.len() > self.params.slots() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "ckks slots",
+                expected: self.params.slots(),
+                actual: values.len(),
+            });
+        }
+        self.check_level(level)?;
+        if !scale.is_finite() || scale < 1.0 {
+            return Err(DeoxysError::crypto(
+                "ckks encode",
+                format!("invalid scale {}", scale),
+            ));
+        }
+        let limit = (self.modulus(0) / 2) as f64;
+        let coeffs: Vec<i64> = self
+            .encoder
+            .embed_inverse(values)
+            .into_iter()
+            .map(|c| {
+                let scaled = (c * scale).round();
+                if scaled.is_finite() && scaled.abs() < limit {
+                    Ok(scaled as i64)
+                } else {
+                    Err(DeoxysError::crypto(
+                        "ckks encode",
+                        "scaled message exceeds half the base prime",
+                    ))
+                }
+            })
+            .collect::<Result<_>>()?;
+        Ok(Plaintext {
+            poly: self.lift(&coeffs, &self.basis(level, false)),
+            level,
+            scale,
+        })
+    }
+
+    /// Real parts of all n/2 slots
+    pub fn decode(&self, plaintext: &Plaintext) -> Vec<f64> {
+        self.decode_complex(plaintext)
+            .into_iter()
+            .map(|z| z.re)
+            .collect()
+    }
+
+    /// Slot values; the message is recovered modulo q_0, which must exceed
+    /// twice its scaled magnitude
+    pub fn decode_complex(&self, plaintext: &Plaintext) -> Vec<Complex64> {
+        let table = &self.tables[0];
+        let q = table.modulus();
+        let mut base = plaintext.poly[0].clone();
+        table.inverse(&mut base);
+        let coeffs: Vec<f64> = base
+            .iter()
+            .map(|&c| center(c, q) as f64 / plaintext.scale)
+            .collect();
+        self.encoder.embed(&coeffs)
+    }
+
+    /// Public-key encryption at the plaintext's level
+    pub fn encrypt(&self, plaintext: &Plaintext, key: &PublicKey) -> Ciphertext {
+        let mut rng = Self::rng();
+        let basis = self.basis(plaintext.level, false);
+        let v = self.lift(&self.sample_ternary(&mut rng), &basis);
+        let e0 = self.lift(&self.sample_error(&mut rng), &basis);
+        let e1 = self.lift(&self.sample_error(&mut rng), &basis);
+
+        let c0 = self.mul_poly(&key.b[..basis.len()].to_vec(), &v, &basis);
+        let c0 = self.add_poly(&self.add_poly(&c0, &e0, &basis), &plaintext.poly, &basis);
+        let c1 = self.mul_poly(&key.a[..basis.len()].to_vec(), &v, &basis);
+        let c1 = self.add_poly(&c1, &e1, &basis);
+        Ciphertext {
+            c0,
+            c1,
+            level: plaintext.level,
+            scale: plaintext.scale,
+        }
+    }
+
+    pub fn decrypt(&self, ciphertext: &Ciphertext, key: &SecretKey) -> Plaintext {
+        let basis = self.basis(ciphertext.level, false);
+        let s = key.ntt[..basis.len()].to_vec();
+        let m = self.add_poly(
+            &ciphertext.c0,
+            &self.mul_poly(&ciphertext.c1, &s, &basis),
+            &basis,
+        );
+        Plaintext {
+            poly: m,
+            level: ciphertext.level,
+            scale: ciphertext.scale,
+        }
+    }
+
+    pub fn add(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+        self.check_compatible("ckks add", a, b.level, b.scale)?;
+        let basis = self.basis(a.level, false);
+        Ok(Ciphertext {
+            c0: self.add_poly(&a.c0, &b.c0, &basis),
+            c1: self.add_poly(&a.c1, &b.c1, &basis),
+            level: a.level,
+            scale: a.scale,
+        })
+    }
+
+    pub fn sub(&self, a: &Ciphertext, b: &Ciphertext) -> Result<Ciphertext> {
+        self.check_compatible("ckks sub", a, b.level, b.scale)?;
+        let basis = self.basis(a.level, false);
+        Ok(Ciphertext {
+            c0: self.sub_poly(&a.c0, &b.c0, &basis),
+            c1: self.sub_poly(&a.c1, &b.c1, &basis),
+            level: a.level,
+            scale: a.scale,
+        })
+    }
+
+    /// Add a plaintext at the same level and scale
+    pub fn add_plain(&self, a: &Ciphertext, plaintext: &Plaintext) -> Result<Ciphertext> {
+        self.check_compatible("ckks add_plain", a, plaintext.level, plaintext.scale)?;
+        let basis = self.basis(a.level, false);
+        Ok(Ciphertext {
+            c0: self.add_poly(&a.c0, &plaintext.poly, &basis),
+            c1: a.c1.clone(),
+            level: a.level,
+            scale: a.scale,
+        })
+    }
+
+    /// Slot-wise product with a plaintext; scales multiply
+    pub fn mul_plain(&self, a: &Ciphertext, plaintext: &Plaintext) -> Result<Ciphertext> {
+        if a.level != plaintext.level {
+            return Err(DeoxysError::crypto(
+                "ckks mul_plain",
+                format!("level mismatch: {} vs {}", a.level, plaintext.level),
+            ));
+        }
+        let basis = self.basis(a.level, false);
+        Ok(Ciphertext {
+            c0: self.mul_poly(&a.c0, &plaintext.poly, &basis),
+            c1: self.mul_poly(&a.c1, &plaintext.poly, &basis),
+            level: a.level,
+            scale: a.scale * plaintext.scale,
+        })
+    }
+
+    /// Slot-wise product of two ciphertexts, relinearized back to degree one
+    pub fn mul(&self, a: &Ciphertext, b: &Ciphertext, relin: &RelinKey) -> Result<Ciphertext> {
+        if a.level != b.level {
+            return Err(DeoxysError::crypto(
+                "ckks mul",
+                format!("level mismatch: {} vs {}", a.level, b.level),
+            ));
+        }
+        let basis = self.basis(a.level, false);
+        let d0 = self.mul_poly(&a.c0, &b.c0, &basis);
+        let d1 = self.add_poly(
+            &self.mul_poly(&a.c0, &b.c1, &basis),
+            &self.mul_poly(&a.c1, &b.c0, &basis),
+            &basis,
+        );
+        let d2 = self.mul_poly(&a.c1, &b.c1, &basis);
+        let (k0, k1) = self.key_switch(&d2, a.level, &relin.0);
+        Ok(Ciphertext {
+            c0: self.add_poly(&d0, &k0, &basis),
+            c1: self.add_poly(&d1, &k1, &basis),
+            level: a.level,
+            scale: a.scale * b.scale,
+        })
+    }
+
+    /// Divide by the top prime q_l, dropping one level
+    pub fn rescale(&self, a: &Ciphertext) -> Result<Ciphertext> {
+        if a.level == 0 {
+            return Err(DeoxysError::crypto(
+                "ckks rescale",
+                "no level left to consume",
+            ));
+        }
+        let q = self.modulus(a.level) as f64;
+        let (mut c0, mut c1) = (a.c0.clone(), a.c1.clone());
+        self.divide_by_last(&mut c0, a.level);
+        self.divide_by_last(&mut c1, a.level);
+        Ok(Ciphertext {
+            c0,
+            c1,
+            level: a.level - 1,
+            scale: a.scale / q,
+        })
+    }
+
+    /// Rotate the slots left by `steps` (right for negative steps)
+    pub fn rotate(&self, a: &Ciphertext, steps: i64, keys: &GaloisKeys) -> Result<Ciphertext> {
+        if steps.rem_euclid(self.params.slots() as i64) == 0 {
+            return Ok(a.clone());
+        }
+        self.apply_galois(a, self.galois_element(steps), keys)
+            .ok_or_else(|| {
+                DeoxysError::crypto("ckks rotate", format!("no Galois key for {} steps", steps))
+            })
+    }
+
+    /// Complex-conjugate every slot
+    pub fn conjugate(&self, a: &Ciphertext, keys: &GaloisKeys) -> Result<Ciphertext> {
+        self.apply_galois(a, self.conjugation_element(), keys)
+            .ok_or_else(|| DeoxysError::crypto("ckks conjugate", "no conjugation key"))
+    }
+
+    fn apply_galois(&self, a: &Ciphertext, g: u64, keys: &GaloisKeys) -> Option<Ciphertext> {
+        let key = keys.get(g)?;
+        let basis = self.basis(a.level, false);
+        let c0 = self.automorphism(&a.c0, &basis, g);
+        let c1 = self.automorphism(&a.c1, &basis, g);
+        let (k0, k1) = self.key_switch(&c1, a.level, key);
+        Some(Ciphertext {
+            c0: self.add_poly(&c0, &k0, &basis),
+            c1: k1,
+            level: a.level,
+            scale: a.scale,
+        })
+    }
+
+    /// Drop residues down to `level` without changing the scale
+    pub fn drop_level(&self, a: &Ciphertext, level: usize) -> Result<Ciphertext> {
+        if level > a.level {
+            return Err(DeoxysError::crypto(
+                "ckks drop_level",
+                format!("cannot raise level {} to {}", a.level, level),
+            ));
+        }
+        Ok(Ciphertext {
+            c0: a.c0[..=level].to_vec(),
+            c1: a.c1[..=level].to_vec(),
+            level,
+            scale: a.scale,
+        })
+    }
+
+    fn check_level(&self, level: usize) -> Result<()> {
+        if level > self.params.max_level() {
+            return Err(DeoxysError::crypto(
+                "ckks level",
+                format!(
+                    "{} exceeds maximum level {}",
+                    level,
+                    self.params.max_level()
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_compatible(
+        &self,
+        operation: &'static str,
+        a: &Ciphertext,
+        level: usize,
+        scale: f64,
+    ) -> Result<()> {
+        if a.level != level {
+            return Err(DeoxysError::crypto(
+                operation,
+                format!("level mismatch: {} vs {}", a.level, level),
+            ));
+        }
+        if ((a.scale - scale) / a.scale).abs() > SCALE_TOLERANCE {
+            return Err(DeoxysError::crypto(
+                operation,
+                format!("scale mismatch: {} vs {}", a.scale, scale),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ckks::KeyGenerator;
+
+    // Small ring for speed; same 60/40/40 + 60 chain shape as the default
+    fn setup() -> (CkksContext, SecretKey, PublicKey, RelinKey) {
+        let context =
+            CkksContext::new(CkksParameters::generate(10, &[60, 40, 40], 60, 40).unwrap());
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let relin = keygen.relin_key(&secret);
+        (context, secret, public, relin)
+    }
+
+    fn values(count: usize, phase: f64) -> Vec<f64> {
+        (0..count)
+            .map(|i| (i as f64 * 0.37 + phase).sin())
+            .collect()
+    }
+
+    fn max_error(actual: &[f64], expected: &[f64]) -> f64 {
+        expected
+            .iter()
+            .zip(actual)
+            .map(|(e, a)| (e - a).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let (context, ..) = setup();
+        let x = values(512, 0.0);
+        let plaintext = context.encode(&x, 2, context.params().scale()).unwrap();
+        assert!(max_error(&context.decode(&plaintext), &x) < 1e-9);
+
+        let z: Vec<Complex64> = x.iter().map(|&v| Complex64::new(v, -v / 2.0)).collect();
+        let decoded =
+            context.decode_complex(&context.encode_complex(&z, 0, 2f64.powi(30)).unwrap());
+        assert!(z.iter().zip(&decoded).all(|(a, b)| (a - b).norm() < 1e-6));
+
+        assert!(context.encode(&values(513, 0.0), 2, 2f64.powi(40)).is_err());
+        assert!(context.encode(&x, 3, 2f64.powi(40)).is_err());
+        // 1e9 spread over 512 slots still needs ~61 bits at scale 2^40
+        assert!(context.encode(&[1e9], 0, 2f64.powi(40)).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_error_bound() {
+        let (context, secret, public, _) = setup();
+        let x = values(512, 1.0);
+        let ciphertext = context.encrypt(&context.encode(&x, 2, 2f64.powi(40)).unwrap(), &public);
+        assert_eq!((ciphertext.level(), ciphertext.scale()), (2, 2f64.powi(40)));
+        // Fresh noise is a few thousand units in the slots, well under 1e-6 * 2^40
+        let decrypted = context.decode(&context.decrypt(&ciphertext, &secret));
+        assert!(max_error(&decrypted, &x) < 1e-6);
+
+        let (_, other, ..) = setup();
+        let wrong = context.decode(&context.decrypt(&ciphertext, &other));
+        assert!(max_error(&wrong, &x) > 1.0);
+    }
+
+    #[test]
+    fn test_homomorphic_add_and_sub() {
+        let (context, secret, public, _) = setup();
+        let scale = context.params().scale();
+        let (x, y) = (values(100, 0.0), values(100, 2.0));
+        let cx = context.encrypt(&context.encode(&x, 2, scale).unwrap(), &public);
+        let cy = context.encrypt(&context.encode(&y, 2, scale).unwrap(), &public);
+
+        let sum = context.decode(&context.decrypt(&context.add(&cx, &cy).unwrap(), &secret));
+        let expected: Vec<f64> = x.iter().zip(&y).map(|(a, b)| a + b).collect();
+        assert!(max_error(&sum[..100], &expected) < 1e-6);
+
+        let diff = context.decode(&context.decrypt(&context.sub(&cx, &cy).unwrap(), &secret));
+        let expected: Vec<f64> = x.iter().zip(&y).map(|(a, b)| a - b).collect();
+        assert!(max_error(&diff[..100], &expected) < 1e-6);
+
+        let lower = context.drop_level(&cy, 1).unwrap();
+        assert!(context.add(&cx, &lower).is_err());
+        assert!(context.drop_level(&lower, 2).is_err());
+    }
+
+    #[test]
+    fn test_plaintext_multiply_and_rescale() {
+        let (context, secret, public, _) = setup();
+        let scale = context.params().scale();
+        let (x, w) = (values(64, 0.5), values(64, 1.5));
+        let cx = context.encrypt(&context.encode(&x, 2, scale).unwrap(), &public);
+        let product = context
+            .mul_plain(&cx, &context.encode(&w, 2, scale).unwrap())
+            .unwrap();
+        let rescaled = context.rescale(&product).unwrap();
+        assert_eq!(rescaled.level(), 1);
+        // Scale returns to roughly 2^40 after dividing by a 40-bit prime
+        assert!((rescaled.scale().log2() - 40.0).abs() < 0.01);
+
+        let decrypted = context.decode(&context.decrypt(&rescaled, &secret));
+        let expected: Vec<f64> = x.iter().zip(&w).map(|(a, b)| a * b).collect();
+        assert!(max_error(&decrypted[..64], &expected) < 1e-6);
+        assert!(context
+            .mul_plain(&rescaled, &context.encode(&w, 2, scale).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_ciphertext_multiply_relinearize_rescale() {
+        let (context, secret, public, relin) = setup();
+        let scale = context.params().scale();
+        let (x, y) = (values(512, 0.0), values(512, 0.7));
+        let cx = context.encrypt(&context.encode(&x, 2, scale).unwrap(), &public);
+        let cy = context.encrypt(&context.encode(&y, 2, scale).unwrap(), &public);
+
+        // Depth two: (x * y) * x, consuming both levels
+        let xy = context
+            .rescale(&context.mul(&cx, &cy, &relin).unwrap())
+            .unwrap();
+        let x1 = context.drop_level(&cx, 1).unwrap();
+        let xyx = context
+            .rescale(&context.mul(&xy, &x1, &relin).unwrap())
+            .unwrap();
+        assert_eq!(xyx.level(), 0);
+        assert!(context.rescale(&xyx).is_err());
+
+        let decrypted = context.decode(&context.decrypt(&xy, &secret));
+        let expected: Vec<f64> = x.iter().zip(&y).map(|(a, b)| a * b).collect();
+        assert!(max_error(&decrypted, &expected) < 1e-5);
+
+        let decrypted = context.decode(&context.decrypt(&xyx, &secret));
+        let expected: Vec<f64> = expected.iter().zip(&x).map(|(a, b)| a * b).collect();
+        assert!(max_error(&decrypted, &expected) < 1e-5);
+    }
+
+    #[test]
+    fn test_rotation_and_conjugation() {
+        let (context, secret, public, _) = setup();
+        let mut keygen = KeyGenerator::new(&context);
+        let keys = keygen.galois_keys(&secret, &[1, -3, 512], true);
+        // Rotation by a multiple of the slot count needs no key
+        assert_eq!(keys.len(), 3);
+
+        let x = values(512, 0.3);
+        let cx = context.encrypt(
+            &context.encode(&x, 2, context.params().scale()).unwrap(),
+            &public,
+        );
+        let left =
+            context.decode(&context.decrypt(&context.rotate(&cx, 1, &keys).unwrap(), &secret));
+        let expected: Vec<f64> = (0..512).map(|j| x[(j + 1) % 512]).collect();
+        assert!(max_error(&left, &expected) < 1e-6);
+
+        let right =
+            context.decode(&context.decrypt(&context.rotate(&cx, -3, &keys).unwrap(), &secret));
+        let expected: Vec<f64> = (0..512).map(|j| x[(j + 509) % 512]).collect();
+        assert!(max_error(&right, &expected) < 1e-6);
+        assert!(context.rotate(&cx, 2, &keys).is_err());
+
+        let z: Vec<Complex64> = x.iter().map(|&v| Complex64::new(v, 0.5 - v)).collect();
+        let cz = context.encrypt(
+            &context
+                .encode_complex(&z, 1, context.params().scale())
+                .unwrap(),
+            &public,
+        );
+        let conjugated = context
+            .decode_complex(&context.decrypt(&context.conjugate(&cz, &keys).unwrap(), &secret));
+        assert!(z
+            .iter()
+            .zip(&conjugated)
+            .all(|(a, b)| (a.conj() - b).norm() < 1e-6));
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Canonical embedding between n/2 complex slots and real polynomials of
+//! Z[X]/(X^n + 1): slot j holds m(zeta^(5^j)) with zeta = exp(i pi / n).
+//! Both directions use the O(n log n) special FFT over the 5-power orbit.
+
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    n: usize,
+    /// 5^j mod 2n
+    rot_group: Vec<usize>,
+    /// exp(2 pi i k / 2n) for k in 0..=2n
+    ksi: Vec<Complex64>,
+}
+
+fn bit_reverse(values: &mut [Complex64]) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+impl Encoder {
+    pub fn new(n: usize) -> Self {
+        let m = 2 * n;
+        let mut rot_group = Vec::with_capacity(n / 2);
+        let mut power = 1;
+        for _ in 0..n / 2 {
+            rot_group.push(power);
+            power = power * 5 % m;
+        }
+        let ksi = (0..=m)
+            .map(|k| Complex64::from_polar(1.0, 2.0 * PI * k as f64 / m as f64))
+            .collect();
+        Self { n, rot_group, ksi }
+    }
+
+    pub fn slots(&self) -> usize {
+        self.n / 2
+    }
+
+    /// Real coefficients (unscaled) of the polynomial whose slots are `values`,
+    /// zero-padded to n/2 slots
+    pub fn embed_inverse(&self, values: &[Complex64]) -> Vec<f64> {
+        let slots = self.slots();
+        let mut v = vec![Complex64::new(0.0, 0.0); slots];
+        v[..values.len()].copy_from_slice(values);
+        self.special_fft_inverse(&mut v);
+
+        let mut coeffs = vec![0.0; self.n];
+        for (i, value) in v.iter().enumerate() {
+            coeffs[i] = value.re;
+            coeffs[i + slots] = value.im;
+        }
+        coeffs
+    }
+
+    /// Slot values of a real polynomial given by its (unscaled) coefficients
+    pub fn embed(&self, coeffs: &[f64]) -> Vec<Complex64> {
+        let slots = self.slots();
+        let mut v: Vec<Complex64> = (0..slots)
+            .map(|i| Complex64::new(coeffs[i], coeffs[i + slots]))
+            .collect();
+        self.special_fft(&mut v);
+        v
+    }
+
+    fn special_fft(&self, v: &mut [Complex64]) {
+        let size = v.len();
+        let m = 2 * self.n;
+        bit_reverse(v);
+        let mut len = 2;
+        while len <= size {
+            let (lenh, lenq) = (len >> 1, len << 2);
+            for i in (0..size).step_by(len) {
+                for j in 0..lenh {
+                    let idx = (self.rot_group[j] % lenq) * (m / lenq);
+                    let u = v[i + j];
+                    let w = v[i + j + lenh] * self.ksi[idx];
+                    v[i + j] = u + w;
+                    v[i + j + lenh] = u - w;
+                }
+            }
+            len <<= 1;
+        }
+    }
+
+    fn special_fft_inverse(&self, v: &mut [Complex64]) {
+        let size = v.len();
+        let m = 2 * self.n;
+        let mut len = size;
+        while len >= 1 {
+            let (lenh, lenq) = (len >> 1, len << 2);
+            for i in (0..size).step_by(len) {
+                for j in 0..lenh {
+                    let idx = (lenq - self.rot_group[j] % lenq) * (m / lenq);
+                    let u = v[i + j] + v[i + j + lenh];
+                    let w = (v[i + j] - v[i + j + lenh]) * self.ksi[idx];
+                    v[i + j] = u;
+                    v[i + j + lenh] = w;
+                }
+            }
+            len >>= 1;
+        }
+        bit_reverse(v);
+        let scale = 1.0 / size as f64;
+        for x in v.iter_mut() {
+            *x *= scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedding_matches_polynomial_evaluation() {
+        let n = 16;
+        let encoder = Encoder::new(n);
+        let values: Vec<Complex64> = (0..n / 2)
+            .map(|j| Complex64::new(j as f64 * 0.5 - 1.0, 0.25 * j as f64))
+            .collect();
+        let coeffs = encoder.embed_inverse(&values);
+
+        // m(zeta^(5^j)) evaluated directly
+        for (j, expected) in values.iter().enumerate() {
+            let root = Complex64::from_polar(1.0, PI * encoder.rot_group[j] as f64 / n as f64);
+            let value: Complex64 = coeffs
+                .iter()
+                .enumerate()
+                .map(|(k, &c)| root.powu(k as u32) * c)
+                .sum();
+            assert!((value - expected).norm() < 1e-12);
+        }
+
+        let back = encoder.embed(&coeffs);
+        for (a, b) in back.iter().zip(&values) {
+            assert!((a - b).norm() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use super::arith::{add_mod, mul_mod, neg_mod};
+use super::context::{CkksContext, RnsPoly};
+use rand::rngs::StdRng;
+use std::collections::BTreeMap;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Ternary secret s, kept in NTT form over q_0..q_L and P; wiped on drop
+pub struct SecretKey {
+    pub(crate) ntt: RnsPoly,
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.ntt.zeroize();
+    }
+}
+
+/// Encryption of zero (b, a) with b = -a s + e over q_0..q_L
+pub struct PublicKey {
+    pub(crate) b: RnsPoly,
+    pub(crate) a: RnsPoly,
+}
+
+/// One (b_i, a_i) pair per chain prime over q_0..q_L and P, with
+/// b_i = -a_i s + e_i + P [q_i-digit] s' so that key switching maps s' to s
+pub struct KeySwitchKey {
+    pub(crate) components: Vec<(RnsPoly, RnsPoly)>,
+}
+
+/// Key-switching key from s^2 to s
+pub struct RelinKey(pub(crate) KeySwitchKey);
+
+/// Key-switching keys from s(X^g) to s, indexed by Galois element g
+#[derive(Default)]
+pub struct GaloisKeys {
+    pub(crate) keys: BTreeMap<u64, KeySwitchKey>,
+}
+
+impl GaloisKeys {
+    pub fn get(&self, element: u64) -> Option<&KeySwitchKey> {
+        self.keys.get(&element)
+    }
+
+    /// Galois elements with a key, ascending
+    pub fn elements(&self) -> impl Iterator<Item = u64> + '_ {
+        self.keys.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// Samples keys for one context from a freshly seeded CSPRNG
+pub struct KeyGenerator<'a> {
+    context: &'a CkksContext,
+    rng: StdRng,
+}
+
+impl<'a> KeyGenerator<'a> {
+    pub fn new(context: &'a CkksContext) -> Self {
+        Self {
+            context,
+            rng: CkksContext::rng(),
+        }
+    }
+
+    pub fn secret_key(&mut self) -> SecretKey {
+        let mut coeffs = self.context.sample_ternary(&mut self.rng);
+        let basis = self.full_basis();
+        let key = SecretKey {
+            ntt: self.context.lift(&coeffs, &basis),
+        };
+        coeffs.zeroize();
+        key
+    }
+
+    pub fn public_key(&mut self, secret: &SecretKey) -> PublicKey {
+        let basis = self.context.basis(self.context.params().max_level(), false);
+        let (b, a) = self.encrypt_zero(secret, &basis);
+        PublicKey { b, a }
+    }
+
+    pub fn relin_key(&mut self, secret: &SecretKey) -> RelinKey {
+        let basis = self.full_basis();
+        let mut squared = self.context.mul_poly(&secret.ntt, &secret.ntt, &basis);
+        let key = RelinKey(self.switching_key(secret, &squared));
+        squared.zeroize();
+        key
+    }
+
+    /// Rotation keys for each of `steps` (slot rotations, negative for right),
+    /// plus the conjugation key if requested
+    pub fn galois_keys(
+        &mut self,
+        secret: &SecretKey,
+        steps: &[i64],
+        conjugate: bool,
+    ) -> GaloisKeys {
+        let mut elements = self.context.rotation_elements(steps);
+        if conjugate {
+            elements.push(self.context.conjugation_element());
+        }
+        let mut keys = GaloisKeys::default();
+        self.add_galois_keys(secret, &elements, &mut keys);
+        keys
+    }
+
+    /// Add keys for any of `elements` missing from `keys`
+    pub fn add_galois_keys(&mut self, secret: &SecretKey, elements: &[u64], keys: &mut GaloisKeys) {
+        let basis = self.full_basis();
+        for &g in elements {
+            if keys.keys.contains_key(&g) {
+                continue;
+            }
+            let mut rotated = self.context.automorphism(&secret.ntt, &basis, g);
+            let key = self.switching_key(secret, &rotated);
+            rotated.zeroize();
+            keys.keys.insert(g, key);
+        }
+    }
+
+    /// Key that switches ciphertexts under `source` (NTT form over the full
+    /// basis) to ciphertexts under `secret`
+    pub(crate) fn switching_key(&mut self, secret: &SecretKey, source: &RnsPoly) -> KeySwitchKey {
+        let basis = self.full_basis();
+        let special = self.context.params().special_modulus();
+        let components = (0..=self.context.params().max_level())
+            .map(|i| {
+                let (mut b, a) = self.encrypt_zero(secret, &basis);
+                let q = self.context.modulus(i);
+                let factor = special % q;
+                for (x, &s) in b[i].iter_mut().zip(&source[i]) {
+                    *x = add_mod(*x, mul_mod(factor, s, q), q);
+                }
+                (b, a)
+            })
+            .collect();
+        KeySwitchKey { components }
+    }
+
+    /// (-a s + e, a) over `basis`, a prefix of the full basis
+    fn encrypt_zero(&mut self, secret: &SecretKey, basis: &[usize]) -> (RnsPoly, RnsPoly) {
+        let a = self.context.sample_uniform(&mut self.rng, basis);
+        // Together with (b, a), the error reveals a s and hence the secret
+        let error = Zeroizing::new(self.context.sample_error(&mut self.rng));
+        let e = Zeroizing::new(self.context.lift(&error, basis));
+        let s = &secret.ntt[..basis.len()];
+        let b = a
+            .iter()
+            .zip(s)
+            .zip(e.iter())
+            .zip(basis)
+            .map(|(((a, s), e), &i)| {
+                let q = self.context.modulus(i);
+                a.iter()
+                    .zip(s)
+                    .zip(e)
+                    .map(|((&a, &s), &e)| add_mod(neg_mod(mul_mod(a, s, q), q), e, q))
+                    .collect()
+            })
+            .collect();
+        (b, a)
+    }
+
+    fn full_basis(&self) -> Vec<usize> {
+        self.context.basis(self.context.params().max_level(), true)
+    }
+}
@@ -0,0 +1,219 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Affine maps y = M x + c on packed ciphertexts by the diagonal method.
+//!
+//! With x in slots 0..cols (zero elsewhere), y_j = sum_i d_i[j] x[j + i]
+//! where the generalized diagonal d_i[j] = M[j][j + i] for i in
+//! -(rows - 1)..cols. Each term is one rotation of x by i slots and one
+//! plaintext product; entries outside the matrix are zero, which masks any
+//! slots that wrap around. Diagonals are encoded at the scale of the prime
+//! that the single closing rescale removes, so the result keeps the input's
+//! scale and sits one level lower.
+
+use super::context::{Ciphertext, CkksContext};
+use super::keys::GaloisKeys;
+use crate::error::{DeoxysError, Result};
+use ndarray::{Array1, Array2};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearTransform {
+    rows: usize,
+    cols: usize,
+    /// (rotation, diagonal) for each non-zero generalized diagonal
+    diagonals: Vec<(i64, Vec<f64>)>,
+    offset: Option<Vec<f64>>,
+}
+
+impl LinearTransform {
+    pub fn new(matrix: &Array2<f64>) -> Result<Self> {
+        if matrix.iter().any(|v| !v.is_finite()) {
+            return Err(DeoxysError::config(
+                "linear transform",
+                "matrix must be finite",
+            ));
+        }
+        let (rows, cols) = matrix.dim();
+        let diagonals = (-(rows as i64 - 1)..cols as i64)
+            .filter_map(|i| {
+                let diagonal: Vec<f64> = (0..rows)
+                    .map(|j| {
+                        let k = j as i64 + i;
+                        if (0..cols as i64).contains(&k) {
+                            matrix[[j, k as usize]]
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect();
+                diagonal.iter().any(|&v| v != 0.0).then_some((i, diagonal))
+            })
+            .collect();
+        Ok(Self {
+            rows,
+            cols,
+            diagonals,
+            offset: None,
+        })
+    }
+
+    /// Add a constant c to the result
+    pub fn with_offset(mut self, offset: &Array1<f64>) -> Result<Self> {
+        if offset.len() != self.rows {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "linear transform offset",
+                expected: self.rows,
+                actual: offset.len(),
+            });
+        }
+        if offset.iter().any(|v| !v.is_finite()) {
+            return Err(DeoxysError::config(
+                "linear transform",
+                "offset must be finite",
+            ));
+        }
+        self.offset = Some(offset.to_vec());
+        Ok(self)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Slot rotations whose Galois keys `evaluate` needs
+    pub fn rotations(&self) -> Vec<i64> {
+        self.diagonals
+            .iter()
+            .map(|&(i, _)| i)
+            .filter(|&i| i != 0)
+            .collect()
+    }
+
+    /// Plaintext reference M x + c
+    pub fn apply(&self, x: &Array1<f64>) -> Array1<f64> {
+        let mut y = Array1::zeros(self.rows);
+        for (i, diagonal) in &self.diagonals {
+            for (j, d) in diagonal.iter().enumerate() {
+                let k = j as i64 + i;
+                if (0..x.len() as i64).contains(&k) {
+                    y[j] += d * x[k as usize];
+                }
+            }
+        }
+        if let Some(offset) = &self.offset {
+            y += &Array1::from_vec(offset.clone());
+        }
+        y
+    }
+
+    /// Evaluate on a ciphertext holding x in slots 0..cols; consumes one level
+    pub fn evaluate(
+        &self,
+        context: &CkksContext,
+        x: &Ciphertext,
+        keys: &GaloisKeys,
+    ) -> Result<Ciphertext> {
+        let slots = context.params().slots();
+        if self.rows.max(self.cols) > slots {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "ckks slots",
+                expected: slots,
+                actual: self.rows.max(self.cols),
+            });
+        }
+        if x.level() == 0 {
+            return Err(DeoxysError::crypto(
+                "ckks linear transform",
+                "no level left to consume",
+            ));
+        }
+        let scale = context.modulus(x.level()) as f64;
+        let mut sum: Option<Ciphertext> = None;
+        for (i, diagonal) in &self.diagonals {
+            let rotated = context.rotate(x, *i, keys)?;
+            let term = context.mul_plain(&rotated, &context.encode(diagonal, x.level(), scale)?)?;
+            sum = Some(match sum {
+                Some(acc) => context.add(&acc, &term)?,
+                None => term,
+            });
+        }
+        let result = match sum {
+            Some(sum) => context.rescale(&sum)?,
+            // Zero matrix: x times an all-zero plaintext
+            None => {
+                context.rescale(&context.mul_plain(x, &context.encode(&[], x.level(), scale)?)?)?
+            }
+        };
+        match &self.offset {
+            Some(offset) => {
+                let constant = context.encode(offset, result.level(), result.scale())?;
+                context.add_plain(&result, &constant)
+            }
+            None => Ok(result),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ckks::{CkksParameters, KeyGenerator};
+    use ndarray::array;
+
+    #[test]
+    fn test_rectangular_transform_matches_plaintext() {
+        let context =
+            CkksContext::new(CkksParameters::generate(10, &[60, 40, 40], 60, 40).unwrap());
+        let m = array![
+            [1.0, -2.0, 0.5],
+            [0.0, 3.0, -1.0],
+            [0.25, 0.0, 0.0],
+            [-1.5, 1.0, 2.0]
+        ];
+        let c = array![0.1, -0.2, 0.3, 0.0];
+        let transform = LinearTransform::new(&m).unwrap().with_offset(&c).unwrap();
+        assert_eq!(transform.rotations(), vec![-3, -2, -1, 1, 2]);
+
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let keys = keygen.galois_keys(&secret, &transform.rotations(), false);
+
+        let x = array![0.3, -0.7, 0.9];
+        let ct = context.encrypt(
+            &context
+                .encode(&x.to_vec(), 2, context.params().scale())
+                .unwrap(),
+            &public,
+        );
+        let y = transform.evaluate(&context, &ct, &keys).unwrap();
+        assert_eq!(y.level(), 1);
+        assert_eq!(y.scale(), ct.scale());
+
+        let decrypted = context.decode(&context.decrypt(&y, &secret));
+        let expected = m.dot(&x) + &c;
+        assert_eq!(transform.apply(&x), expected);
+        for (j, value) in decrypted.iter().enumerate() {
+            let want = expected.get(j).copied().unwrap_or(0.0);
+            assert!(
+                (value - want).abs() < 1e-6,
+                "slot {}: {} vs {}",
+                j,
+                value,
+                want
+            );
+        }
+
+        let lowest = context.drop_level(&ct, 0).unwrap();
+        assert!(transform.evaluate(&context, &lowest, &keys).is_err());
+        assert!(LinearTransform::new(&m)
+            .unwrap()
+            .with_offset(&array![1.0])
+            .is_err());
+    }
+}
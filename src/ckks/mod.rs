@@ -0,0 +1,38 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Approximate homomorphic encryption (CKKS) over Z[X]/(X^N + 1).
+//!
+//! Polynomials are held in RNS form over a chain of NTT-friendly primes
+//! q_0..q_L, each residue in NTT (evaluation) form so that ring products are
+//! slot-wise. Real or complex vectors of up to N/2 values are mapped through
+//! the inverse canonical embedding and scaled by Δ before rounding; a
+//! ciphertext at level l lives modulo q_0..q_l and every rescale divides by
+//! q_l, consuming one level. Relinearization uses hybrid key switching with a
+//! single special prime P; the same machinery provides slot rotations and
+//! conjugation through Galois keys.
+//!
+//! Keys and ciphertexts serialize to a versioned binary format bound to the
+//! parameter set (see [`BinaryFormat`]); secret keys are wiped on drop.
+//!
+//! Decoding recovers the message modulo q_0 only, so q_0 must exceed twice
+//! the largest scaled slot coefficient at the final scale.
+
+mod arith;
+mod context;
+mod encoder;
+mod keys;
+mod linear;
+mod ntt;
+mod params;
+mod poly;
+mod serial;
+
+pub use context::{Ciphertext, CkksContext, Plaintext};
+pub use encoder::Encoder;
+pub use keys::{GaloisKeys, KeyGenerator, KeySwitchKey, PublicKey, RelinKey, SecretKey};
+pub use linear::LinearTransform;
+pub use params::{CkksParameters, ParameterSet, SecurityLevel};
+pub use poly::polynomial_depth;
+pub use serial::{BinaryFormat, ObjectKind, Reader, Writer, FORMAT_VERSION, MAGIC};
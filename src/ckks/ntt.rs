@@ -0,0 +1,147 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Negacyclic number-theoretic transform over Z_q[X]/(X^n + 1)
+//! (Longa-Naehrig, twiddles in bit-reversed order; output in bit-reversed order).
+
+use super::arith::{add_mod, inv_mod, mul_shoup, pow_mod, primitive_root, shoup, sub_mod};
+
+#[derive(Debug, Clone)]
+pub struct NttTable {
+    q: u64,
+    roots: Vec<u64>,
+    roots_shoup: Vec<u64>,
+    inv_roots: Vec<u64>,
+    inv_roots_shoup: Vec<u64>,
+    n_inv: u64,
+    n_inv_shoup: u64,
+}
+
+fn bit_reverse(i: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        i.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+impl NttTable {
+    pub fn new(n: usize, q: u64) -> Self {
+        let bits = n.trailing_zeros();
+        let psi = primitive_root(2 * n as u64, q);
+        let psi_inv = inv_mod(psi, q);
+        let roots: Vec<u64> = (0..n)
+            .map(|i| pow_mod(psi, bit_reverse(i, bits) as u64, q))
+            .collect();
+        let inv_roots: Vec<u64> = (0..n)
+            .map(|i| pow_mod(psi_inv, bit_reverse(i, bits) as u64, q))
+            .collect();
+        let n_inv = inv_mod(n as u64, q);
+        Self {
+            q,
+            roots_shoup: roots.iter().map(|&w| shoup(w, q)).collect(),
+            inv_roots_shoup: inv_roots.iter().map(|&w| shoup(w, q)).collect(),
+            roots,
+            inv_roots,
+            n_inv,
+            n_inv_shoup: shoup(n_inv, q),
+        }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.q
+    }
+
+    pub fn forward(&self, a: &mut [u64]) {
+        let (n, q) = (a.len(), self.q);
+        let mut t = n;
+        let mut m = 1;
+        while m < n {
+            t >>= 1;
+            for i in 0..m {
+                let (w, w_shoup) = (self.roots[m + i], self.roots_shoup[m + i]);
+                let j1 = 2 * i * t;
+                for j in j1..j1 + t {
+                    let u = a[j];
+                    let v = mul_shoup(a[j + t], w, w_shoup, q);
+                    a[j] = add_mod(u, v, q);
+                    a[j + t] = sub_mod(u, v, q);
+                }
+            }
+            m <<= 1;
+        }
+    }
+
+    pub fn inverse(&self, a: &mut [u64]) {
+        let (n, q) = (a.len(), self.q);
+        let mut t = 1;
+        let mut m = n;
+        while m > 1 {
+            let h = m / 2;
+            let mut j1 = 0;
+            for i in 0..h {
+                let (w, w_shoup) = (self.inv_roots[h + i], self.inv_roots_shoup[h + i]);
+                for j in j1..j1 + t {
+                    let u = a[j];
+                    let v = a[j + t];
+                    a[j] = add_mod(u, v, q);
+                    a[j + t] = mul_shoup(sub_mod(u, v, q), w, w_shoup, q);
+                }
+                j1 += 2 * t;
+            }
+            t <<= 1;
+            m = h;
+        }
+        for x in a.iter_mut() {
+            *x = mul_shoup(*x, self.n_inv, self.n_inv_shoup, q);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::arith::{mul_mod, ntt_primes};
+    use super::*;
+
+    fn schoolbook(a: &[u64], b: &[u64], q: u64) -> Vec<u64> {
+        let n = a.len();
+        let mut c = vec![0; n];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                let p = mul_mod(x, y, q);
+                let k = (i + j) % n;
+                // X^n = -1
+                c[k] = if i + j >= n {
+                    sub_mod(c[k], p, q)
+                } else {
+                    add_mod(c[k], p, q)
+                };
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn test_negacyclic_convolution() {
+        let n = 64;
+        let q = ntt_primes(50, 1, n, &[]).unwrap()[0];
+        let table = NttTable::new(n, q);
+        let a: Vec<u64> = (0..n as u64).map(|i| (i * 7919 + 3) % q).collect();
+        let b: Vec<u64> = (0..n as u64).map(|i| q - 1 - i * i).collect();
+
+        let (mut fa, mut fb) = (a.clone(), b.clone());
+        table.forward(&mut fa);
+        table.forward(&mut fb);
+        let mut c: Vec<u64> = fa
+            .iter()
+            .zip(&fb)
+            .map(|(&x, &y)| mul_mod(x, y, q))
+            .collect();
+        table.inverse(&mut c);
+        assert_eq!(c, schoolbook(&a, &b, q));
+
+        table.inverse(&mut fa);
+        assert_eq!(fa, a);
+    }
+}
@@ -0,0 +1,393 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use super::arith::ntt_primes;
+use crate::error::{DeoxysError, Result};
+use serde::Serialize;
+use std::fmt;
+
+/// Classical security target against the lattice attacks of the
+/// Homomorphic Encryption Standard (ternary secret, sigma = 3.2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum SecurityLevel {
+    Bits128,
+    Bits192,
+    Bits256,
+}
+
+impl SecurityLevel {
+    pub fn bits(self) -> u32 {
+        match self {
+            Self::Bits128 => 128,
+            Self::Bits192 => 192,
+            Self::Bits256 => 256,
+        }
+    }
+
+    /// Largest admissible log2(Q P) for ring degree 2^`log_degree`, from the
+    /// standard's tables; `None` for degrees it does not cover
+    pub fn max_log_qp(self, log_degree: u32) -> Option<u32> {
+        let row = match log_degree {
+            10 => [27, 19, 14],
+            11 => [54, 37, 29],
+            12 => [109, 75, 58],
+            13 => [218, 152, 118],
+            14 => [438, 305, 237],
+            15 => [881, 611, 476],
+            _ => return None,
+        };
+        Some(match self {
+            Self::Bits128 => row[0],
+            Self::Bits192 => row[1],
+            Self::Bits256 => row[2],
+        })
+    }
+}
+
+impl fmt::Display for SecurityLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-bit", self.bits())
+    }
+}
+
+/// Named parameter presets, each within its security level's modulus budget.
+/// The numeric code identifies the set in serialized headers; 0 is custom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ParameterSet {
+    /// n = 2^13, q = 60 + 2 x 40, P = 60 (200 bits), scale 2^40, depth 2
+    N8192Depth2Bits128,
+    /// n = 2^14, q = 60 + 6 x 40, P = 60 (360 bits), scale 2^40, depth 6
+    N16384Depth6Bits128,
+    /// n = 2^14, q = 60 + 4 x 40, P = 60 (280 bits), scale 2^40, depth 4
+    N16384Depth4Bits192,
+    /// n = 2^14, q = 58 + 3 x 40, P = 58 (236 bits), scale 2^40, depth 3
+    N16384Depth3Bits256,
+    /// n = 2^15, q = 60 + 8 x 40, P = 60 (440 bits), scale 2^40, depth 8
+    N32768Depth8Bits256,
+}
+
+impl ParameterSet {
+    pub const ALL: [ParameterSet; 5] = [
+        Self::N8192Depth2Bits128,
+        Self::N16384Depth6Bits128,
+        Self::N16384Depth4Bits192,
+        Self::N16384Depth3Bits256,
+        Self::N32768Depth8Bits256,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Self::N8192Depth2Bits128 => "ckks-n8192-d2-128",
+            Self::N16384Depth6Bits128 => "ckks-n16384-d6-128",
+            Self::N16384Depth4Bits192 => "ckks-n16384-d4-192",
+            Self::N16384Depth3Bits256 => "ckks-n16384-d3-256",
+            Self::N32768Depth8Bits256 => "ckks-n32768-d8-256",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|set| set.id() == id)
+    }
+
+    pub fn code(self) -> u8 {
+        match self {
+            Self::N8192Depth2Bits128 => 1,
+            Self::N16384Depth6Bits128 => 2,
+            Self::N16384Depth4Bits192 => 3,
+            Self::N16384Depth3Bits256 => 4,
+            Self::N32768Depth8Bits256 => 5,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        Self::ALL.into_iter().find(|set| set.code() == code)
+    }
+
+    pub fn security(self) -> SecurityLevel {
+        match self {
+            Self::N8192Depth2Bits128 | Self::N16384Depth6Bits128 => SecurityLevel::Bits128,
+            Self::N16384Depth4Bits192 => SecurityLevel::Bits192,
+            Self::N16384Depth3Bits256 | Self::N32768Depth8Bits256 => SecurityLevel::Bits256,
+        }
+    }
+
+    pub fn log_degree(self) -> u32 {
+        self.shape().0
+    }
+
+    /// Highest ciphertext level L of the preset's chain
+    pub fn max_level(self) -> usize {
+        self.shape().1.len() - 1
+    }
+
+    /// (log_degree, chain bits, special bits, log_scale)
+    fn shape(self) -> (u32, Vec<u32>, u32, u32) {
+        let chain = |base: u32, depth: usize| {
+            std::iter::once(base)
+                .chain(std::iter::repeat_n(40, depth))
+                .collect::<Vec<u32>>()
+        };
+        match self {
+            Self::N8192Depth2Bits128 => (13, chain(60, 2), 60, 40),
+            Self::N16384Depth6Bits128 => (14, chain(60, 6), 60, 40),
+            Self::N16384Depth4Bits192 => (14, chain(60, 4), 60, 40),
+            Self::N16384Depth3Bits256 => (14, chain(58, 3), 58, 40),
+            Self::N32768Depth8Bits256 => (15, chain(60, 8), 60, 40),
+        }
+    }
+}
+
+impl fmt::Display for ParameterSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.id())
+    }
+}
+
+/// Ring degree, RNS modulus chain q_0..q_L, key-switching prime P and default scale
+#[derive(Debug, Clone, PartialEq)]
+pub struct CkksParameters {
+    log_degree: u32,
+    moduli: Vec<u64>,
+    special: u64,
+    scale: f64,
+    /// Level the parameters were validated against; `None` only for
+    /// crate-internal unchecked parameters
+    security: Option<SecurityLevel>,
+    set: Option<ParameterSet>,
+}
+
+impl CkksParameters {
+    pub fn preset(set: ParameterSet) -> Self {
+        let (log_degree, chain, special, log_scale) = set.shape();
+        let mut params = Self::custom(log_degree, &chain, special, log_scale, set.security())
+            .expect("preset CKKS parameters are valid and secure");
+        params.set = Some(set);
+        params
+    }
+
+    /// Custom chain, refused unless log2(Q P) fits the standard's budget for
+    /// the ring degree at the requested security level
+    pub fn custom(
+        log_degree: u32,
+        modulus_bits: &[u32],
+        special_bits: u32,
+        log_scale: u32,
+        security: SecurityLevel,
+    ) -> Result<Self> {
+        let Some(limit) = security.max_log_qp(log_degree) else {
+            return Err(DeoxysError::config(
+                "ckks ring degree",
+                format!(
+                    "2^{} is not covered by the {} security table",
+                    log_degree, security
+                ),
+            ));
+        };
+        let mut params = Self::generate(log_degree, modulus_bits, special_bits, log_scale)?;
+        if params.log_qp() > limit as f64 {
+            return Err(DeoxysError::config(
+                "ckks modulus chain",
+                format!(
+                    "log2(QP) = {:.1} exceeds {} for n = 2^{} at {}",
+                    params.log_qp(),
+                    limit,
+                    log_degree,
+                    security
+                ),
+            ));
+        }
+        params.security = Some(security);
+        Ok(params)
+    }
+
+    /// Generate NTT-friendly primes for the requested chain, without any
+    /// security check (tests use small rings). `modulus_bits[0]`
+    /// is the base prime q_0, which must leave headroom above the scale for the
+    /// decoded message; each following prime is consumed by one rescale and is
+    /// normally sized like the scale. The special prime must be at least as
+    /// large as every chain prime.
+    pub(crate) fn generate(
+        log_degree: u32,
+        modulus_bits: &[u32],
+        special_bits: u32,
+        log_scale: u32,
+    ) -> Result<Self> {
+        if !(4..=16).contains(&log_degree) {
+            return Err(DeoxysError::config(
+                "ckks ring degree",
+                format!("2^{} outside 2^4..=2^16", log_degree),
+            ));
+        }
+        if modulus_bits.is_empty() {
+            return Err(DeoxysError::config(
+                "ckks modulus chain",
+                "must contain at least one prime",
+            ));
+        }
+        if special_bits < modulus_bits.iter().copied().max().unwrap_or(0) {
+            return Err(DeoxysError::config(
+                "ckks special prime",
+                format!(
+                    "{} bits is smaller than the largest chain prime",
+                    special_bits
+                ),
+            ));
+        }
+        if log_scale == 0 || log_scale >= modulus_bits[0] {
+            return Err(DeoxysError::config(
+                "ckks scale",
+                format!("2^{} must lie below the base prime q_0", log_scale),
+            ));
+        }
+
+        let n = 1usize << log_degree;
+        let mut moduli: Vec<u64> = Vec::with_capacity(modulus_bits.len());
+        for &bits in modulus_bits {
+            let prime = ntt_primes(bits, 1, n, &moduli)?[0];
+            moduli.push(prime);
+        }
+        let special = ntt_primes(special_bits, 1, n, &moduli)?[0];
+        Ok(Self {
+            log_degree,
+            moduli,
+            special,
+            scale: 2f64.powi(log_scale as i32),
+            security: None,
+            set: None,
+        })
+    }
+
+    pub fn degree(&self) -> usize {
+        1 << self.log_degree
+    }
+
+    pub fn log_degree(&self) -> u32 {
+        self.log_degree
+    }
+
+    /// Complex slots per ciphertext, n/2
+    pub fn slots(&self) -> usize {
+        self.degree() / 2
+    }
+
+    /// Chain primes q_0..q_L
+    pub fn moduli(&self) -> &[u64] {
+        &self.moduli
+    }
+
+    pub fn special_modulus(&self) -> u64 {
+        self.special
+    }
+
+    /// Highest ciphertext level L; a fresh ciphertext supports L rescales
+    pub fn max_level(&self) -> usize {
+        self.moduli.len() - 1
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn security(&self) -> Option<SecurityLevel> {
+        self.security
+    }
+
+    pub fn parameter_set(&self) -> Option<ParameterSet> {
+        self.set
+    }
+
+    /// Preset id, or a description of custom parameters, for receipts
+    pub fn id(&self) -> String {
+        match (self.set, self.security) {
+            (Some(set), _) => set.id().to_string(),
+            (None, Some(security)) => format!(
+                "custom-n{}-l{}-{}",
+                self.degree(),
+                self.max_level(),
+                security.bits()
+            ),
+            (None, None) => format!("unchecked-n{}-l{}", self.degree(), self.max_level()),
+        }
+    }
+
+    /// Bit size of Q * P, which determines security for a given ring degree
+    pub fn log_qp(&self) -> f64 {
+        self.moduli
+            .iter()
+            .chain(std::iter::once(&self.special))
+            .map(|&q| (q as f64).log2())
+            .sum()
+    }
+}
+
+impl Default for CkksParameters {
+    /// 128-bit preset: n = 8192, q = 60 + 40 + 40 bits, P = 60 bits, scale 2^40
+    fn default() -> Self {
+        Self::preset(ParameterSet::N8192Depth2Bits128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_distinct_chain() {
+        let params = CkksParameters::generate(12, &[50, 30, 30], 50, 30).unwrap();
+        assert_eq!(params.max_level(), 2);
+        assert_eq!(params.slots(), 2048);
+        assert_ne!(params.moduli()[1], params.moduli()[2]);
+        assert_ne!(params.moduli()[0], params.special_modulus());
+        assert!((params.log_qp() - 160.0).abs() < 0.01);
+
+        assert!(CkksParameters::generate(12, &[50, 30], 40, 30).is_err());
+        assert!(CkksParameters::generate(12, &[30, 30], 40, 30).is_err());
+        assert!(CkksParameters::generate(20, &[50], 50, 30).is_err());
+    }
+
+    #[test]
+    fn test_presets_within_security_tables() {
+        for set in ParameterSet::ALL {
+            let params = CkksParameters::preset(set);
+            let limit = set.security().max_log_qp(params.log_degree()).unwrap();
+            assert!(
+                params.log_qp() <= limit as f64,
+                "{} exceeds its budget",
+                set
+            );
+            assert_eq!(params.parameter_set(), Some(set));
+            assert_eq!(ParameterSet::from_id(&params.id()), Some(set));
+            assert_eq!(ParameterSet::from_code(set.code()), Some(set));
+            assert_eq!(set.log_degree(), params.log_degree());
+            assert_eq!(set.max_level(), params.max_level());
+            // Headroom for the decoded message above the scale
+            assert!((params.moduli()[0] as f64).log2() - params.scale().log2() >= 17.0);
+        }
+        assert_eq!(CkksParameters::default().id(), "ckks-n8192-d2-128");
+        assert_eq!(ParameterSet::from_code(0), None);
+    }
+
+    #[test]
+    fn test_custom_parameters_refused_when_insecure() {
+        let params = CkksParameters::custom(14, &[60, 40, 40, 40], 60, 40, SecurityLevel::Bits256);
+        // 60 + 3 x 40 + 60 = 240 > 237
+        assert!(params.is_err());
+        let params =
+            CkksParameters::custom(14, &[60, 40, 40, 40], 60, 40, SecurityLevel::Bits192).unwrap();
+        assert_eq!(params.security(), Some(SecurityLevel::Bits192));
+        assert_eq!(params.id(), "custom-n16384-l3-192");
+        assert!(CkksParameters::custom(13, &[60, 40, 50], 60, 40, SecurityLevel::Bits128).is_ok());
+        assert!(
+            CkksParameters::custom(13, &[60, 50, 50, 50], 60, 40, SecurityLevel::Bits128).is_err()
+        );
+        // Rings below 2^10 are not covered by the tables
+        assert!(CkksParameters::custom(9, &[20], 20, 10, SecurityLevel::Bits128).is_err());
+        assert_eq!(
+            CkksParameters::generate(10, &[40], 40, 20)
+                .unwrap()
+                .security(),
+            None
+        );
+    }
+}
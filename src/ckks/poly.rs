@@ -0,0 +1,218 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Slot-wise polynomial evaluation p(x) = sum_k c_k x^k.
+//!
+//! Each term c_k x^k (k >= 2) is built as (c_k x^(k - p)) * x^p with p the
+//! largest power of two below k, so the constant is folded into the
+//! shallower factor instead of costing its own level. Powers of two are
+//! squared once and shared. A term then needs
+//!
+//! ```text
+//! depth(1) = 1,  depth(k) = max(depth(k - p), log2 p) + 1
+//! ```
+//!
+//! levels, i.e. ceil(log2 d) + 1 for degree d >= 2.
+
+use super::context::{Ciphertext, CkksContext};
+use super::keys::RelinKey;
+use crate::error::{DeoxysError, Result};
+use std::collections::BTreeMap;
+
+fn largest_power_below(k: usize) -> usize {
+    1 << (usize::BITS - 1 - (k - 1).leading_zeros())
+}
+
+fn term_depth(k: usize) -> usize {
+    if k == 1 {
+        return 1;
+    }
+    let p = largest_power_below(k);
+    term_depth(k - p).max(p.trailing_zeros() as usize) + 1
+}
+
+/// Levels consumed by `evaluate_polynomial` for these coefficients
+pub fn polynomial_depth(coefficients: &[f64]) -> usize {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, &c)| c != 0.0)
+        .map(|(k, _)| term_depth(k))
+        .max()
+        .unwrap_or(0)
+}
+
+impl CkksContext {
+    /// Evaluate p on every slot; consumes `polynomial_depth(coefficients)` levels
+    pub fn evaluate_polynomial(
+        &self,
+        x: &Ciphertext,
+        coefficients: &[f64],
+        relin: &RelinKey,
+    ) -> Result<Ciphertext> {
+        let depth = polynomial_depth(coefficients);
+        if depth > x.level() {
+            return Err(DeoxysError::crypto(
+                "ckks polynomial",
+                format!("needs {} levels, ciphertext has {}", depth, x.level()),
+            ));
+        }
+        let mut powers = BTreeMap::from([(1usize, x.clone())]);
+        let mut sum: Option<Ciphertext> = None;
+        for (k, &c) in coefficients.iter().enumerate().skip(1) {
+            if c == 0.0 {
+                continue;
+            }
+            let term = self.scaled_power(c, k, &mut powers, relin)?;
+            sum = Some(match sum {
+                Some(acc) => {
+                    let level = acc.level().min(term.level());
+                    self.add(
+                        &self.drop_level(&acc, level)?,
+                        &self.drop_level(&term, level)?,
+                    )?
+                }
+                None => term,
+            });
+        }
+        let constant = coefficients.first().copied().unwrap_or(0.0);
+        match sum {
+            Some(sum) => {
+                let c0 = self.encode(
+                    &vec![constant; self.params().slots()],
+                    sum.level(),
+                    sum.scale(),
+                )?;
+                self.add_plain(&sum, &c0)
+            }
+            // Constant polynomial: 0 * x + c0 keeps the input's level
+            None => {
+                let zero = self.encode(&[], x.level(), 1.0)?;
+                let product = self.mul_plain(x, &zero)?;
+                let c0 = self.encode(
+                    &vec![constant; self.params().slots()],
+                    x.level(),
+                    product.scale(),
+                )?;
+                self.add_plain(&product, &c0)
+            }
+        }
+    }
+
+    /// c x^k as a ciphertext
+    fn scaled_power(
+        &self,
+        c: f64,
+        k: usize,
+        powers: &mut BTreeMap<usize, Ciphertext>,
+        relin: &RelinKey,
+    ) -> Result<Ciphertext> {
+        if k == 1 {
+            let x = &powers[&1];
+            let scale = self.modulus(x.level()) as f64;
+            let constant = self.encode(&vec![c; self.params().slots()], x.level(), scale)?;
+            return self.rescale(&self.mul_plain(x, &constant)?);
+        }
+        let p = largest_power_below(k);
+        let low = self.scaled_power(c, k - p, powers, relin)?;
+        let high = self.power_of_two(p, powers, relin)?;
+        self.multiply_aligned(&low, &high, relin)
+    }
+
+    /// x^p for p a power of two, by repeated squaring (memoized)
+    fn power_of_two(
+        &self,
+        p: usize,
+        powers: &mut BTreeMap<usize, Ciphertext>,
+        relin: &RelinKey,
+    ) -> Result<Ciphertext> {
+        if let Some(power) = powers.get(&p) {
+            return Ok(power.clone());
+        }
+        let half = self.power_of_two(p / 2, powers, relin)?;
+        let power = self.multiply_aligned(&half, &half, relin)?;
+        powers.insert(p, power.clone());
+        Ok(power)
+    }
+
+    fn multiply_aligned(
+        &self,
+        a: &Ciphertext,
+        b: &Ciphertext,
+        relin: &RelinKey,
+    ) -> Result<Ciphertext> {
+        let level = a.level().min(b.level());
+        let product = self.mul(
+            &self.drop_level(a, level)?,
+            &self.drop_level(b, level)?,
+            relin,
+        )?;
+        self.rescale(&product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ckks::{CkksParameters, KeyGenerator};
+
+    #[test]
+    fn test_depth_formula() {
+        assert_eq!(polynomial_depth(&[]), 0);
+        assert_eq!(polynomial_depth(&[0.5]), 0);
+        assert_eq!(polynomial_depth(&[0.0, 2.0]), 1);
+        assert_eq!(polynomial_depth(&[0.0, 0.0, 1.0]), 2);
+        assert_eq!(polynomial_depth(&[0.1, 0.8, 0.0, -0.1]), 2);
+        assert_eq!(polynomial_depth(&[0.0, 0.0, 0.0, 0.0, 1.0]), 3);
+        assert_eq!(
+            polynomial_depth(&[0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]),
+            3
+        );
+        assert_eq!(
+            polynomial_depth(&[0.0; 8].into_iter().chain([1.0]).collect::<Vec<_>>()),
+            4
+        );
+    }
+
+    #[test]
+    fn test_polynomial_matches_plaintext_and_depth() {
+        let context =
+            CkksContext::new(CkksParameters::generate(10, &[60, 40, 40, 40], 60, 40).unwrap());
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let relin = keygen.relin_key(&secret);
+
+        let x: Vec<f64> = (0..512).map(|i| -1.5 + 3.0 * i as f64 / 511.0).collect();
+        let ct = context.encrypt(
+            &context.encode(&x, 3, context.params().scale()).unwrap(),
+            &public,
+        );
+        for coefficients in [
+            vec![0.25],
+            vec![0.1, -0.5],
+            vec![0.0, 0.8664, 0.0, -0.1057],
+            vec![0.5, 0.0, 0.2, 0.0, -0.03],
+        ] {
+            let result = context
+                .evaluate_polynomial(&ct, &coefficients, &relin)
+                .unwrap();
+            assert_eq!(result.level(), 3 - polynomial_depth(&coefficients));
+            let decrypted = context.decode(&context.decrypt(&result, &secret));
+            for (xi, yi) in x.iter().zip(&decrypted) {
+                let expected: f64 = coefficients.iter().rev().fold(0.0, |acc, c| acc * xi + c);
+                assert!(
+                    (yi - expected).abs() < 1e-5,
+                    "p({}) = {} vs {}",
+                    xi,
+                    yi,
+                    expected
+                );
+            }
+        }
+        let deep = [0.0; 8].into_iter().chain([1.0]).collect::<Vec<_>>();
+        assert!(context.evaluate_polynomial(&ct, &deep, &relin).is_err());
+    }
+}
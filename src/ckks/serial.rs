@@ -0,0 +1,474 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Versioned little-endian binary format for keys and ciphertexts.
+//!
+//! Every object starts with the same header, which binds it to the exact
+//! parameter set it was produced under:
+//!
+//! ```text
+//! magic "DXCK" | version u8 | kind u8 | parameter set u8 | log_degree u8
+//! | primes u8 | q_0..q_L u64 | P u64 | body
+//! ```
+//!
+//! Polynomials are written residue by residue in NTT form, n u64 words each;
+//! the number of residues follows from the kind and, for ciphertexts, the
+//! level. The parameter set is the preset's code, 0 for custom parameters.
+//! Parsing rejects residues that are not reduced, unknown versions,
+//! mismatched parameters and trailing bytes.
+
+use super::context::{Ciphertext, CkksContext, RnsPoly};
+use super::keys::{GaloisKeys, KeySwitchKey, PublicKey, RelinKey, SecretKey};
+use crate::crypto::write_file;
+use crate::error::{DeoxysError, Result};
+use std::fs;
+use std::path::Path;
+use zeroize::Zeroize;
+
+pub const MAGIC: [u8; 4] = *b"DXCK";
+pub const FORMAT_VERSION: u8 = 2;
+
+/// Object type tag in the header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ObjectKind {
+    SecretKey = 1,
+    PublicKey = 2,
+    RelinKey = 3,
+    GaloisKeys = 4,
+    Ciphertext = 5,
+}
+
+impl ObjectKind {
+    fn from_u8(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(Self::SecretKey),
+            2 => Some(Self::PublicKey),
+            3 => Some(Self::RelinKey),
+            4 => Some(Self::GaloisKeys),
+            5 => Some(Self::Ciphertext),
+            _ => None,
+        }
+    }
+}
+
+fn set_code(context: &CkksContext) -> u8 {
+    context.params().parameter_set().map_or(0, |set| set.code())
+}
+
+fn malformed(reason: impl std::fmt::Display) -> DeoxysError {
+    DeoxysError::crypto("ckks decode", reason)
+}
+
+/// Body encoder handed to [`BinaryFormat::write_body`]
+pub struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn new(context: &CkksContext, kind: ObjectKind) -> Self {
+        let params = context.params();
+        let mut w = Self { buf: Vec::new() };
+        w.buf.extend_from_slice(&MAGIC);
+        w.u8(FORMAT_VERSION);
+        w.u8(kind as u8);
+        w.u8(set_code(context));
+        w.u8(params.log_degree() as u8);
+        w.u8(params.moduli().len() as u8);
+        for &q in params.moduli() {
+            w.u64(q);
+        }
+        w.u64(params.special_modulus());
+        w
+    }
+
+    pub(crate) fn u8(&mut self, v: u8) {
+        self.buf.push(v);
+    }
+
+    pub(crate) fn u32(&mut self, v: u32) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    pub(crate) fn u64(&mut self, v: u64) {
+        self.buf.extend_from_slice(&v.to_le_bytes());
+    }
+
+    pub(crate) fn f64(&mut self, v: f64) {
+        self.u64(v.to_bits());
+    }
+
+    pub(crate) fn poly(&mut self, poly: &RnsPoly) {
+        for residue in poly {
+            for &c in residue {
+                self.u64(c);
+            }
+        }
+    }
+}
+
+/// Body decoder handed to [`BinaryFormat::read_body`]
+pub struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(context: &CkksContext, kind: ObjectKind, bytes: &'a [u8]) -> Result<Self> {
+        let mut r = Self { bytes, pos: 0 };
+        if r.take(4)? != MAGIC {
+            return Err(malformed("bad magic"));
+        }
+        let version = r.u8()?;
+        if version != FORMAT_VERSION {
+            return Err(malformed(format!("unsupported format version {}", version)));
+        }
+        let tag = r.u8()?;
+        match ObjectKind::from_u8(tag) {
+            Some(found) if found == kind => {}
+            Some(found) => {
+                return Err(malformed(format!("expected {:?}, found {:?}", kind, found)))
+            }
+            None => return Err(malformed(format!("unknown object kind {}", tag))),
+        }
+        let params = context.params();
+        let set = r.u8()?;
+        if set != set_code(context) {
+            return Err(malformed(format!(
+                "parameter set {} does not match {}",
+                set,
+                params.id()
+            )));
+        }
+        let log_degree = r.u8()?;
+        let primes = r.u8()? as usize;
+        let mut moduli = Vec::with_capacity(primes);
+        for _ in 0..primes {
+            moduli.push(r.u64()?);
+        }
+        let special = r.u64()?;
+        if log_degree as u32 != params.log_degree()
+            || moduli != params.moduli()
+            || special != params.special_modulus()
+        {
+            return Err(malformed("encoded under different CKKS parameters"));
+        }
+        Ok(r)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| malformed("truncated input"))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(
+            self.take(4)?.try_into().expect("4 bytes"),
+        ))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(
+            self.take(8)?.try_into().expect("8 bytes"),
+        ))
+    }
+
+    pub(crate) fn f64(&mut self) -> Result<f64> {
+        Ok(f64::from_bits(self.u64()?))
+    }
+
+    /// One polynomial over the given table indices, each residue reduced
+    pub(crate) fn poly(&mut self, context: &CkksContext, basis: &[usize]) -> Result<RnsPoly> {
+        let n = context.params().degree();
+        basis
+            .iter()
+            .map(|&i| {
+                let q = context.modulus(i);
+                (0..n)
+                    .map(|_| {
+                        let c = self.u64()?;
+                        if c >= q {
+                            return Err(malformed("residue not reduced"));
+                        }
+                        Ok(c)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn finish(self) -> Result<()> {
+        if self.pos != self.bytes.len() {
+            return Err(malformed(format!(
+                "{} trailing bytes",
+                self.bytes.len() - self.pos
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Binary encoding of a CKKS object bound to its context's parameters
+pub trait BinaryFormat: Sized {
+    const KIND: ObjectKind;
+    /// Written with owner-only permissions and wiped from memory after use
+    const SECRET: bool = false;
+
+    fn write_body(&self, w: &mut Writer);
+
+    fn read_body(context: &CkksContext, r: &mut Reader) -> Result<Self>;
+
+    fn to_bytes(&self, context: &CkksContext) -> Vec<u8> {
+        let mut w = Writer::new(context, Self::KIND);
+        self.write_body(&mut w);
+        w.buf
+    }
+
+    fn from_bytes(context: &CkksContext, bytes: &[u8]) -> Result<Self> {
+        let mut r = Reader::new(context, Self::KIND, bytes)?;
+        let value = Self::read_body(context, &mut r)?;
+        r.finish()?;
+        Ok(value)
+    }
+
+    fn save(&self, context: &CkksContext, path: impl AsRef<Path>) -> Result<()> {
+        let mut bytes = self.to_bytes(context);
+        let written = write_file(path.as_ref(), &bytes, Self::SECRET, "ckks save");
+        if Self::SECRET {
+            bytes.zeroize();
+        }
+        written
+    }
+
+    fn load(context: &CkksContext, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut bytes = fs::read(path)
+            .map_err(|e| DeoxysError::crypto("ckks load", format!("{}: {}", path.display(), e)))?;
+        let value = Self::from_bytes(context, &bytes);
+        if Self::SECRET {
+            bytes.zeroize();
+        }
+        value
+    }
+}
+
+fn write_switching_key(w: &mut Writer, key: &KeySwitchKey) {
+    w.u8(key.components.len() as u8);
+    for (b, a) in &key.components {
+        w.poly(b);
+        w.poly(a);
+    }
+}
+
+fn read_switching_key(context: &CkksContext, r: &mut Reader) -> Result<KeySwitchKey> {
+    let count = r.u8()? as usize;
+    if count != context.params().max_level() + 1 {
+        return Err(malformed(format!("{} key-switching components", count)));
+    }
+    let basis = context.basis(context.params().max_level(), true);
+    let components = (0..count)
+        .map(|_| Ok((r.poly(context, &basis)?, r.poly(context, &basis)?)))
+        .collect::<Result<_>>()?;
+    Ok(KeySwitchKey { components })
+}
+
+impl BinaryFormat for SecretKey {
+    const KIND: ObjectKind = ObjectKind::SecretKey;
+    const SECRET: bool = true;
+
+    fn write_body(&self, w: &mut Writer) {
+        w.poly(&self.ntt);
+    }
+
+    fn read_body(context: &CkksContext, r: &mut Reader) -> Result<Self> {
+        let basis = context.basis(context.params().max_level(), true);
+        Ok(Self {
+            ntt: r.poly(context, &basis)?,
+        })
+    }
+}
+
+impl BinaryFormat for PublicKey {
+    const KIND: ObjectKind = ObjectKind::PublicKey;
+
+    fn write_body(&self, w: &mut Writer) {
+        w.poly(&self.b);
+        w.poly(&self.a);
+    }
+
+    fn read_body(context: &CkksContext, r: &mut Reader) -> Result<Self> {
+        let basis = context.basis(context.params().max_level(), false);
+        Ok(Self {
+            b: r.poly(context, &basis)?,
+            a: r.poly(context, &basis)?,
+        })
+    }
+}
+
+impl BinaryFormat for RelinKey {
+    const KIND: ObjectKind = ObjectKind::RelinKey;
+
+    fn write_body(&self, w: &mut Writer) {
+        write_switching_key(w, &self.0);
+    }
+
+    fn read_body(context: &CkksContext, r: &mut Reader) -> Result<Self> {
+        Ok(Self(read_switching_key(context, r)?))
+    }
+}
+
+impl BinaryFormat for GaloisKeys {
+    const KIND: ObjectKind = ObjectKind::GaloisKeys;
+
+    fn write_body(&self, w: &mut Writer) {
+        w.u32(self.keys.len() as u32);
+        for (&g, key) in &self.keys {
+            w.u64(g);
+            write_switching_key(w, key);
+        }
+    }
+
+    fn read_body(context: &CkksContext, r: &mut Reader) -> Result<Self> {
+        let two_n = 2 * context.params().degree() as u64;
+        let count = r.u32()?;
+        let mut keys = GaloisKeys::default();
+        for _ in 0..count {
+            let g = r.u64()?;
+            if g.is_multiple_of(2) || g >= two_n {
+                return Err(malformed(format!("invalid Galois element {}", g)));
+            }
+            let key = read_switching_key(context, r)?;
+            if keys.keys.insert(g, key).is_some() {
+                return Err(malformed(format!("duplicate Galois element {}", g)));
+            }
+        }
+        Ok(keys)
+    }
+}
+
+impl BinaryFormat for Ciphertext {
+    const KIND: ObjectKind = ObjectKind::Ciphertext;
+
+    fn write_body(&self, w: &mut Writer) {
+        w.u8(self.level as u8);
+        w.f64(self.scale);
+        w.poly(&self.c0);
+        w.poly(&self.c1);
+    }
+
+    fn read_body(context: &CkksContext, r: &mut Reader) -> Result<Self> {
+        let level = r.u8()? as usize;
+        if level > context.params().max_level() {
+            return Err(malformed(format!(
+                "level {} exceeds the modulus chain",
+                level
+            )));
+        }
+        let scale = r.f64()?;
+        if !scale.is_finite() || scale < 1.0 {
+            return Err(malformed(format!("invalid scale {}", scale)));
+        }
+        let basis = context.basis(level, false);
+        Ok(Self {
+            c0: r.poly(context, &basis)?,
+            c1: r.poly(context, &basis)?,
+            level,
+            scale,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ckks::{CkksParameters, KeyGenerator, ParameterSet};
+
+    fn context(log_degree: u32) -> CkksContext {
+        CkksContext::new(CkksParameters::generate(log_degree, &[50, 30], 50, 30).unwrap())
+    }
+
+    #[test]
+    fn test_key_and_ciphertext_roundtrip() {
+        let context = context(6);
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let relin = keygen.relin_key(&secret);
+        let galois = keygen.galois_keys(&secret, &[1, 2], true);
+
+        let secret2 = SecretKey::from_bytes(&context, &secret.to_bytes(&context)).unwrap();
+        assert!(secret2.ntt == secret.ntt);
+        let public2 = PublicKey::from_bytes(&context, &public.to_bytes(&context)).unwrap();
+        assert!(public2.a == public.a && public2.b == public.b);
+        let relin2 = RelinKey::from_bytes(&context, &relin.to_bytes(&context)).unwrap();
+        assert!(relin2.0.components == relin.0.components);
+        let galois2 = GaloisKeys::from_bytes(&context, &galois.to_bytes(&context)).unwrap();
+        assert_eq!(
+            galois2.elements().collect::<Vec<_>>(),
+            galois.elements().collect::<Vec<_>>()
+        );
+
+        let ciphertext = context.encrypt(
+            &context.encode(&[0.5, -0.25], 1, 2f64.powi(30)).unwrap(),
+            &public,
+        );
+        let bytes = ciphertext.to_bytes(&context);
+        assert_eq!(&bytes[..4], b"DXCK");
+        assert_eq!(bytes[4], FORMAT_VERSION);
+        let decoded = Ciphertext::from_bytes(&context, &bytes).unwrap();
+        assert_eq!(decoded, ciphertext);
+        let values = context.decode(&context.decrypt(&decoded, &secret2));
+        assert!((values[0] - 0.5).abs() < 1e-4 && (values[1] + 0.25).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let context = context(6);
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let bytes = public.to_bytes(&context);
+
+        // Wrong kind, parameters, version, length and unreduced residues
+        assert!(SecretKey::from_bytes(&context, &bytes).is_err());
+        assert!(PublicKey::from_bytes(&self::context(7), &bytes).is_err());
+        let mut tampered = bytes.clone();
+        tampered[4] = FORMAT_VERSION + 1;
+        assert!(PublicKey::from_bytes(&context, &tampered).is_err());
+        assert!(PublicKey::from_bytes(&context, &bytes[..bytes.len() - 1]).is_err());
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert!(PublicKey::from_bytes(&context, &extended).is_err());
+        let mut unreduced = bytes.clone();
+        let body = unreduced.len() - 8;
+        unreduced[body..].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert!(PublicKey::from_bytes(&context, &unreduced).is_err());
+        assert!(PublicKey::from_bytes(&context, b"DXCK").is_err());
+    }
+
+    #[test]
+    fn test_header_records_parameter_set() {
+        let preset = CkksContext::new(CkksParameters::default());
+        let secret = KeyGenerator::new(&preset).secret_key();
+        let bytes = secret.to_bytes(&preset);
+        assert_eq!(bytes[5], ObjectKind::SecretKey as u8);
+        assert_eq!(bytes[6], ParameterSet::N8192Depth2Bits128.code());
+
+        // Same primes, but a custom set must not pass for the preset
+        let mut relabelled = bytes.clone();
+        relabelled[6] = 0;
+        assert!(SecretKey::from_bytes(&preset, &relabelled).is_err());
+        assert_eq!(context(6).params().parameter_set(), None);
+    }
+}
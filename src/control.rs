@@ -0,0 +1,340 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use crate::ckks::{Ciphertext, CkksContext, GaloisKeys, LinearTransform};
+use crate::error::{DeoxysError, Result};
+use crate::rik::OperatorBounds;
+use ndarray::{Array1, Array2};
+
+/// Default agreement required between encrypted and plaintext control
+pub const ENCRYPTED_CONTROL_TOLERANCE: f64 = 1e-4;
+
+/// Discrete-time linear plant x_{k+1} = A x_k + B u_k, y_k = C x_k, sampled at the RIK rate
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlantModel {
+    a: Array2<f64>,
+    b: Array2<f64>,
+    c: Array2<f64>,
+}
+
+impl PlantModel {
+    pub fn new(a: Array2<f64>, b: Array2<f64>) -> Result<Self> {
+        if !a.is_square() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "plant A columns",
+                expected: a.nrows(),
+                actual: a.ncols(),
+            });
+        }
+        if b.nrows() != a.nrows() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "plant B rows",
+                expected: a.nrows(),
+                actual: b.nrows(),
+            });
+        }
+        if a.iter().chain(b.iter()).any(|v| !v.is_finite()) {
+            return Err(DeoxysError::config(
+                "plant model",
+                "matrices must be finite",
+            ));
+        }
+        let c = Array2::eye(a.nrows());
+        Ok(Self { a, b, c })
+    }
+
+    /// Measured outputs; the default C = I observes the full state
+    pub fn with_output(mut self, c: Array2<f64>) -> Result<Self> {
+        if c.ncols() != self.states() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "plant C columns",
+                expected: self.states(),
+                actual: c.ncols(),
+            });
+        }
+        if c.iter().any(|v| !v.is_finite()) {
+            return Err(DeoxysError::config("plant model", "matrices must be finite"));
+        }
+        self.c = c;
+        Ok(self)
+    }
+
+    pub fn a(&self) -> &Array2<f64> {
+        &self.a
+    }
+
+    pub fn b(&self) -> &Array2<f64> {
+        &self.b
+    }
+
+    pub fn c(&self) -> &Array2<f64> {
+        &self.c
+    }
+
+    pub fn states(&self) -> usize {
+        self.a.nrows()
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.b.ncols()
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.c.nrows()
+    }
+
+    pub fn step(&self, state: &Array1<f64>, input: &Array1<f64>) -> Array1<f64> {
+        self.a.dot(state) + self.b.dot(input)
+    }
+}
+
+/// State feedback u = -K x, saturated to the operator bounds
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearPlanner {
+    gain: Array2<f64>,
+}
+
+impl LinearPlanner {
+    pub fn new(gain: Array2<f64>) -> Result<Self> {
+        if gain.iter().any(|v| !v.is_finite()) {
+            return Err(DeoxysError::config("planner gain", "must be finite"));
+        }
+        Ok(Self { gain })
+    }
+
+    pub fn gain(&self) -> &Array2<f64> {
+        &self.gain
+    }
+
+    /// Check that K is inputs x states for the given plant
+    pub fn check(&self, plant: &PlantModel) -> Result<()> {
+        if self.gain.nrows() != plant.inputs() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "planner gain rows",
+                expected: plant.inputs(),
+                actual: self.gain.nrows(),
+            });
+        }
+        if self.gain.ncols() != plant.states() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "planner gain columns",
+                expected: plant.states(),
+                actual: self.gain.ncols(),
+            });
+        }
+        Ok(())
+    }
+
+    pub fn propose(&self, state: &Array1<f64>, bounds: OperatorBounds) -> Array1<f64> {
+        self.gain
+            .dot(state)
+            .mapv(|u| (-u).clamp(bounds.min, bounds.max))
+    }
+
+    /// Unsaturated closed-loop matrix A - B K
+    pub fn closed_loop(&self, plant: &PlantModel) -> Array2<f64> {
+        plant.a() - &plant.b().dot(&self.gain)
+    }
+}
+
+/// Affine law u = -K x + u0 evaluated on a CKKS-encrypted state, so a party
+/// holding only evaluation keys can compute controls without seeing x.
+/// Saturation is not polynomial: the key holder clamps after decryption.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncryptedPlanner {
+    planner: LinearPlanner,
+    offset: Array1<f64>,
+    transform: LinearTransform,
+    tolerance: f64,
+}
+
+impl EncryptedPlanner {
+    pub fn new(planner: LinearPlanner) -> Result<Self> {
+        let offset = Array1::zeros(planner.gain().nrows());
+        Self::with_offset(planner, offset)
+    }
+
+    pub fn with_offset(planner: LinearPlanner, offset: Array1<f64>) -> Result<Self> {
+        let transform = LinearTransform::new(&-planner.gain())?.with_offset(&offset)?;
+        Ok(Self {
+            planner,
+            offset,
+            transform,
+            tolerance: ENCRYPTED_CONTROL_TOLERANCE,
+        })
+    }
+
+    /// Largest accepted deviation from the plaintext law, per input
+    pub fn with_tolerance(mut self, tolerance: f64) -> Result<Self> {
+        if !tolerance.is_finite() || tolerance <= 0.0 {
+            return Err(DeoxysError::config(
+                "encrypted control tolerance",
+                format!("{} must be finite and positive", tolerance),
+            ));
+        }
+        self.tolerance = tolerance;
+        Ok(self)
+    }
+
+    pub fn planner(&self) -> &LinearPlanner {
+        &self.planner
+    }
+
+    pub fn offset(&self) -> &Array1<f64> {
+        &self.offset
+    }
+
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Slot rotations the evaluating party needs Galois keys for
+    pub fn rotations(&self) -> Vec<i64> {
+        self.transform.rotations()
+    }
+
+    /// Encrypted -K x + u0 from an encrypted state; consumes one level
+    pub fn evaluate(
+        &self,
+        context: &CkksContext,
+        state: &Ciphertext,
+        keys: &GaloisKeys,
+    ) -> Result<Ciphertext> {
+        self.transform.evaluate(context, state, keys)
+    }
+
+    /// Plaintext law, saturated to the bounds; equals `LinearPlanner::propose`
+    /// when the offset is zero
+    pub fn propose(&self, state: &Array1<f64>, bounds: OperatorBounds) -> Array1<f64> {
+        self.transform
+            .apply(state)
+            .mapv(|u| u.clamp(bounds.min, bounds.max))
+    }
+
+    /// Saturate the decrypted control and check it against the plaintext law;
+    /// returns the clamped control and the largest deviation
+    pub fn verify(
+        &self,
+        decrypted: &Array1<f64>,
+        state: &Array1<f64>,
+        bounds: OperatorBounds,
+    ) -> Result<(Array1<f64>, f64)> {
+        let expected = self.propose(state, bounds);
+        if decrypted.len() < expected.len() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "encrypted control inputs",
+                expected: expected.len(),
+                actual: decrypted.len(),
+            });
+        }
+        let control = decrypted
+            .slice(ndarray::s![..expected.len()])
+            .mapv(|u| u.clamp(bounds.min, bounds.max));
+        let deviation = (&control - &expected)
+            .iter()
+            .fold(0.0f64, |worst, e| worst.max(e.abs()));
+        if deviation > self.tolerance {
+            return Err(DeoxysError::crypto(
+                "encrypted control",
+                format!(
+                    "deviation {:e} exceeds tolerance {:e}",
+                    deviation, self.tolerance
+                ),
+            ));
+        }
+        Ok((control, deviation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_plant_and_planner_dimensions() {
+        assert!(PlantModel::new(Array2::eye(2), Array2::eye(3)).is_err());
+        assert!(PlantModel::new(Array2::zeros((2, 3)), Array2::eye(2)).is_err());
+        let plant = PlantModel::new(Array2::eye(2), Array2::eye(2)).unwrap();
+        assert_eq!(plant.outputs(), 2);
+        assert!(plant.clone().with_output(array![[1.0, 0.0, 0.0]]).is_err());
+        assert_eq!(plant.with_output(array![[1.0, 0.0]]).unwrap().outputs(), 1);
+
+        let plant = PlantModel::new(Array2::eye(2), array![[1.0], [0.0]]).unwrap();
+        assert!(LinearPlanner::new(Array2::eye(2))
+            .unwrap()
+            .check(&plant)
+            .is_err());
+        assert!(LinearPlanner::new(array![[0.5, 0.0]])
+            .unwrap()
+            .check(&plant)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_planner_saturates_and_closes_loop() {
+        let plant = PlantModel::new(Array2::eye(2) * 1.2, Array2::eye(2)).unwrap();
+        let planner = LinearPlanner::new(Array2::eye(2) * 0.6).unwrap();
+
+        let u = planner.propose(&array![0.5, -3.0], OperatorBounds::default());
+        assert_eq!(u, array![-0.3, 1.0]);
+        assert_eq!(planner.closed_loop(&plant), Array2::<f64>::eye(2) * 0.6);
+        let next = plant.step(&array![0.5, -3.0], &u);
+        assert!((next[0] - 0.3).abs() < 1e-12 && (next[1] + 2.6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_encrypted_planner_matches_plaintext() {
+        use crate::ckks::{CkksParameters, KeyGenerator};
+
+        let context =
+            CkksContext::new(CkksParameters::generate(10, &[60, 40, 40], 60, 40).unwrap());
+        let planner = LinearPlanner::new(array![[0.6, 0.1, 0.0], [-0.2, 0.4, 0.3]]).unwrap();
+        let encrypted = EncryptedPlanner::new(planner.clone()).unwrap();
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        // The evaluating party holds only these
+        let keys = keygen.galois_keys(&secret, &encrypted.rotations(), false);
+
+        let bounds = OperatorBounds::default();
+        for x in [array![0.5, -1.0, 0.25], array![3.0, -2.0, 1.0]] {
+            let ct = context.encrypt(
+                &context
+                    .encode(&x.to_vec(), 2, context.params().scale())
+                    .unwrap(),
+                &public,
+            );
+            let u = encrypted.evaluate(&context, &ct, &keys).unwrap();
+            let decrypted = Array1::from_vec(context.decode(&context.decrypt(&u, &secret)));
+            let (control, deviation) = encrypted.verify(&decrypted, &x, bounds).unwrap();
+            assert!(deviation < 1e-6);
+            assert!((control - planner.propose(&x, bounds))
+                .iter()
+                .all(|e| e.abs() < 1e-6));
+        }
+
+        // Affine offset, and a tampered result rejected by the tolerance
+        let offset = array![0.05, -0.1];
+        let affine = EncryptedPlanner::with_offset(planner.clone(), offset.clone()).unwrap();
+        let x = array![0.2, 0.1, -0.4];
+        let ct = context.encrypt(
+            &context
+                .encode(&x.to_vec(), 2, context.params().scale())
+                .unwrap(),
+            &public,
+        );
+        let u = affine.evaluate(&context, &ct, &keys).unwrap();
+        let decrypted = Array1::from_vec(context.decode(&context.decrypt(&u, &secret)));
+        let expected = -planner.gain().dot(&x) + &offset;
+        assert!(
+            (decrypted[0] - expected[0]).abs() < 1e-6 && (decrypted[1] - expected[1]).abs() < 1e-6
+        );
+        assert!(affine.verify(&decrypted, &x, bounds).is_ok());
+        let mut tampered = decrypted.clone();
+        tampered[1] += 1e-3;
+        assert!(affine.verify(&tampered, &x, bounds).is_err());
+        assert!(affine.with_tolerance(0.0).is_err());
+    }
+}
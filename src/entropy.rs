@@ -0,0 +1,303 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Shannon entropy of each state channel over a sliding window of cycles.
+//!
+//! Entropy is measured in bits at a declared resolution, so that a channel
+//! holding a single value (to within that resolution) over the window has
+//! zero entropy. This is the measurable meaning of the Zero Entropy Law:
+//! in steady state every channel's windowed entropy is zero, and between
+//! cycles it must not grow faster than the configured limit.
+//!
+//! - Histogram: discrete entropy of the channel quantized into fixed-width
+//!   bins anchored at zero, -sum p_i log2 p_i.
+//! - Kernel density: resubstitution estimate -mean log2 f(x_i) of a Gaussian
+//!   KDE with bandwidth h, minus the entropy log2(h sqrt(2 pi)) it assigns to a
+//!   constant channel. Smooth in the data and never negative.
+
+use crate::error::{DeoxysError, Result};
+use crate::invariants::{Invariant, InvariantContext, Verdict};
+use ndarray::Array1;
+use std::collections::VecDeque;
+
+/// Entropy estimator and its resolution
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EntropyEstimator {
+    Histogram { bin_width: f64 },
+    Kernel { bandwidth: f64 },
+}
+
+impl EntropyEstimator {
+    pub fn histogram(bin_width: f64) -> Result<Self> {
+        if !bin_width.is_finite() || bin_width <= 0.0 {
+            return Err(DeoxysError::config(
+                "histogram bin width",
+                format!("{} must be finite and positive", bin_width),
+            ));
+        }
+        Ok(Self::Histogram { bin_width })
+    }
+
+    pub fn kernel(bandwidth: f64) -> Result<Self> {
+        if !bandwidth.is_finite() || bandwidth <= 0.0 {
+            return Err(DeoxysError::config(
+                "kernel bandwidth",
+                format!("{} must be finite and positive", bandwidth),
+            ));
+        }
+        Ok(Self::Kernel { bandwidth })
+    }
+
+    /// Entropy in bits of one channel's samples; zero for fewer than two samples
+    pub fn entropy(&self, samples: &[f64]) -> f64 {
+        if samples.len() < 2 {
+            return 0.0;
+        }
+        match *self {
+            Self::Histogram { bin_width } => histogram_entropy(samples, bin_width),
+            Self::Kernel { bandwidth } => kernel_entropy(samples, bandwidth),
+        }
+    }
+}
+
+fn histogram_entropy(samples: &[f64], bin_width: f64) -> f64 {
+    let mut bins: Vec<i64> = samples
+        .iter()
+        .map(|x| (x / bin_width).floor() as i64)
+        .collect();
+    bins.sort_unstable();
+    let n = samples.len() as f64;
+    bins.chunk_by(|a, b| a == b)
+        .map(|run| {
+            let p = run.len() as f64 / n;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn kernel_entropy(samples: &[f64], bandwidth: f64) -> f64 {
+    // Normalization cancels against the constant-channel baseline:
+    // f(x_i) / K_h(0) = mean_j exp(-(x_i - x_j)^2 / 2h^2)
+    let n = samples.len() as f64;
+    let mean_log: f64 = samples
+        .iter()
+        .map(|xi| {
+            let density: f64 = samples
+                .iter()
+                .map(|xj| (-((xi - xj) / bandwidth).powi(2) / 2.0).exp())
+                .sum();
+            (density / n).log2()
+        })
+        .sum::<f64>()
+        / n;
+    (-mean_log).max(0.0)
+}
+
+/// Per-channel entropy over the most recent `window` states
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowedEntropy {
+    pub estimator: EntropyEstimator,
+    pub window: usize,
+}
+
+impl WindowedEntropy {
+    pub fn new(estimator: EntropyEstimator, window: usize) -> Result<Self> {
+        if window < 2 {
+            return Err(DeoxysError::config(
+                "entropy window",
+                format!("{} must span at least two cycles", window),
+            ));
+        }
+        Ok(Self { estimator, window })
+    }
+
+    /// Entropy of each channel over the last `window - 1` committed states and
+    /// the current one; `None` until the window is full
+    pub fn estimate(
+        &self,
+        history: &VecDeque<Array1<f64>>,
+        current: &Array1<f64>,
+    ) -> Option<Vec<f64>> {
+        if history.len() + 1 < self.window {
+            return None;
+        }
+        let recent: Vec<&Array1<f64>> = history
+            .iter()
+            .skip(history.len() + 1 - self.window)
+            .chain(std::iter::once(current))
+            .collect();
+        let channels = recent.iter().map(|s| s.len()).min().unwrap_or(0);
+        Some(
+            (0..channels)
+                .map(|channel| {
+                    let samples: Vec<f64> = recent.iter().map(|s| s[channel]).collect();
+                    self.estimator.entropy(&samples)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Bounds the per-cycle growth of each channel's windowed entropy
+pub struct EntropyGrowth {
+    entropy: WindowedEntropy,
+    max_growth: f64,
+    last: Option<Vec<f64>>,
+}
+
+impl EntropyGrowth {
+    pub const NAME: &'static str = "entropy-growth";
+
+    /// `max_growth` in bits per cycle per channel
+    pub fn new(entropy: WindowedEntropy, max_growth: f64) -> Result<Self> {
+        if !max_growth.is_finite() || max_growth < 0.0 {
+            return Err(DeoxysError::config(
+                "entropy growth limit",
+                format!("{} must be finite and non-negative", max_growth),
+            ));
+        }
+        Ok(Self {
+            entropy,
+            max_growth,
+            last: None,
+        })
+    }
+
+    /// Per-channel entropy from the most recent full window
+    pub fn last_entropy(&self) -> Option<&[f64]> {
+        self.last.as_deref()
+    }
+}
+
+impl Invariant for EntropyGrowth {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let Some(current) = self.entropy.estimate(ctx.history, ctx.state) else {
+            return Verdict::Pass;
+        };
+        let previous = self.last.replace(current.clone());
+        let Some(previous) = previous else {
+            return Verdict::Pass;
+        };
+
+        // Report the channel with the largest growth
+        let worst = current
+            .iter()
+            .zip(&previous)
+            .enumerate()
+            .map(|(channel, (now, before))| (channel, *before, *now, now - before))
+            .max_by(|a, b| a.3.total_cmp(&b.3));
+        match worst {
+            Some((channel, previous, entropy, growth)) if growth > self.max_growth => {
+                Verdict::Fail(DeoxysError::EntropyGrowth {
+                    cycle: ctx.cycle,
+                    channel,
+                    previous,
+                    entropy,
+                    limit: self.max_growth,
+                })
+            }
+            _ => Verdict::Pass,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rik::OperatorBounds;
+    use ndarray::Array2;
+
+    #[test]
+    fn test_constant_channel_has_zero_entropy() {
+        let samples = [0.25; 16];
+        assert_eq!(
+            EntropyEstimator::histogram(0.1).unwrap().entropy(&samples),
+            0.0
+        );
+        assert!(
+            EntropyEstimator::kernel(0.1)
+                .unwrap()
+                .entropy(&samples)
+                .abs()
+                < 1e-12
+        );
+    }
+
+    #[test]
+    fn test_histogram_entropy_bits() {
+        let estimator = EntropyEstimator::histogram(1.0).unwrap();
+        assert!((estimator.entropy(&[0.5, 1.5]) - 1.0).abs() < 1e-12);
+        let uniform: Vec<f64> = (0..8).map(|i| i as f64 + 0.5).collect();
+        assert!((estimator.entropy(&uniform) - 3.0).abs() < 1e-12);
+        // Same bin at this resolution
+        assert_eq!(estimator.entropy(&[0.1, 0.2, 0.9]), 0.0);
+    }
+
+    #[test]
+    fn test_kernel_entropy_separated_clusters() {
+        let estimator = EntropyEstimator::kernel(0.01).unwrap();
+        // Two well-separated values: f(x_i) ~ K(0) / 2, i.e. one bit
+        assert!((estimator.entropy(&[0.0, 0.0, 1.0, 1.0]) - 1.0).abs() < 1e-9);
+        assert!(EntropyEstimator::kernel(0.0).is_err());
+        assert!(EntropyEstimator::histogram(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_window_requires_full_history() {
+        let entropy = WindowedEntropy::new(EntropyEstimator::histogram(0.5).unwrap(), 3).unwrap();
+        let mut history = VecDeque::new();
+        history.push_back(Array1::from_vec(vec![0.0, 0.0]));
+        assert!(entropy
+            .estimate(&history, &Array1::from_vec(vec![0.0, 1.0]))
+            .is_none());
+
+        history.push_front(Array1::from_vec(vec![9.0, 9.0]));
+        history.push_back(Array1::from_vec(vec![0.0, 0.0]));
+        // Oldest state falls outside the window
+        let estimate = entropy
+            .estimate(&history, &Array1::from_vec(vec![0.0, 1.0]))
+            .unwrap();
+        assert_eq!(estimate[0], 0.0);
+        assert!((estimate[1] - (3f64.log2() - 2.0 / 3.0)).abs() < 1e-12);
+        assert!(WindowedEntropy::new(EntropyEstimator::histogram(0.5).unwrap(), 1).is_err());
+    }
+
+    #[test]
+    fn test_entropy_growth_invariant() {
+        let entropy = WindowedEntropy::new(EntropyEstimator::histogram(0.1).unwrap(), 4).unwrap();
+        let mut invariant = EntropyGrowth::new(entropy, 0.5).unwrap();
+        let covariance = Array2::eye(1);
+        let mut history = VecDeque::new();
+        let mut verdicts = Vec::new();
+        // Settled channel, then a jump spreading it over two bins
+        for (cycle, value) in [0.0, 0.0, 0.0, 0.0, 0.0, 1.0].into_iter().enumerate() {
+            let state = Array1::from_vec(vec![value]);
+            verdicts.push(invariant.evaluate(&InvariantContext {
+                cycle: cycle as u64 + 1,
+                state: &state,
+                control: &state,
+                covariance: &covariance,
+                history: &history,
+                bounds: OperatorBounds::default(),
+            }));
+            history.push_back(state);
+        }
+        assert!(verdicts[..5].iter().all(|v| *v == Verdict::Pass));
+        match &verdicts[5] {
+            Verdict::Fail(DeoxysError::EntropyGrowth {
+                channel, entropy, ..
+            }) => {
+                assert_eq!(*channel, 0);
+                // One of four samples in a second bin
+                assert!((entropy - 0.811278).abs() < 1e-6);
+            }
+            other => panic!("expected entropy growth failure, got {:?}", other),
+        }
+        assert_eq!(invariant.last_entropy().map(|e| e.len()), Some(1));
+    }
+}
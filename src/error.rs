@@ -0,0 +1,230 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use crate::crypto::ProvenanceError;
+use crate::ledger::LogError;
+use crate::wire::WireError;
+use serde::Serialize;
+use std::fmt;
+
+pub type Result<T, E = DeoxysError> = std::result::Result<T, E>;
+
+/// Structured failure of the RIK engine, its invariants or its configuration.
+///
+/// Serializes with a `kind` tag so the same fields appear in receipts and logs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DeoxysError {
+    /// Sovereign substrate failed verification
+    IntegrityViolation { reason: String },
+    /// V(x) exceeded its bound (`limit`) or failed to decrease (`required_rate`)
+    StabilityBreach {
+        cycle: u64,
+        energy: f64,
+        limit: Option<f64>,
+        previous_energy: Option<f64>,
+        decrease_rate: Option<f64>,
+        required_rate: Option<f64>,
+    },
+    /// State variance above the strict entropy threshold
+    EntropyViolation { cycle: u64, variance: f64, threshold: f64 },
+    /// Windowed entropy of a state channel grew faster than allowed (bits)
+    EntropyGrowth { cycle: u64, channel: usize, previous: f64, entropy: f64, limit: f64 },
+    /// Control output outside operator bounds
+    BoundViolation { cycle: u64, channel: usize, value: f64, min: f64, max: f64 },
+    /// Vector or matrix of unexpected size
+    DimensionMismatch { context: &'static str, expected: usize, actual: usize },
+    /// Non-physical input or estimate (e.g. NaN) on a given channel
+    SensorFault { cycle: u64, source: &'static str, index: usize, reason: String },
+    /// Signing, verification, encryption or key handling failed
+    CryptoFailure { operation: &'static str, reason: String },
+    /// Breach reported by an invariant without a dedicated variant
+    InvariantViolation { invariant: String, cycle: u64, detail: String },
+    /// Rejected configuration parameter
+    InvalidConfiguration { parameter: &'static str, reason: String },
+    /// Engine refuses cycles after a lockdown or halt escalation until reset
+    Lockdown { invariant: String, since_cycle: u64, cause: Box<DeoxysError> },
+    /// Wire envelope rejected during parsing or validation
+    MalformedWire { error: WireError },
+    /// Cycle receipt or receipt chain failed provenance verification
+    ProvenanceFailure { error: ProvenanceError },
+    /// Provenance log damaged, tampered with or not a log
+    CorruptLog { error: LogError },
+    /// Supervised cycle attempted without a signed operator approval
+    ApprovalRequired { cycle: u64 },
+    /// Configuration change reserved to a certified operator quorum
+    QuorumRequired { action: &'static str },
+}
+
+impl DeoxysError {
+    /// Stable machine-readable identifier, identical to the serialized `kind`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::IntegrityViolation { .. } => "integrity_violation",
+            Self::StabilityBreach { .. } => "stability_breach",
+            Self::EntropyViolation { .. } => "entropy_violation",
+            Self::EntropyGrowth { .. } => "entropy_growth",
+            Self::BoundViolation { .. } => "bound_violation",
+            Self::DimensionMismatch { .. } => "dimension_mismatch",
+            Self::SensorFault { .. } => "sensor_fault",
+            Self::CryptoFailure { .. } => "crypto_failure",
+            Self::InvariantViolation { .. } => "invariant_violation",
+            Self::InvalidConfiguration { .. } => "invalid_configuration",
+            Self::Lockdown { .. } => "lockdown",
+            Self::MalformedWire { .. } => "malformed_wire",
+            Self::ProvenanceFailure { .. } => "provenance_failure",
+            Self::CorruptLog { .. } => "corrupt_log",
+            Self::ApprovalRequired { .. } => "approval_required",
+            Self::QuorumRequired { .. } => "quorum_required",
+        }
+    }
+
+    pub fn config(parameter: &'static str, reason: impl Into<String>) -> Self {
+        Self::InvalidConfiguration { parameter, reason: reason.into() }
+    }
+
+    pub fn crypto(operation: &'static str, reason: impl fmt::Display) -> Self {
+        Self::CryptoFailure { operation, reason: reason.to_string() }
+    }
+}
+
+impl fmt::Display for DeoxysError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::IntegrityViolation { reason } => {
+                write!(f, "Sovereign state integrity violation: {}", reason)
+            }
+            Self::StabilityBreach {
+                cycle,
+                energy,
+                limit: Some(limit),
+                ..
+            } => write!(
+                f,
+                "Lyapunov Unstable at cycle {}: System energy {} exceeds bound {}.",
+                cycle, energy, limit
+            ),
+            Self::StabilityBreach {
+                cycle,
+                energy,
+                previous_energy,
+                decrease_rate,
+                required_rate,
+                ..
+            } => write!(
+                f,
+                "Lyapunov Decrease Violation at cycle {}: V(x_k)={:.6e}, V(x_k+1)={:.6e}, \
+                 decrease rate {:.6e} (required <= -{})",
+                cycle,
+                previous_energy.unwrap_or(f64::NAN),
+                energy,
+                decrease_rate.unwrap_or(f64::NAN),
+                required_rate.unwrap_or(f64::NAN)
+            ),
+            Self::EntropyViolation { cycle, variance, threshold } => write!(
+                f,
+                "Entropy Violation at cycle {}: Variance {} exceeds threshold {}.",
+                cycle, variance, threshold
+            ),
+            Self::EntropyGrowth { cycle, channel, previous, entropy, limit } => write!(
+                f,
+                "Entropy growth at cycle {}: channel {} rose from {:.4} to {:.4} bits (limit {} per cycle)",
+                cycle, channel, previous, entropy, limit
+            ),
+            Self::BoundViolation { cycle, channel, value, min, max } => write!(
+                f,
+                "Output violation at cycle {}: channel {} value {} exceeds operator bounds [{}, {}]",
+                cycle, channel, value, min, max
+            ),
+            Self::DimensionMismatch { context, expected, actual } => write!(
+                f,
+                "Dimension mismatch in {}: expected {}, got {}",
+                context, expected, actual
+            ),
+            Self::SensorFault { cycle, source, index, reason } => write!(
+                f,
+                "Sensor fault at cycle {}: {} index {}: {}",
+                cycle, source, index, reason
+            ),
+            Self::CryptoFailure { operation, reason } => {
+                write!(f, "Cryptographic failure during {}: {}", operation, reason)
+            }
+            Self::InvariantViolation { invariant, cycle, detail } => write!(
+                f,
+                "Invariant '{}' violated at cycle {}: {}",
+                invariant, cycle, detail
+            ),
+            Self::InvalidConfiguration { parameter, reason } => {
+                write!(f, "Invalid {}: {}", parameter, reason)
+            }
+            Self::Lockdown { invariant, since_cycle, cause } => write!(
+                f,
+                "Lockdown since cycle {} after '{}' escalated ({}); authorized reset required",
+                since_cycle, invariant, cause
+            ),
+            Self::MalformedWire { error } => write!(f, "Malformed wire envelope: {}", error),
+            Self::ProvenanceFailure { error } => {
+                write!(f, "Provenance verification failed: {}", error)
+            }
+            Self::CorruptLog { error } => write!(f, "Corrupt provenance log: {}", error),
+            Self::ApprovalRequired { cycle } => {
+                write!(f, "Cycle {} requires a signed operator approval", cycle)
+            }
+            Self::QuorumRequired { action } => {
+                write!(f, "{} requires a quorum certificate from the operators", action)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeoxysError {}
+
+impl From<WireError> for DeoxysError {
+    fn from(error: WireError) -> Self {
+        Self::MalformedWire { error }
+    }
+}
+
+impl From<ProvenanceError> for DeoxysError {
+    fn from(error: ProvenanceError) -> Self {
+        Self::ProvenanceFailure { error }
+    }
+}
+
+impl From<LogError> for DeoxysError {
+    fn from(error: LogError) -> Self {
+        Self::CorruptLog { error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_code_matches_serialized_kind() {
+        let errors = [
+            DeoxysError::IntegrityViolation { reason: "root mismatch".into() },
+            DeoxysError::EntropyViolation { cycle: 3, variance: 0.5, threshold: 0.1 },
+            DeoxysError::DimensionMismatch { context: "observation", expected: 10, actual: 4 },
+            DeoxysError::crypto("sign", "key unavailable"),
+            DeoxysError::config("bounds", "min >= max"),
+        ];
+        for error in errors {
+            let json = serde_json::to_value(&error).unwrap();
+            assert_eq!(json["kind"], error.code());
+        }
+    }
+
+    #[test]
+    fn test_bound_violation_fields() {
+        let error =
+            DeoxysError::BoundViolation { cycle: 7, channel: 2, value: 1.5, min: -1.0, max: 1.0 };
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["cycle"], 7);
+        assert_eq!(json["channel"], 2);
+        assert_eq!(json["value"], 1.5);
+        assert!(error.to_string().contains("channel 2"));
+    }
+}
@@ -4,56 +4,104 @@
 //! This module exposes a C-compatible API for the Deoxys RIK engine
 //! to be called from Swift/Objective-C in iOS applications.
 
-use std::ffi::{CStr, CString};
+use std::ffi::CStr;
 use std::os::raw::{c_char, c_double, c_int, c_uint};
 use std::ptr;
 use std::slice;
-use std::sync::{Arc, Mutex};
+use std::sync::Mutex;
 
+use ndarray::Array1;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::error::DeoxysError;
 use crate::rik::RikEngine;
-use crate::state::StateVector;
+use crate::substrate::SovereignState;
 
 /// Opaque handle to RIK engine instance
 pub struct DeoxysHandle {
-    engine: Arc<Mutex<RikEngine>>,
+    engine: Mutex<RikEngine>,
+    /// Drives the engine's async cycle on the caller's thread
+    runtime: Runtime,
 }
 
 /// Status codes returned by FFI functions
 #[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DeoxysStatus {
     Success = 0,
     ErrorNullPointer = -1,
     ErrorInvalidHandle = -2,
     ErrorInvariantViolation = -3,
     ErrorInternal = -4,
+    ErrorIntegrityViolation = -5,
+    ErrorStabilityBreach = -6,
+    ErrorEntropyViolation = -7,
+    ErrorBoundViolation = -8,
+    ErrorDimensionMismatch = -9,
+    ErrorSensorFault = -10,
+    ErrorCryptoFailure = -11,
+    ErrorInvalidConfiguration = -12,
+    ErrorLockdown = -13,
+    ErrorEntropyGrowth = -14,
+    ErrorMalformedWire = -15,
+    ErrorProvenanceFailure = -16,
+    ErrorCorruptLog = -17,
+    ErrorApprovalRequired = -18,
+    ErrorQuorumRequired = -19,
+}
+
+impl From<&DeoxysError> for DeoxysStatus {
+    fn from(error: &DeoxysError) -> Self {
+        match error {
+            DeoxysError::IntegrityViolation { .. } => DeoxysStatus::ErrorIntegrityViolation,
+            DeoxysError::StabilityBreach { .. } => DeoxysStatus::ErrorStabilityBreach,
+            DeoxysError::EntropyViolation { .. } => DeoxysStatus::ErrorEntropyViolation,
+            DeoxysError::BoundViolation { .. } => DeoxysStatus::ErrorBoundViolation,
+            DeoxysError::DimensionMismatch { .. } => DeoxysStatus::ErrorDimensionMismatch,
+            DeoxysError::SensorFault { .. } => DeoxysStatus::ErrorSensorFault,
+            DeoxysError::CryptoFailure { .. } => DeoxysStatus::ErrorCryptoFailure,
+            DeoxysError::InvariantViolation { .. } => DeoxysStatus::ErrorInvariantViolation,
+            DeoxysError::InvalidConfiguration { .. } => DeoxysStatus::ErrorInvalidConfiguration,
+            DeoxysError::Lockdown { .. } => DeoxysStatus::ErrorLockdown,
+            DeoxysError::EntropyGrowth { .. } => DeoxysStatus::ErrorEntropyGrowth,
+            DeoxysError::MalformedWire { .. } => DeoxysStatus::ErrorMalformedWire,
+            DeoxysError::ProvenanceFailure { .. } => DeoxysStatus::ErrorProvenanceFailure,
+            DeoxysError::CorruptLog { .. } => DeoxysStatus::ErrorCorruptLog,
+            DeoxysError::ApprovalRequired { .. } => DeoxysStatus::ErrorApprovalRequired,
+            DeoxysError::QuorumRequired { .. } => DeoxysStatus::ErrorQuorumRequired,
+        }
+    }
 }
 
 /// Initialize a new Deoxys RIK engine instance
 /// 
 /// # Safety
-/// This function is safe to call from C/Swift
+/// `root_signature` must be null or point to a NUL-terminated string
 /// 
 /// # Parameters
-/// - `state_dim`: Dimension of the state vector
-/// - `constraint_dim`: Dimension of constraints
-/// - `control_dim`: Dimension of control actions
+/// - `root_signature`: Sovereign root signature the engine verifies every cycle
 /// 
 /// # Returns
 /// Opaque handle to the engine, or null on failure
 #[no_mangle]
-pub extern "C" fn deoxys_init(
-    state_dim: c_uint,
-    constraint_dim: c_uint,
-    control_dim: c_uint,
-) -> *mut DeoxysHandle {
-    let engine = RikEngine::new(
-        state_dim as usize,
-        constraint_dim as usize,
-        control_dim as usize,
-    );
+pub unsafe extern "C" fn deoxys_init(root_signature: *const c_char) -> *mut DeoxysHandle {
+    if root_signature.is_null() {
+        return ptr::null_mut();
+    }
+    let Ok(root_signature) = CStr::from_ptr(root_signature).to_str() else {
+        return ptr::null_mut();
+    };
+    let substrate = SovereignState::new(root_signature);
+    if !substrate.verify_integrity() {
+        return ptr::null_mut();
+    }
+    let Ok(runtime) = Builder::new_current_thread().build() else {
+        return ptr::null_mut();
+    };
     
     let handle = Box::new(DeoxysHandle {
-        engine: Arc::new(Mutex::new(engine)),
+        engine: Mutex::new(RikEngine::new(substrate)),
+        runtime,
     });
     
     Box::into_raw(handle)
@@ -69,15 +117,15 @@ pub extern "C" fn deoxys_init(
 /// 
 /// # Parameters
 /// - `handle`: Engine handle
-/// - `input`: Input state vector
+/// - `input`: Observation fed to the Bayes update
 /// - `input_len`: Length of input array
-/// - `output`: Output buffer for control action
+/// - `output`: Output buffer for the committed control state
 /// - `output_len`: Length of output buffer
 /// 
 /// # Returns
 /// Status code indicating success or failure
 #[no_mangle]
-pub extern "C" fn deoxys_step(
+pub unsafe extern "C" fn deoxys_step(
     handle: *mut DeoxysHandle,
     input: *const c_double,
     input_len: c_uint,
@@ -88,30 +136,24 @@ pub extern "C" fn deoxys_step(
         return DeoxysStatus::ErrorNullPointer as c_int;
     }
     
-    let handle = unsafe { &*handle };
+    let handle = &*handle;
     
     // Convert C array to Rust Vec
-    let input_slice = unsafe { slice::from_raw_parts(input, input_len as usize) };
-    let input_vec: Vec<f64> = input_slice.iter().copied().collect();
-    let input_state = StateVector::from_vec(input_vec);
+    let input_slice = slice::from_raw_parts(input, input_len as usize);
+    let observation = Array1::from_vec(input_slice.to_vec());
     
     // Execute RIK step
-    let result = match handle.engine.lock() {
-        Ok(engine) => engine.step(&input_state),
-        Err(_) => return DeoxysStatus::ErrorInternal as c_int,
+    let Ok(mut engine) = handle.engine.lock() else {
+        return DeoxysStatus::ErrorInternal as c_int;
     };
-    
-    match result {
-        Ok(action) => {
+    match handle.runtime.block_on(engine.execute_cycle_with(observation)) {
+        Ok(_) => {
             // Copy output to C buffer
-            let output_slice = unsafe { slice::from_raw_parts_mut(output, output_len as usize) };
-            let copy_len = output_slice.len().min(action.u.len());
-            for i in 0..copy_len {
-                output_slice[i] = action.u[i];
-            }
+            let output_slice = slice::from_raw_parts_mut(output, output_len as usize);
+            copy_state(engine.state(), output_slice);
             DeoxysStatus::Success as c_int
         }
-        Err(_) => DeoxysStatus::ErrorInvariantViolation as c_int,
+        Err(error) => DeoxysStatus::from(&error) as c_int,
     }
 }
 
@@ -122,7 +164,7 @@ pub extern "C" fn deoxys_step(
 /// - `handle` is valid
 /// - `output` points to valid writable array of length `output_len`
 #[no_mangle]
-pub extern "C" fn deoxys_get_state(
+pub unsafe extern "C" fn deoxys_get_state(
     handle: *const DeoxysHandle,
     output: *mut c_double,
     output_len: c_uint,
@@ -131,54 +173,52 @@ pub extern "C" fn deoxys_get_state(
         return DeoxysStatus::ErrorNullPointer as c_int;
     }
     
-    let handle = unsafe { &*handle };
+    let handle = &*handle;
     
-    let state = match handle.engine.lock() {
-        Ok(engine) => engine.get_state(),
-        Err(_) => return DeoxysStatus::ErrorInternal as c_int,
+    let Ok(engine) = handle.engine.lock() else {
+        return DeoxysStatus::ErrorInternal as c_int;
     };
-    
-    let output_slice = unsafe { slice::from_raw_parts_mut(output, output_len as usize) };
-    let copy_len = output_slice.len().min(state.x.len());
-    for i in 0..copy_len {
-        output_slice[i] = state.x[i];
-    }
+    let output_slice = slice::from_raw_parts_mut(output, output_len as usize);
+    copy_state(engine.state(), output_slice);
     
     DeoxysStatus::Success as c_int
 }
 
 /// Get current cycle count
+/// 
+/// # Safety
+/// `handle` must be null or valid
 #[no_mangle]
-pub extern "C" fn deoxys_get_cycle_count(handle: *const DeoxysHandle) -> c_uint {
+pub unsafe extern "C" fn deoxys_get_cycle_count(handle: *const DeoxysHandle) -> c_uint {
     if handle.is_null() {
         return 0;
     }
     
-    let handle = unsafe { &*handle };
+    let handle = &*handle;
     
     match handle.engine.lock() {
-        Ok(engine) => engine.get_cycle_count() as c_uint,
+        Ok(engine) => engine.cycle_count() as c_uint,
         Err(_) => 0,
     }
 }
 
-/// Verify cryptographic provenance chain
+/// Verify the last receipt against the engine's provenance key and state
+/// 
+/// # Safety
+/// `handle` must be null or valid
 #[no_mangle]
-pub extern "C" fn deoxys_verify_provenance(handle: *const DeoxysHandle) -> c_int {
+pub unsafe extern "C" fn deoxys_verify_provenance(handle: *const DeoxysHandle) -> c_int {
     if handle.is_null() {
         return DeoxysStatus::ErrorNullPointer as c_int;
     }
     
-    let handle = unsafe { &*handle };
+    let handle = &*handle;
     
     match handle.engine.lock() {
-        Ok(engine) => {
-            if engine.verify_provenance() {
-                DeoxysStatus::Success as c_int
-            } else {
-                DeoxysStatus::ErrorInternal as c_int
-            }
-        }
+        Ok(engine) => match engine.verify_provenance() {
+            Ok(()) => DeoxysStatus::Success as c_int,
+            Err(error) => DeoxysStatus::from(&error) as c_int,
+        },
         Err(_) => DeoxysStatus::ErrorInternal as c_int,
     }
 }
@@ -190,11 +230,9 @@ pub extern "C" fn deoxys_verify_provenance(handle: *const DeoxysHandle) -> c_int
 /// - Must not be called more than once with same handle
 /// - Handle becomes invalid after this call
 #[no_mangle]
-pub extern "C" fn deoxys_destroy(handle: *mut DeoxysHandle) {
+pub unsafe extern "C" fn deoxys_destroy(handle: *mut DeoxysHandle) {
     if !handle.is_null() {
-        unsafe {
-            let _ = Box::from_raw(handle);
-        }
+        drop(Box::from_raw(handle));
     }
 }
 
@@ -204,3 +242,124 @@ pub extern "C" fn deoxys_version() -> *const c_char {
     static VERSION_CSTRING: &str = "2.0.0\0";
     VERSION_CSTRING.as_ptr() as *const c_char
 }
+
+/// Copy as much of `state` as fits into `output`
+fn copy_state(state: &Array1<f64>, output: &mut [f64]) {
+    let copy_len = output.len().min(state.len());
+    for (out, &x) in output.iter_mut().zip(state.iter()).take(copy_len) {
+        *out = x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{ProvenanceError, ProvenanceVerifier, ReceiptRecord};
+    use crate::ledger::LogError;
+    use crate::wire::WireError;
+    use std::collections::HashSet;
+    use std::ffi::CString;
+
+    #[test]
+    fn test_errors_map_to_distinct_status_codes() {
+        let cause = DeoxysError::IntegrityViolation { reason: "root mismatch".into() };
+        let errors = [
+            cause.clone(),
+            DeoxysError::StabilityBreach {
+                cycle: 2,
+                energy: 1.5,
+                limit: Some(1.0),
+                previous_energy: None,
+                decrease_rate: None,
+                required_rate: None,
+            },
+            DeoxysError::EntropyViolation { cycle: 3, variance: 0.5, threshold: 0.1 },
+            DeoxysError::EntropyGrowth {
+                cycle: 3,
+                channel: 0,
+                previous: 1.0,
+                entropy: 2.0,
+                limit: 0.5,
+            },
+            DeoxysError::BoundViolation { cycle: 7, channel: 2, value: 1.5, min: -1.0, max: 1.0 },
+            DeoxysError::DimensionMismatch { context: "observation", expected: 10, actual: 4 },
+            DeoxysError::SensorFault { cycle: 1, source: "state", index: 0, reason: "NaN".into() },
+            DeoxysError::crypto("sign", "key unavailable"),
+            DeoxysError::InvariantViolation { invariant: "x".into(), cycle: 1, detail: "y".into() },
+            DeoxysError::config("bounds", "min >= max"),
+            DeoxysError::Lockdown { invariant: "x".into(), since_cycle: 1, cause: Box::new(cause) },
+            DeoxysError::MalformedWire { error: WireError::BadMagic },
+            ProvenanceError::BadSignature { cycle: 1 }.into(),
+            LogError::BadMagic.into(),
+            DeoxysError::ApprovalRequired { cycle: 1 },
+            DeoxysError::QuorumRequired { action: "Key rotation" },
+        ];
+        let reserved = [
+            DeoxysStatus::Success,
+            DeoxysStatus::ErrorNullPointer,
+            DeoxysStatus::ErrorInvalidHandle,
+            DeoxysStatus::ErrorInternal,
+        ];
+        let mut codes = HashSet::new();
+        for error in &errors {
+            let status = DeoxysStatus::from(error);
+            assert!(!reserved.contains(&status), "{} maps to {:?}", error.code(), status);
+            assert!(codes.insert(status as c_int), "{} shares {:?}", error.code(), status);
+        }
+        assert_eq!(
+            DeoxysStatus::from(&errors[5]) as c_int,
+            DeoxysStatus::ErrorDimensionMismatch as c_int
+        );
+    }
+
+    #[test]
+    fn test_step_reports_dimension_mismatch() {
+        let root = CString::new("C_EQUALS_XNXALEXIS_ROOT").unwrap();
+        let impostor = CString::new("IMPOSTOR").unwrap();
+        unsafe {
+            assert!(deoxys_init(impostor.as_ptr()).is_null());
+            let handle = deoxys_init(root.as_ptr());
+            assert!(!handle.is_null());
+
+            let mut output = [0.0; 10];
+            let input = [0.01; 10];
+            let status = deoxys_step(handle, input.as_ptr(), 10, output.as_mut_ptr(), 10);
+            assert_eq!(status, DeoxysStatus::Success as c_int);
+            assert_eq!(output, [0.01; 10]);
+            assert_eq!(deoxys_get_cycle_count(handle), 1);
+
+            let status = deoxys_step(handle, input.as_ptr(), 4, output.as_mut_ptr(), 10);
+            assert_eq!(status, DeoxysStatus::ErrorDimensionMismatch as c_int);
+            deoxys_destroy(handle);
+        }
+    }
+
+    #[test]
+    fn test_verify_provenance_on_handle() {
+        let root = CString::new("C_EQUALS_XNXALEXIS_ROOT").unwrap();
+        unsafe {
+            assert_eq!(
+                deoxys_verify_provenance(ptr::null()),
+                DeoxysStatus::ErrorNullPointer as c_int
+            );
+            let handle = deoxys_init(root.as_ptr());
+            // Trivially verified before the first receipt
+            assert_eq!(deoxys_verify_provenance(handle), DeoxysStatus::Success as c_int);
+
+            let input = [0.01; 10];
+            let mut output = [0.0; 10];
+            for _ in 0..3 {
+                deoxys_step(handle, input.as_ptr(), 10, output.as_mut_ptr(), 10);
+            }
+            assert_eq!(deoxys_get_cycle_count(handle), 3);
+            assert_eq!(deoxys_verify_provenance(handle), DeoxysStatus::Success as c_int);
+            // The state handed back over FFI is the one the receipt signs
+            let engine = (*handle).engine.lock().unwrap();
+            let receipt = ReceiptRecord::from(engine.last_receipt().unwrap());
+            let verifier = ProvenanceVerifier::from(engine.signer());
+            assert!(verifier.verify_state(&receipt, &Array1::from_vec(output.to_vec())).is_ok());
+            drop(engine);
+            deoxys_destroy(handle);
+        }
+    }
+}
@@ -2,39 +2,826 @@
 // Author: Alexis Adams <sovereign@axiomhive.net>
 // SPDX-License-Identifier: Proprietary
 
-use anyhow::{bail, Result};
-use ndarray::Array1;
+use crate::canonical::Encoder;
+use crate::error::{DeoxysError, Result};
+use crate::rik::OperatorBounds;
+use crate::roa::RoaReport;
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
+/// Discrete Lyapunov decrease condition:
+/// V(x_{k+1}) - V(x_k) <= -rate * V(x_k) + tolerance
+///
+/// The condition is only enforced while V(x_k) lies outside the terminal
+/// neighbourhood V <= terminal_energy, where the estimator is allowed to settle.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct DecreaseCondition {
+    /// Required fractional decrease per cycle (alpha), in [0, 1)
+    pub rate: f64,
+    /// Sublevel set around the equilibrium where decrease is not enforced
+    pub terminal_energy: f64,
+    /// Absolute slack absorbing estimator noise
+    pub tolerance: f64,
+}
+
+impl DecreaseCondition {
+    pub fn new(rate: f64, terminal_energy: f64, tolerance: f64) -> Result<Self> {
+        if !(0.0..1.0).contains(&rate) {
+            return Err(DeoxysError::config(
+                "decrease rate",
+                format!("{} must lie in [0, 1)", rate),
+            ));
+        }
+        if !terminal_energy.is_finite() || terminal_energy < 0.0 {
+            return Err(DeoxysError::config(
+                "terminal energy",
+                format!("{} must be finite and non-negative", terminal_energy),
+            ));
+        }
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(DeoxysError::config(
+                "noise tolerance",
+                format!("{} must be finite and non-negative", tolerance),
+            ));
+        }
+        Ok(Self { rate, terminal_energy, tolerance })
+    }
+}
+
+impl Default for DecreaseCondition {
+    fn default() -> Self {
+        Self {
+            rate: 0.05,
+            terminal_energy: 0.01,
+            tolerance: 1e-6,
+        }
+    }
+}
+
+/// Whether the variance (entropy) bound fails the cycle or is only observed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntropyMode {
+    Permissive,
+    Strict,
+}
+
+impl EntropyMode {
+    /// Stable code used in canonical encodings
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Permissive => 0,
+            Self::Strict => 1,
+        }
+    }
+}
+
+/// Period during which strict entropy enforcement is suspended
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarmUp {
+    /// Enforce from cycle `n + 1` onward
+    Cycles(u64),
+    /// Enforce once |ΔV| <= tolerance has held for `window` consecutive cycles
+    Convergence { tolerance: f64, window: u64 },
+}
+
+/// Zero Entropy Law policy: mode, variance threshold and warm-up
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct EntropyPolicy {
+    pub mode: EntropyMode,
+    pub threshold: f64,
+    pub warm_up: WarmUp,
+}
+
+impl EntropyPolicy {
+    pub fn new(mode: EntropyMode, threshold: f64, warm_up: WarmUp) -> Result<Self> {
+        if !threshold.is_finite() || threshold <= 0.0 {
+            return Err(DeoxysError::config(
+                "entropy threshold",
+                format!("{} must be finite and positive", threshold),
+            ));
+        }
+        if let WarmUp::Convergence { tolerance, window } = warm_up {
+            if !tolerance.is_finite() || tolerance < 0.0 {
+                return Err(DeoxysError::config(
+                    "convergence tolerance",
+                    format!("{} must be finite and non-negative", tolerance),
+                ));
+            }
+            if window == 0 {
+                return Err(DeoxysError::config(
+                    "convergence window",
+                    "must span at least one cycle",
+                ));
+            }
+        }
+        Ok(Self { mode, threshold, warm_up })
+    }
+
+    /// Strict enforcement after the given warm-up
+    pub fn strict(threshold: f64, warm_up: WarmUp) -> Result<Self> {
+        Self::new(EntropyMode::Strict, threshold, warm_up)
+    }
+
+    /// Append every field to a canonical message that embeds this policy
+    pub(crate) fn encode(&self, encoder: Encoder) -> Encoder {
+        let encoder = encoder.u8(self.mode.code()).f64(self.threshold);
+        match self.warm_up {
+            WarmUp::Cycles(n) => encoder.u8(0).u64(n),
+            WarmUp::Convergence { tolerance, window } => encoder.u8(1).f64(tolerance).u64(window),
+        }
+    }
+}
+
+impl Default for EntropyPolicy {
+    fn default() -> Self {
+        Self {
+            mode: EntropyMode::Permissive,
+            threshold: 0.001, // Tight bound for Zero Entropy
+            warm_up: WarmUp::Cycles(0),
+        }
+    }
+}
+
+/// Outcome of a single invariant evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    /// Cycle proceeds; detail is surfaced in the receipt and log
+    Warn(String),
+    /// Cycle is rejected
+    Fail(DeoxysError),
+}
+
+/// Everything an invariant may inspect for the current cycle
+pub struct InvariantContext<'a> {
+    pub cycle: u64,
+    /// Belief state after the Bayes update, before safety projection
+    pub state: &'a Array1<f64>,
+    /// Control output after safety projection
+    pub control: &'a Array1<f64>,
+    /// Belief covariance after the Bayes update
+    pub covariance: &'a Array2<f64>,
+    /// Committed belief states of previous cycles, oldest first
+    pub history: &'a VecDeque<Array1<f64>>,
+    pub bounds: OperatorBounds,
+}
+
+impl InvariantContext<'_> {
+    /// Committed state of the previous cycle, if any
+    pub fn previous(&self) -> Option<&Array1<f64>> {
+        self.history.back()
+    }
+}
+
+/// A named check run by the `InvariantRegistry` once per cycle
+pub trait Invariant: Send {
+    fn name(&self) -> &str;
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict;
+
+    /// Whether a breach of this invariant currently fails the cycle
+    fn enforcing(&self) -> bool {
+        true
+    }
+}
+
+/// Lyapunov candidate V(x) = ||x||^2
+pub fn energy(state_vector: &Array1<f64>) -> f64 {
+    state_vector.iter().map(|x| x.powi(2)).sum()
+}
+
+/// Bounds V(x): the Inverted Lagrangian check, energy must minimize, not explode
+pub struct EnergyBound {
+    limit: f64,
+}
+
+impl EnergyBound {
+    pub const NAME: &'static str = "energy-bound";
+
+    pub fn new(limit: f64) -> Result<Self> {
+        if !limit.is_finite() || limit <= 0.0 {
+            return Err(DeoxysError::config(
+                "energy limit",
+                format!("{} must be finite and positive", limit),
+            ));
+        }
+        Ok(Self { limit })
+    }
+
+    pub fn limit(&self) -> f64 {
+        self.limit
+    }
+}
+
+impl Default for EnergyBound {
+    fn default() -> Self {
+        Self { limit: 1.0 } // Unity bound
+    }
+}
+
+impl Invariant for EnergyBound {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let energy = energy(ctx.state);
+        if energy > self.limit {
+            // Divergence detected
+            return Verdict::Fail(DeoxysError::StabilityBreach {
+                cycle: ctx.cycle,
+                energy,
+                limit: Some(self.limit),
+                previous_energy: None,
+                decrease_rate: None,
+                required_rate: None,
+            });
+        }
+        Verdict::Pass
+    }
+}
+
+/// Enforces ΔV < 0 against the previous cycle's V, both taken on the
+/// belief state before safety projection
+pub struct LyapunovDecrease {
+    condition: DecreaseCondition,
+    previous_energy: Option<f64>,
+}
+
+impl LyapunovDecrease {
+    pub const NAME: &'static str = "lyapunov-decrease";
+
+    pub fn new(condition: DecreaseCondition) -> Self {
+        Self { condition, previous_energy: None }
+    }
+}
+
+impl Invariant for LyapunovDecrease {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let next = energy(ctx.state);
+        let Some(previous) = self.previous_energy.replace(next) else {
+            return Verdict::Pass;
+        };
+        if previous <= self.condition.terminal_energy {
+            return Verdict::Pass;
+        }
+        let delta = next - previous;
+        if delta > -self.condition.rate * previous + self.condition.tolerance {
+            return Verdict::Fail(DeoxysError::StabilityBreach {
+                cycle: ctx.cycle,
+                energy: next,
+                limit: None,
+                previous_energy: Some(previous),
+                decrease_rate: Some(delta / previous),
+                required_rate: Some(self.condition.rate),
+            });
+        }
+        Verdict::Pass
+    }
+}
+
+/// Zero Entropy Law variance bound with strict mode and warm-up
+pub struct VarianceBound {
+    policy: EntropyPolicy,
+    previous_energy: Option<f64>,
+    converged_cycles: u64,
+    warmed_up: bool,
+}
+
+impl VarianceBound {
+    pub const NAME: &'static str = "variance";
+
+    pub fn new(policy: EntropyPolicy) -> Self {
+        Self {
+            policy,
+            previous_energy: None,
+            converged_cycles: 0,
+            warmed_up: false,
+        }
+    }
+
+    pub fn policy(&self) -> EntropyPolicy {
+        self.policy
+    }
+
+    /// Warm-up latches: once complete it stays complete until the policy changes
+    fn advance_warm_up(&mut self, ctx: &InvariantContext) {
+        let energy = energy(ctx.state);
+        let previous = self.previous_energy.replace(energy);
+        if self.warmed_up {
+            return;
+        }
+        self.warmed_up = match self.policy.warm_up {
+            WarmUp::Cycles(n) => ctx.cycle > n,
+            WarmUp::Convergence { tolerance, window } => {
+                let settled =
+                    previous.is_some_and(|previous| (energy - previous).abs() <= tolerance);
+                self.converged_cycles = if settled { self.converged_cycles + 1 } else { 0 };
+                self.converged_cycles >= window
+            }
+        };
+    }
+}
+
+impl Invariant for VarianceBound {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        self.advance_warm_up(ctx);
+
+        // Single-vector variance proxy for entropy; the windowed Shannon
+        // estimate per channel is `entropy::EntropyGrowth`
+        let variance = ctx.state.var(0.0);
+        if variance <= self.policy.threshold {
+            return Verdict::Pass;
+        }
+        // In a deterministic system, high variance implies hallucination or noise
+        let violation = DeoxysError::EntropyViolation {
+            cycle: ctx.cycle,
+            variance,
+            threshold: self.policy.threshold,
+        };
+        if self.enforcing() {
+            Verdict::Fail(violation)
+        } else {
+            Verdict::Warn(violation.to_string())
+        }
+    }
+
+    /// True once strict mode is active and warm-up has completed
+    fn enforcing(&self) -> bool {
+        self.policy.mode == EntropyMode::Strict && self.warmed_up
+    }
+}
+
+/// Rejects NaN or infinite values anywhere in state, control or covariance
+pub struct FiniteValues;
+
+impl FiniteValues {
+    pub const NAME: &'static str = "finite-values";
+}
+
+impl Invariant for FiniteValues {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let sources = [
+            ("state", ctx.state.iter().position(|x| !x.is_finite())),
+            ("control", ctx.control.iter().position(|x| !x.is_finite())),
+            ("covariance", ctx.covariance.iter().position(|x| !x.is_finite())),
+        ];
+        for (source, index) in sources {
+            if let Some(index) = index {
+                return Verdict::Fail(DeoxysError::SensorFault {
+                    cycle: ctx.cycle,
+                    source,
+                    index,
+                    reason: "non-finite value".to_string(),
+                });
+            }
+        }
+        Verdict::Pass
+    }
+}
+
+/// Verifies all outputs are strictly bounded by the operator's intent
+pub struct BoundAdherence;
+
+impl BoundAdherence {
+    pub const NAME: &'static str = "bound-adherence";
+}
+
+impl Invariant for BoundAdherence {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let bounds = ctx.bounds;
+        for (channel, &val) in ctx.control.iter().enumerate() {
+            if val < bounds.min || val > bounds.max {
+                return Verdict::Fail(DeoxysError::BoundViolation {
+                    cycle: ctx.cycle,
+                    channel,
+                    value: val,
+                    min: bounds.min,
+                    max: bounds.max,
+                });
+            }
+        }
+        let clipped = ctx
+            .state
+            .iter()
+            .filter(|&&x| x < bounds.min || x > bounds.max)
+            .count();
+        if clipped > 0 {
+            return Verdict::Warn(format!(
+                "Safety projection clipped {} channel(s) to [{}, {}]",
+                clipped, bounds.min, bounds.max
+            ));
+        }
+        Verdict::Pass
+    }
+}
+
+/// Limits the per-channel change of the state between consecutive cycles
+pub struct RateOfChange {
+    warn_delta: f64,
+    max_delta: f64,
+}
+
+impl RateOfChange {
+    pub const NAME: &'static str = "rate-of-change";
+
+    /// Warn above `warn_delta`, fail above `max_delta` (per channel, per cycle)
+    pub fn new(warn_delta: f64, max_delta: f64) -> Result<Self> {
+        if !max_delta.is_finite() || max_delta <= 0.0 {
+            return Err(DeoxysError::config(
+                "rate limit",
+                format!("{} must be finite and positive", max_delta),
+            ));
+        }
+        if !(0.0..=max_delta).contains(&warn_delta) {
+            return Err(DeoxysError::config(
+                "rate warning level",
+                format!("{} must lie in [0, {}]", warn_delta, max_delta),
+            ));
+        }
+        Ok(Self { warn_delta, max_delta })
+    }
+}
+
+impl Invariant for RateOfChange {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let Some(previous) = ctx.previous() else {
+            return Verdict::Pass;
+        };
+        let (channel, delta) = ctx
+            .state
+            .iter()
+            .zip(previous.iter())
+            .map(|(x, p)| (x - p).abs())
+            .enumerate()
+            .fold((0, 0.0), |best, (i, d)| if d > best.1 { (i, d) } else { best });
+        if delta > self.max_delta {
+            Verdict::Fail(DeoxysError::InvariantViolation {
+                invariant: Self::NAME.to_string(),
+                cycle: ctx.cycle,
+                detail: format!("channel {} moved {} (limit {})", channel, delta, self.max_delta),
+            })
+        } else if delta > self.warn_delta {
+            Verdict::Warn(format!(
+                "Rate warning at cycle {}: channel {} moved {} (warn above {})",
+                ctx.cycle, channel, delta, self.warn_delta
+            ))
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+/// Energy bound, Lyapunov decrease and Zero Entropy Law checked together on
+/// one state per cycle, as the engine's standard invariants do
 pub struct LyapunovValidator {
-    energy_threshold: f64,
+    energy: EnergyBound,
+    decrease: LyapunovDecrease,
+    variance: VarianceBound,
 }
 
 impl LyapunovValidator {
     pub fn new() -> Self {
+        Self::with_policy(DecreaseCondition::default(), EntropyPolicy::default())
+    }
+
+    pub fn with_policy(decrease: DecreaseCondition, entropy: EntropyPolicy) -> Self {
         Self {
-            energy_threshold: 0.001, // Tight bound for Zero Entropy
+            energy: EnergyBound::default(),
+            decrease: LyapunovDecrease::new(decrease),
+            variance: VarianceBound::new(entropy),
         }
     }
 
-    /// Enforces V(x) < 0 (Stability)
-    pub fn check_stability(&self, state_vector: &Array1<f64>) -> Result<()> {
-        let energy: f64 = state_vector.iter().map(|x| x.powi(2)).sum();
-        
-        // The Inverted Lagrangian check: Energy must minimize, not explode
-        if energy > 1.0 {
-            // Divergence detected
-            bail!("Lyapunov Unstable: System energy {} exceeds unity bound.", energy);
+    /// Bound V(x) by the level of a verified region of attraction, so every
+    /// accepted state is one the closed loop provably recovers from
+    pub fn with_region_of_attraction(mut self, report: &RoaReport) -> Result<Self> {
+        self.set_energy_limit(report.energy_limit()?)?;
+        Ok(self)
+    }
+
+    /// Replace the V(x) limit of the energy bound
+    pub fn set_energy_limit(&mut self, limit: f64) -> Result<()> {
+        self.energy = EnergyBound::new(limit)?;
+        Ok(())
+    }
+
+    pub fn energy_limit(&self) -> f64 {
+        self.energy.limit()
+    }
+
+    /// Replace the decrease condition enforced between consecutive cycles
+    pub fn set_decrease_condition(&mut self, decrease: DecreaseCondition) {
+        self.decrease.condition = decrease;
+    }
+
+    /// Replace the entropy policy; warm-up restarts from the next cycle
+    pub fn set_entropy_policy(&mut self, entropy: EntropyPolicy) {
+        self.variance = VarianceBound::new(entropy);
+    }
+
+    pub fn entropy_policy(&self) -> EntropyPolicy {
+        self.variance.policy()
+    }
+
+    /// True once strict mode is active and warm-up has completed
+    pub fn entropy_enforced(&self) -> bool {
+        self.variance.enforcing()
+    }
+
+    /// Lyapunov candidate V(x) = ||x||^2
+    pub fn energy(state_vector: &Array1<f64>) -> f64 {
+        energy(state_vector)
+    }
+
+    /// Enforces V(x) bounded and ΔV < 0 across consecutive cycles (Stability)
+    pub fn check_stability(&mut self, state_vector: &Array1<f64>, cycle: u64) -> Result<()> {
+        let covariance = Array2::zeros((0, 0));
+        let history = VecDeque::new();
+        let ctx = InvariantContext {
+            cycle,
+            state: state_vector,
+            control: state_vector,
+            covariance: &covariance,
+            history: &history,
+            bounds: OperatorBounds::default(),
+        };
+        // Every check observes the cycle; the first failure in order is reported
+        let verdicts = [
+            self.energy.evaluate(&ctx),
+            self.decrease.evaluate(&ctx),
+            self.variance.evaluate(&ctx),
+        ];
+        verdicts
+            .into_iter()
+            .find_map(|verdict| match verdict {
+                Verdict::Fail(error) => Some(error),
+                _ => None,
+            })
+            .map_or(Ok(()), Err)
+    }
+
+    /// The wrapped invariants in evaluation order, for an `InvariantRegistry`
+    pub fn into_invariants(self) -> [Box<dyn Invariant>; 3] {
+        [Box::new(self.energy), Box::new(self.decrease), Box::new(self.variance)]
+    }
+}
+
+impl Default for LyapunovValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixture {
+        state: Array1<f64>,
+        control: Array1<f64>,
+        covariance: Array2<f64>,
+        history: VecDeque<Array1<f64>>,
+    }
+
+    impl Fixture {
+        fn new(state: Vec<f64>) -> Self {
+            let state = Array1::from_vec(state);
+            Self {
+                control: state.clone(),
+                covariance: Array2::eye(state.len()),
+                state,
+                history: VecDeque::new(),
+            }
         }
-        
-        // Entropy check (simplified Shannon approximation for numeric vector)
-        // Ideally, we want low variance implies low entropy in this control context
-        let variance = state_vector.var(0.0);
-        if variance > self.energy_threshold {
-             // In a deterministic system, high variance implies hallucination or noise
-             // bail!("Entropy Violation: Variance {} exceeds threshold.", variance);
-             // NOTE: Commented out to allow initial convergence, strict mode would enable this.
+
+        fn after(mut self, previous: Vec<f64>) -> Self {
+            self.history.push_back(Array1::from_vec(previous));
+            self
         }
 
-        Ok(())
+        fn ctx(&self, cycle: u64) -> InvariantContext<'_> {
+            InvariantContext {
+                cycle,
+                state: &self.state,
+                control: &self.control,
+                covariance: &self.covariance,
+                history: &self.history,
+                bounds: OperatorBounds::default(),
+            }
+        }
+    }
+
+    fn is_fail(verdict: &Verdict) -> bool {
+        matches!(verdict, Verdict::Fail(_))
+    }
+
+    #[test]
+    fn test_decrease_condition_validation() {
+        assert!(DecreaseCondition::new(0.1, 0.01, 1e-6).is_ok());
+        assert!(DecreaseCondition::new(1.0, 0.01, 1e-6).is_err());
+        assert!(DecreaseCondition::new(-0.1, 0.01, 1e-6).is_err());
+        assert!(DecreaseCondition::new(0.1, f64::NAN, 1e-6).is_err());
+        assert!(DecreaseCondition::new(0.1, 0.01, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_decreasing_energy_passes() {
+        let mut validator = LyapunovValidator::new();
+        let mut state = Array1::from_vec(vec![0.3; 10]);
+        for cycle in 1..=10 {
+            assert!(validator.check_stability(&state, cycle).is_ok());
+            state.mapv_inplace(|x| x * 0.9);
+        }
+    }
+
+    #[test]
+    fn test_increasing_energy_outside_terminal_set_fails() {
+        let mut validator = LyapunovValidator::new();
+        assert!(validator.check_stability(&Array1::from_vec(vec![0.1; 10]), 1).is_ok());
+
+        let err = validator
+            .check_stability(&Array1::from_vec(vec![0.11; 10]), 2)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("cycle 2"), "{}", err);
+    }
+
+    #[test]
+    fn test_terminal_neighbourhood_not_enforced() {
+        let mut validator = LyapunovValidator::new();
+        // V = 0.001 lies inside the default terminal set V <= 0.01
+        assert!(validator.check_stability(&Array1::from_vec(vec![0.01; 10]), 1).is_ok());
+        assert!(validator.check_stability(&Array1::from_vec(vec![0.02; 10]), 2).is_ok());
+    }
+
+    #[test]
+    fn test_noise_within_tolerance_passes() {
+        let mut validator = LyapunovValidator::new();
+        validator.set_decrease_condition(DecreaseCondition::new(0.0, 0.0, 1e-3).unwrap());
+        assert!(validator.check_stability(&Array1::from_vec(vec![0.1; 10]), 1).is_ok());
+        // ΔV = 10 * (0.10001^2 - 0.1^2) ≈ 2e-5, inside the 1e-3 noise budget
+        assert!(validator.check_stability(&Array1::from_vec(vec![0.10001; 10]), 2).is_ok());
+    }
+
+    fn spread_state() -> Array1<f64> {
+        // Variance 0.0025 with V = 0.025
+        Array1::from_vec(vec![0.0, 0.1, 0.0, 0.1, 0.0, 0.1, 0.0, 0.1, 0.0, 0.1])
+    }
+
+    fn steady_validator(entropy: EntropyPolicy) -> LyapunovValidator {
+        // Steady state: keep the decrease condition out of the way
+        LyapunovValidator::with_policy(DecreaseCondition::new(0.0, 1.0, 0.0).unwrap(), entropy)
+    }
+
+    #[test]
+    fn test_permissive_mode_ignores_variance() {
+        let mut validator = steady_validator(EntropyPolicy::default());
+        for cycle in 1..=5 {
+            assert!(validator.check_stability(&spread_state(), cycle).is_ok());
+        }
+        assert!(!validator.entropy_enforced());
+    }
+
+    #[test]
+    fn test_strict_mode_after_cycle_warm_up() {
+        let policy = EntropyPolicy::strict(0.001, WarmUp::Cycles(3)).unwrap();
+        let mut validator = steady_validator(policy);
+        for cycle in 1..=3 {
+            assert!(validator.check_stability(&spread_state(), cycle).is_ok());
+        }
+        let err = validator.check_stability(&spread_state(), 4).unwrap_err().to_string();
+        assert!(err.contains("Entropy Violation at cycle 4"), "{}", err);
+    }
+
+    #[test]
+    fn test_strict_mode_after_convergence() {
+        let policy = EntropyPolicy::strict(
+            0.001,
+            WarmUp::Convergence { tolerance: 1e-9, window: 2 },
+        )
+        .unwrap();
+        let mut validator = steady_validator(policy);
+
+        // First sample has no ΔV, second and third are settled
+        assert!(validator.check_stability(&spread_state(), 1).is_ok());
+        assert!(validator.check_stability(&spread_state(), 2).is_ok());
+        assert!(!validator.entropy_enforced());
+        assert!(validator.check_stability(&spread_state(), 3).is_err());
+        assert!(validator.entropy_enforced());
+    }
+
+    #[test]
+    fn test_entropy_policy_validation() {
+        assert!(EntropyPolicy::strict(0.0, WarmUp::Cycles(0)).is_err());
+        assert!(EntropyPolicy::strict(f64::INFINITY, WarmUp::Cycles(0)).is_err());
+        let settle = |window| WarmUp::Convergence { tolerance: 1e-6, window };
+        assert!(EntropyPolicy::strict(0.1, settle(0)).is_err());
+        assert!(EntropyPolicy::strict(0.1, settle(5)).is_ok());
+    }
+
+    #[test]
+    fn test_energy_bound() {
+        let mut bound = EnergyBound::default();
+        assert_eq!(bound.evaluate(&Fixture::new(vec![0.3; 10]).ctx(1)), Verdict::Pass);
+        assert!(is_fail(&bound.evaluate(&Fixture::new(vec![0.4; 10]).ctx(1))));
+        assert!(EnergyBound::new(0.0).is_err());
+    }
+
+    #[test]
+    fn test_decrease_breach_details() {
+        let mut decrease = LyapunovDecrease::new(DecreaseCondition::default());
+        assert_eq!(decrease.evaluate(&Fixture::new(vec![0.1; 10]).ctx(1)), Verdict::Pass);
+        match decrease.evaluate(&Fixture::new(vec![0.11; 10]).ctx(2)) {
+            Verdict::Fail(DeoxysError::StabilityBreach {
+                cycle,
+                previous_energy,
+                decrease_rate,
+                ..
+            }) => {
+                assert_eq!(cycle, 2);
+                assert!((previous_energy.unwrap() - 0.1).abs() < 1e-12);
+                assert!(decrease_rate.unwrap() > 0.0);
+            }
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decrease_compares_unprojected_states() {
+        let mut decrease = LyapunovDecrease::new(DecreaseCondition::default());
+        assert_eq!(decrease.evaluate(&Fixture::new(vec![0.3; 10]).ctx(1)), Verdict::Pass);
+        // The committed state was projected to 0.2; V still falls from the unprojected 0.3
+        let projected = Fixture::new(vec![0.28; 10]).after(vec![0.2; 10]);
+        assert_eq!(decrease.evaluate(&projected.ctx(2)), Verdict::Pass);
+    }
+
+    #[test]
+    fn test_finite_values() {
+        let mut finite = FiniteValues;
+        assert_eq!(finite.evaluate(&Fixture::new(vec![0.1; 4]).ctx(1)), Verdict::Pass);
+
+        let mut fixture = Fixture::new(vec![0.1; 4]);
+        fixture.covariance[[2, 2]] = f64::NAN;
+        match finite.evaluate(&fixture.ctx(1)) {
+            Verdict::Fail(DeoxysError::SensorFault { source, index, .. }) => {
+                assert_eq!((source, index), ("covariance", 10));
+            }
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_bound_adherence() {
+        let mut adherence = BoundAdherence;
+        let mut fixture = Fixture::new(vec![1.5, 0.0]);
+        assert!(matches!(
+            adherence.evaluate(&fixture.ctx(1)),
+            Verdict::Fail(DeoxysError::BoundViolation { channel: 0, .. })
+        ));
+
+        // Projected control inside bounds, but the raw state had to be clipped
+        fixture.control = Array1::from_vec(vec![1.0, 0.0]);
+        assert!(matches!(adherence.evaluate(&fixture.ctx(1)), Verdict::Warn(_)));
+    }
+
+    #[test]
+    fn test_rate_of_change() {
+        let mut rate = RateOfChange::new(0.05, 0.1).unwrap();
+        assert_eq!(rate.evaluate(&Fixture::new(vec![0.5; 3]).ctx(1)), Verdict::Pass);
+
+        let slow = Fixture::new(vec![0.02, 0.0, 0.0]).after(vec![0.0; 3]);
+        assert_eq!(rate.evaluate(&slow.ctx(2)), Verdict::Pass);
+        let brisk = Fixture::new(vec![0.0, 0.07, 0.0]).after(vec![0.0; 3]);
+        assert!(matches!(rate.evaluate(&brisk.ctx(2)), Verdict::Warn(_)));
+        let fast = Fixture::new(vec![0.0, 0.0, -0.2]).after(vec![0.0; 3]);
+        match rate.evaluate(&fast.ctx(2)) {
+            Verdict::Fail(error) => assert!(error.to_string().contains("channel 2"), "{}", error),
+            other => panic!("expected failure, got {:?}", other),
+        }
+        assert!(RateOfChange::new(0.2, 0.1).is_err());
     }
 }
@@ -0,0 +1,525 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Passphrase-encrypted store for provenance and operator signing keys.
+//!
+//! The store holds any number of named Ed25519 keys with their metadata. The
+//! passphrase is stretched with Argon2id and the key list is sealed with
+//! XChaCha20-Poly1305. All integers are little-endian:
+//!
+//! ```text
+//! offset  size  field
+//!      0     4  magic "DXKS"
+//!      4     2  format version (1)
+//!      6     4  Argon2id memory cost, KiB
+//!     10     4  Argon2id iterations
+//!     14     4  Argon2id parallelism
+//!     18    16  salt
+//!     34    24  nonce
+//!     58     *  sealed key list (JSON) followed by the 16-byte tag
+//! ```
+//!
+//! The header is authenticated as associated data, so weakening the KDF
+//! parameters or swapping the salt fails to unlock like a wrong passphrase.
+//! Every save draws a fresh nonce, and decrypted seeds and plaintext are wiped
+//! when dropped.
+
+use crate::crypto::{check_private, write_file, ProvenanceSigner};
+use crate::error::{DeoxysError, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{Key, XChaCha20Poly1305, XNonce};
+use ed25519_dalek::SECRET_KEY_LENGTH;
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use zeroize::Zeroizing;
+
+const MAGIC: &[u8; 4] = b"DXKS";
+const VERSION: u16 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const HEADER_LEN: usize = 4 + 2 + 4 * 3 + SALT_LEN + NONCE_LEN;
+const TAG_LEN: usize = 16;
+
+/// Upper bounds on KDF costs accepted from a header, so a tampered file
+/// cannot make unlocking exhaust memory or stall before the tag is checked
+const MAX_MEMORY_KIB: u32 = 4 * 1024 * 1024;
+const MAX_ITERATIONS: u32 = 64;
+const MAX_PARALLELISM: u32 = 16;
+
+/// Argon2id cost parameters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    /// 64 MiB, 3 passes, single lane
+    fn default() -> Self {
+        Self {
+            memory_kib: 64 * 1024,
+            iterations: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+impl KdfParams {
+    fn argon2(&self) -> Result<Argon2<'static>> {
+        if self.memory_kib > MAX_MEMORY_KIB
+            || self.iterations > MAX_ITERATIONS
+            || self.parallelism > MAX_PARALLELISM
+        {
+            return Err(DeoxysError::crypto(
+                "keystore kdf",
+                format!("parameters {:?} exceed the supported maximum", self),
+            ));
+        }
+        let params = Params::new(
+            self.memory_kib,
+            self.iterations,
+            self.parallelism,
+            Some(32),
+        )
+        .map_err(|e| DeoxysError::crypto("keystore kdf", e))?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+
+    fn derive(&self, passphrase: &[u8], salt: &[u8; SALT_LEN]) -> Result<Zeroizing<[u8; 32]>> {
+        let mut key = Zeroizing::new([0u8; 32]);
+        self.argon2()?
+            .hash_password_into(passphrase, salt, key.as_mut())
+            .map_err(|e| DeoxysError::crypto("keystore kdf", e))?;
+        Ok(key)
+    }
+}
+
+/// Public description of a stored key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyMetadata {
+    pub name: String,
+    pub label: String,
+    /// Unix seconds
+    pub created_at: u64,
+    /// Recomputed from the seed on unlock, never read from the file
+    #[serde(skip)]
+    pub fingerprint: String,
+}
+
+/// Sealed form of one entry
+#[derive(Serialize, Deserialize)]
+struct StoredKey {
+    #[serde(flatten)]
+    metadata: KeyMetadata,
+    seed: String,
+}
+
+struct KeyEntry {
+    metadata: KeyMetadata,
+    seed: Zeroizing<[u8; SECRET_KEY_LENGTH]>,
+}
+
+/// Unlocked keystore. Every change is written back immediately.
+pub struct Keystore {
+    path: PathBuf,
+    kdf: KdfParams,
+    salt: [u8; SALT_LEN],
+    key: Zeroizing<[u8; 32]>,
+    entries: Vec<KeyEntry>,
+}
+
+impl fmt::Debug for Keystore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keystore")
+            .field("path", &self.path)
+            .field("kdf", &self.kdf)
+            .field("keys", &self.keys().map(|k| &k.name).collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Keystore {
+    /// Create an empty keystore at `path`; an existing file is never replaced
+    pub fn create(path: impl AsRef<Path>, passphrase: &[u8], kdf: KdfParams) -> Result<Self> {
+        let path = path.as_ref();
+        if path.exists() {
+            return Err(DeoxysError::crypto(
+                "keystore create",
+                format!("{}: already exists", path.display()),
+            ));
+        }
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|e| {
+                DeoxysError::crypto("keystore create", format!("{}: {}", parent.display(), e))
+            })?;
+        }
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let store = Self {
+            path: path.to_path_buf(),
+            key: kdf.derive(passphrase, &salt)?,
+            kdf,
+            salt,
+            entries: Vec::new(),
+        };
+        store.save()?;
+        Ok(store)
+    }
+
+    /// Decrypt the keystore at `path`. On unix, files readable by group or
+    /// others are refused.
+    pub fn unlock(path: impl AsRef<Path>, passphrase: &[u8]) -> Result<Self> {
+        let path = path.as_ref();
+        let fail = |reason: String| {
+            DeoxysError::crypto("keystore unlock", format!("{}: {}", path.display(), reason))
+        };
+        check_private(path).map_err(fail)?;
+        let bytes = fs::read(path).map_err(|e| fail(e.to_string()))?;
+        if bytes.len() < HEADER_LEN + TAG_LEN || &bytes[..4] != MAGIC {
+            return Err(fail("not a keystore".into()));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != VERSION {
+            return Err(fail(format!("unsupported version {}", version)));
+        }
+        let word = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let kdf = KdfParams {
+            memory_kib: word(6),
+            iterations: word(10),
+            parallelism: word(14),
+        };
+        let salt: [u8; SALT_LEN] = bytes[18..34].try_into().unwrap();
+        let (header, sealed) = bytes.split_at(HEADER_LEN);
+        let key = kdf.derive(passphrase, &salt)?;
+        let plaintext = XChaCha20Poly1305::new(Key::from_slice(key.as_ref()))
+            .decrypt(
+                XNonce::from_slice(&header[34..]),
+                Payload {
+                    msg: sealed,
+                    aad: header,
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| fail("wrong passphrase or corrupted keystore".into()))?;
+        let stored: Vec<StoredKey> =
+            serde_json::from_slice(&plaintext).map_err(|e| fail(e.to_string()))?;
+        let entries = stored
+            .into_iter()
+            .map(|entry| {
+                let seed = Zeroizing::new(entry.seed);
+                let mut bytes = Zeroizing::new([0u8; SECRET_KEY_LENGTH]);
+                hex::decode_to_slice(seed.as_str(), bytes.as_mut())
+                    .map_err(|e| fail(format!("key {}: {}", entry.metadata.name, e)))?;
+                let mut metadata = entry.metadata;
+                metadata.fingerprint = ProvenanceSigner::from_seed(&bytes).fingerprint().into();
+                Ok(KeyEntry {
+                    metadata,
+                    seed: bytes,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            kdf,
+            salt,
+            key,
+            entries,
+        })
+    }
+
+    /// Unlock `path`, or create it with default KDF parameters on first run
+    pub fn open_or_create(path: impl AsRef<Path>, passphrase: &[u8]) -> Result<Self> {
+        let path = path.as_ref();
+        if path.exists() {
+            Self::unlock(path, passphrase)
+        } else {
+            Self::create(path, passphrase, KdfParams::default())
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn kdf(&self) -> KdfParams {
+        self.kdf
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &KeyMetadata> {
+        self.entries.iter().map(|entry| &entry.metadata)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.entries.iter().any(|entry| entry.metadata.name == name)
+    }
+
+    /// Signer for the key stored under `name`
+    pub fn signer(&self, name: &str) -> Result<ProvenanceSigner> {
+        self.entries
+            .iter()
+            .find(|entry| entry.metadata.name == name)
+            .map(|entry| ProvenanceSigner::from_seed(&entry.seed))
+            .ok_or_else(|| DeoxysError::crypto("keystore lookup", format!("no key named {}", name)))
+    }
+
+    /// Store `signer` under a new `name` and save
+    pub fn insert(&mut self, name: &str, signer: &ProvenanceSigner, label: &str) -> Result<()> {
+        if self.contains(name) {
+            return Err(DeoxysError::crypto(
+                "keystore insert",
+                format!("key {} already exists", name),
+            ));
+        }
+        let created_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or(0);
+        self.entries.push(KeyEntry {
+            metadata: KeyMetadata {
+                name: name.to_string(),
+                label: label.to_string(),
+                created_at,
+                fingerprint: signer.fingerprint().to_string(),
+            },
+            seed: signer.seed(),
+        });
+        self.save().inspect_err(|_| {
+            self.entries.pop();
+        })
+    }
+
+    /// Generate a fresh key under `name` and save
+    pub fn generate(&mut self, name: &str, label: &str) -> Result<ProvenanceSigner> {
+        let signer = ProvenanceSigner::new();
+        self.insert(name, &signer, label)?;
+        Ok(signer)
+    }
+
+    /// Delete the key under `name` and save
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.metadata.name == name)
+            .ok_or_else(|| {
+                DeoxysError::crypto("keystore remove", format!("no key named {}", name))
+            })?;
+        let entry = self.entries.remove(index);
+        self.save().inspect_err(|_| {
+            self.entries.insert(index, entry);
+        })
+    }
+
+    /// Re-encrypt every key under `passphrase` with a fresh salt
+    pub fn change_passphrase(&mut self, passphrase: &[u8]) -> Result<()> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let key = self.kdf.derive(passphrase, &salt)?;
+        let previous = (
+            std::mem::replace(&mut self.salt, salt),
+            std::mem::replace(&mut self.key, key),
+        );
+        self.save().inspect_err(|_| {
+            (self.salt, self.key) = previous;
+        })
+    }
+
+    /// Seal the key list under a fresh nonce and replace the file atomically
+    fn save(&self) -> Result<()> {
+        let stored: Vec<StoredKey> = self
+            .entries
+            .iter()
+            .map(|entry| StoredKey {
+                metadata: entry.metadata.clone(),
+                seed: hex::encode(entry.seed.as_ref()),
+            })
+            .collect();
+        let plaintext = Zeroizing::new(
+            serde_json::to_vec(&stored).map_err(|e| DeoxysError::crypto("keystore save", e))?,
+        );
+        // Hex copies of the seeds are wiped before the list is dropped
+        stored
+            .into_iter()
+            .for_each(|entry| drop(Zeroizing::new(entry.seed)));
+
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let mut bytes = Vec::with_capacity(HEADER_LEN + plaintext.len() + TAG_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.kdf.memory_kib.to_le_bytes());
+        bytes.extend_from_slice(&self.kdf.iterations.to_le_bytes());
+        bytes.extend_from_slice(&self.kdf.parallelism.to_le_bytes());
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&nonce);
+        let sealed = XChaCha20Poly1305::new(Key::from_slice(self.key.as_ref()))
+            .encrypt(
+                XNonce::from_slice(&nonce),
+                Payload {
+                    msg: &plaintext,
+                    aad: &bytes,
+                },
+            )
+            .map_err(|e| DeoxysError::crypto("keystore save", e))?;
+        bytes.extend_from_slice(&sealed);
+
+        let staging = self.path.with_extension("tmp");
+        write_file(&staging, &bytes, true, "keystore save")?;
+        fs::rename(&staging, &self.path).map_err(|e| {
+            DeoxysError::crypto("keystore save", format!("{}: {}", self.path.display(), e))
+        })
+    }
+}
+
+/// Read a passphrase from a secret file, dropping one trailing newline. On
+/// unix, files readable by group or others are refused.
+pub fn read_passphrase_file(path: impl AsRef<Path>) -> Result<Zeroizing<Vec<u8>>> {
+    let path = path.as_ref();
+    let fail = |reason: String| {
+        DeoxysError::crypto("keystore passphrase", format!("{}: {}", path.display(), reason))
+    };
+    check_private(path).map_err(fail)?;
+    let mut passphrase = Zeroizing::new(fs::read(path).map_err(|e| fail(e.to_string()))?);
+    if passphrase.ends_with(b"\n") {
+        passphrase.pop();
+        if passphrase.ends_with(b"\r") {
+            passphrase.pop();
+        }
+    }
+    if passphrase.is_empty() {
+        return Err(fail("passphrase is empty".into()));
+    }
+    Ok(passphrase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap parameters so tests do not spend 64 MiB per unlock
+    const TEST_KDF: KdfParams = KdfParams {
+        memory_kib: 256,
+        iterations: 1,
+        parallelism: 1,
+    };
+
+    fn temp_path(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "deoxys-keystore-{}-{}.dxks",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let path = temp_path("roundtrip");
+        let provenance = ProvenanceSigner::from_seed(&[5; 32]);
+        let mut store = Keystore::create(&path, b"correct horse", TEST_KDF).unwrap();
+        store.insert("provenance", &provenance, "receipts").unwrap();
+        let operator = store.generate("operator", "approvals").unwrap();
+        assert!(store.insert("operator", &provenance, "again").is_err());
+        assert!(Keystore::create(&path, b"other", TEST_KDF).is_err());
+
+        // Seeds never appear in the clear
+        let bytes = fs::read(&path).unwrap();
+        assert!(!bytes.windows(32).any(|w| w == [5; 32]));
+        assert!(!String::from_utf8_lossy(&bytes).contains(&hex::encode([5u8; 32])));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let store = Keystore::unlock(&path, b"correct horse").unwrap();
+        assert_eq!(store.kdf(), TEST_KDF);
+        let names: Vec<_> = store.keys().map(|k| k.name.as_str()).collect();
+        assert_eq!(names, ["provenance", "operator"]);
+        let metadata = store.keys().next().unwrap();
+        assert_eq!(metadata.label, "receipts");
+        assert_eq!(metadata.fingerprint, provenance.fingerprint());
+        assert_eq!(
+            store.signer("operator").unwrap().fingerprint(),
+            operator.fingerprint()
+        );
+        assert!(store.signer("missing").is_err());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_keystore_rejects_wrong_passphrase_and_tampering() {
+        let path = temp_path("tamper");
+        let mut store = Keystore::create(&path, b"secret", TEST_KDF).unwrap();
+        store.generate("provenance", "").unwrap();
+        let err = Keystore::unlock(&path, b"Secret").unwrap_err();
+        assert!(err.to_string().contains("wrong passphrase"));
+
+        // Weakening the KDF in the header breaks authentication
+        let original = fs::read(&path).unwrap();
+        let mut weakened = original.clone();
+        weakened[10] = 2;
+        write_file(&path, &weakened, true, "test").unwrap();
+        assert!(Keystore::unlock(&path, b"secret").is_err());
+        // Absurd costs are refused before the KDF runs
+        let mut inflated = original.clone();
+        inflated[6..10].copy_from_slice(&u32::MAX.to_le_bytes());
+        write_file(&path, &inflated, true, "test").unwrap();
+        let err = Keystore::unlock(&path, b"secret").unwrap_err();
+        assert!(err.to_string().contains("exceed"));
+        let mut flipped = original.clone();
+        *flipped.last_mut().unwrap() ^= 1;
+        write_file(&path, &flipped, true, "test").unwrap();
+        assert!(Keystore::unlock(&path, b"secret").is_err());
+
+        write_file(&path, &original, true, "test").unwrap();
+        Keystore::unlock(&path, b"secret").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+            let err = Keystore::unlock(&path, b"secret").unwrap_err();
+            assert!(err.to_string().contains("too open"));
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_change_passphrase_reencrypts() {
+        let path = temp_path("rekey");
+        let passphrase_path = path.with_extension("pass");
+        let mut store = Keystore::create(&path, b"old", TEST_KDF).unwrap();
+        let signer = store.generate("operator", "").unwrap();
+        let before = fs::read(&path).unwrap();
+        store.change_passphrase(b"new").unwrap();
+        let after = fs::read(&path).unwrap();
+        assert_ne!(before[18..34], after[18..34]);
+        assert!(Keystore::unlock(&path, b"old").is_err());
+
+        write_file(&passphrase_path, b"new\r\n", true, "test").unwrap();
+        let passphrase = read_passphrase_file(&passphrase_path).unwrap();
+        assert_eq!(passphrase.as_slice(), b"new");
+        let mut store = Keystore::unlock(&path, &passphrase).unwrap();
+        assert_eq!(
+            store.signer("operator").unwrap().fingerprint(),
+            signer.fingerprint()
+        );
+        store.remove("operator").unwrap();
+        assert_eq!(
+            Keystore::unlock(&path, b"new").unwrap().keys().count(),
+            0
+        );
+        fs::remove_file(&path).unwrap();
+        fs::remove_file(&passphrase_path).unwrap();
+    }
+}
@@ -0,0 +1,888 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Append-only, hash-chained provenance log.
+//!
+//! All integers are little-endian:
+//!
+//! ```text
+//! file    header frame*
+//! header  magic "DXPL" | version u16 (1)
+//! frame   length u32 | crc32 u32 over body | body
+//! body    sequence u64 | previous [32] | kind u8 | payload
+//! ```
+//!
+//! A record's hash is the SHA-256 of its body and `previous` holds the hash
+//! of the preceding record (zeros for the first), so inserting, removing,
+//! reordering or modifying a record breaks the chain. Every append is
+//! followed by `fsync`. A frame cut short by a crash can only be the last
+//! one; [`ProvenanceLog::recover`] truncates it, while damage anywhere else
+//! is reported and never repaired.
+
+use crate::approval::Decision;
+use crate::crypto::{check_links, ProvenanceError, ProvenanceVerifier, ReceiptRecord};
+use crate::error::{DeoxysError, Result};
+use crate::merkle::EpochCommitment;
+use crate::quorum::{Governance, GovernanceRecord, QuorumCertificate};
+use crate::rotation::{KeyChain, RevocationRecord, RotationRecord};
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const LOG_MAGIC: [u8; 4] = *b"DXPL";
+pub const LOG_VERSION: u16 = 1;
+/// Upper bound on a record body, so a corrupt length cannot force a large allocation
+pub const MAX_RECORD_LEN: usize = 1 << 20;
+const HEADER_LEN: usize = 6;
+const FRAME_HEADER_LEN: usize = 8;
+const BODY_HEADER_LEN: usize = 41;
+
+/// Type of a record's payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    /// JSON [`ReceiptRecord`]
+    Receipt,
+    /// JSON [`EpochCommitment`]
+    Epoch,
+    /// JSON [`RotationRecord`]
+    Rotation,
+    /// JSON [`RevocationRecord`]
+    Revocation,
+    /// JSON operator [`Decision`] denying a cycle or resetting an escalation
+    Decision,
+    /// JSON [`QuorumCertificate`]
+    Quorum,
+    /// JSON [`GovernanceRecord`]
+    Governance,
+}
+
+impl RecordKind {
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Receipt => 1,
+            Self::Epoch => 2,
+            Self::Rotation => 3,
+            Self::Revocation => 4,
+            Self::Decision => 5,
+            Self::Quorum => 6,
+            Self::Governance => 7,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            1 => Some(Self::Receipt),
+            2 => Some(Self::Epoch),
+            3 => Some(Self::Rotation),
+            4 => Some(Self::Revocation),
+            5 => Some(Self::Decision),
+            6 => Some(Self::Quorum),
+            7 => Some(Self::Governance),
+            _ => None,
+        }
+    }
+}
+
+/// Why a log was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum LogError {
+    BadMagic,
+    UnsupportedVersion {
+        version: u16,
+    },
+    /// Frame at `offset` runs past the end of the file
+    TornFrame {
+        offset: usize,
+    },
+    ChecksumMismatch {
+        offset: usize,
+    },
+    OversizedRecord {
+        offset: usize,
+        length: usize,
+    },
+    /// Frame body too short for the record header
+    ShortRecord {
+        offset: usize,
+        length: usize,
+    },
+    UnknownKind {
+        sequence: u64,
+        kind: u8,
+    },
+    /// Records are missing, duplicated or out of order
+    SequenceGap {
+        expected: u64,
+        found: u64,
+    },
+    /// `previous` does not hold the hash of the preceding record
+    BrokenLink {
+        sequence: u64,
+    },
+    MalformedPayload {
+        sequence: u64,
+    },
+}
+
+impl fmt::Display for LogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic => write!(f, "bad magic"),
+            Self::UnsupportedVersion { version } => write!(f, "unsupported version {}", version),
+            Self::TornFrame { offset } => write!(f, "torn frame at offset {}", offset),
+            Self::ChecksumMismatch { offset } => {
+                write!(f, "checksum mismatch in frame at offset {}", offset)
+            }
+            Self::OversizedRecord { offset, length } => {
+                write!(f, "frame at offset {} declares {} bytes", offset, length)
+            }
+            Self::ShortRecord { offset, length } => {
+                write!(f, "frame at offset {} holds only {} bytes", offset, length)
+            }
+            Self::UnknownKind { sequence, kind } => {
+                write!(f, "record {} has unknown kind {}", sequence, kind)
+            }
+            Self::SequenceGap { expected, found } => {
+                write!(f, "record {} where {} was expected", found, expected)
+            }
+            Self::BrokenLink { sequence } => {
+                write!(f, "record {} does not link to its predecessor", sequence)
+            }
+            Self::MalformedPayload { sequence } => {
+                write!(f, "record {} has a malformed payload", sequence)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogRecord {
+    pub sequence: u64,
+    pub previous: [u8; 32],
+    pub kind: RecordKind,
+    pub payload: Vec<u8>,
+}
+
+impl LogRecord {
+    fn body(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(BODY_HEADER_LEN + self.payload.len());
+        body.extend_from_slice(&self.sequence.to_le_bytes());
+        body.extend_from_slice(&self.previous);
+        body.push(self.kind.code());
+        body.extend_from_slice(&self.payload);
+        body
+    }
+
+    fn decode(body: &[u8], offset: usize) -> Result<Self, LogError> {
+        if body.len() < BODY_HEADER_LEN {
+            return Err(LogError::ShortRecord {
+                offset,
+                length: body.len(),
+            });
+        }
+        let sequence = u64::from_le_bytes(body[..8].try_into().unwrap());
+        let kind = RecordKind::from_code(body[40]).ok_or(LogError::UnknownKind {
+            sequence,
+            kind: body[40],
+        })?;
+        Ok(Self {
+            sequence,
+            previous: body[8..40].try_into().unwrap(),
+            kind,
+            payload: body[BODY_HEADER_LEN..].to_vec(),
+        })
+    }
+
+    /// Record body as framed in the log, e.g. for export
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.body()
+    }
+
+    /// Parse a body produced by [`Self::to_bytes`]
+    pub fn from_bytes(body: &[u8]) -> Result<Self, LogError> {
+        if body.len() > MAX_RECORD_LEN {
+            return Err(LogError::OversizedRecord {
+                offset: 0,
+                length: body.len(),
+            });
+        }
+        Self::decode(body, 0)
+    }
+
+    /// SHA-256 of the record body, linked from the next record
+    pub fn hash(&self) -> [u8; 32] {
+        Sha256::digest(self.body()).into()
+    }
+
+    fn decode_payload<T: DeserializeOwned>(&self, kind: RecordKind) -> Result<T, LogError> {
+        let malformed = LogError::MalformedPayload {
+            sequence: self.sequence,
+        };
+        if self.kind != kind {
+            return Err(malformed);
+        }
+        serde_json::from_slice(&self.payload).map_err(|_| malformed)
+    }
+
+    pub fn receipt(&self) -> Result<ReceiptRecord, LogError> {
+        self.decode_payload(RecordKind::Receipt)
+    }
+
+    pub fn epoch(&self) -> Result<EpochCommitment, LogError> {
+        self.decode_payload(RecordKind::Epoch)
+    }
+
+    pub fn rotation(&self) -> Result<RotationRecord, LogError> {
+        self.decode_payload(RecordKind::Rotation)
+    }
+
+    pub fn revocation(&self) -> Result<RevocationRecord, LogError> {
+        self.decode_payload(RecordKind::Revocation)
+    }
+
+    pub fn decision(&self) -> Result<Decision, LogError> {
+        self.decode_payload(RecordKind::Decision)
+    }
+
+    pub fn quorum(&self) -> Result<QuorumCertificate, LogError> {
+        self.decode_payload(RecordKind::Quorum)
+    }
+
+    pub fn governance(&self) -> Result<GovernanceRecord, LogError> {
+        self.decode_payload(RecordKind::Governance)
+    }
+}
+
+/// Records parsed from the front of a log, the length they span and the
+/// error that stopped parsing, if any
+struct Scan {
+    records: Vec<LogRecord>,
+    valid_len: usize,
+    error: Option<LogError>,
+}
+
+fn scan(bytes: &[u8]) -> Result<Scan, LogError> {
+    if bytes.len() < HEADER_LEN || bytes[..4] != LOG_MAGIC {
+        return Err(LogError::BadMagic);
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != LOG_VERSION {
+        return Err(LogError::UnsupportedVersion { version });
+    }
+    let mut records = Vec::new();
+    let mut offset = HEADER_LEN;
+    while offset < bytes.len() {
+        let result = frame(bytes, offset)
+            .and_then(|(body, end)| LogRecord::decode(body, offset).map(|record| (record, end)));
+        match result {
+            Ok((record, end)) => {
+                records.push(record);
+                offset = end;
+            }
+            Err(error) => {
+                return Ok(Scan {
+                    records,
+                    valid_len: offset,
+                    error: Some(error),
+                })
+            }
+        }
+    }
+    Ok(Scan {
+        records,
+        valid_len: offset,
+        error: None,
+    })
+}
+
+/// Body of the frame at `offset` and the offset just past it
+fn frame(bytes: &[u8], offset: usize) -> Result<(&[u8], usize), LogError> {
+    let header = bytes
+        .get(offset..offset + FRAME_HEADER_LEN)
+        .ok_or(LogError::TornFrame { offset })?;
+    let length = u32::from_le_bytes(header[..4].try_into().unwrap()) as usize;
+    let checksum = u32::from_le_bytes(header[4..].try_into().unwrap());
+    if length > MAX_RECORD_LEN {
+        return Err(LogError::OversizedRecord { offset, length });
+    }
+    let end = offset + FRAME_HEADER_LEN + length;
+    let body = bytes
+        .get(offset + FRAME_HEADER_LEN..end)
+        .ok_or(LogError::TornFrame { offset })?;
+    if crc32fast::hash(body) != checksum {
+        return Err(LogError::ChecksumMismatch { offset });
+    }
+    Ok((body, end))
+}
+
+/// Check sequence numbers and links; returns the hash of the last record
+pub fn verify_records(records: &[LogRecord]) -> Result<[u8; 32], LogError> {
+    verify_segment(records, 0, [0; 32])
+}
+
+/// Check a run of records that starts at sequence `start` and links to the
+/// record hashed `previous`; returns the hash of the last record
+pub fn verify_segment(
+    records: &[LogRecord],
+    start: u64,
+    previous: [u8; 32],
+) -> Result<[u8; 32], LogError> {
+    let mut head = previous;
+    for (expected, record) in (start..).zip(records) {
+        if record.sequence != expected {
+            return Err(LogError::SequenceGap {
+                expected,
+                found: record.sequence,
+            });
+        }
+        if record.previous != head {
+            return Err(LogError::BrokenLink {
+                sequence: record.sequence,
+            });
+        }
+        head = record.hash();
+    }
+    Ok(head)
+}
+
+/// Follow the key rotations in `records` from `root` and check that every
+/// receipt, epoch commitment and governance record is signed by the key
+/// active at its position, that no receipt falls under a revocation, that
+/// operator decisions come from the operators then authorized, that quorum
+/// certificates satisfy the policy then in force, and that receipts link
+/// into chains. Returns the resulting key chain.
+pub fn verify_signatures(records: &[LogRecord], root: &ProvenanceVerifier) -> Result<KeyChain> {
+    verify_signatures_from(records, KeyChain::new(root.clone()), Governance::default())
+}
+
+/// [`verify_signatures`] for records that follow on from the rotations,
+/// revocations and governance changes already applied to `keys` and
+/// `governance`
+pub fn verify_signatures_from(
+    records: &[LogRecord],
+    mut keys: KeyChain,
+    mut governance: Governance,
+) -> Result<KeyChain> {
+    let mut receipts = Vec::new();
+    for record in records {
+        match record.kind {
+            RecordKind::Receipt => {
+                let receipt = record.receipt()?;
+                keys.verify_receipt(&receipt)?;
+                receipts.push(receipt);
+            }
+            RecordKind::Epoch => {
+                let epoch = record.epoch()?;
+                epoch.verify(keys.active())?;
+                if epoch.previous != Some(hex::encode(record.previous)) {
+                    return Err(ProvenanceError::MisplacedEpoch { epoch: epoch.epoch }.into());
+                }
+            }
+            RecordKind::Rotation => keys.rotate(&record.rotation()?)?,
+            RecordKind::Revocation => keys.revoke(&record.revocation()?)?,
+            RecordKind::Decision => governance.check_decision(&record.decision()?)?,
+            RecordKind::Quorum => governance.certify(&record.quorum()?, &keys.keys()[0])?,
+            RecordKind::Governance => governance.apply(&record.governance()?, keys.active())?,
+        }
+    }
+    // Each engine start begins a new run of receipts without a link
+    for run in receipts.chunk_by(|_, next| next.previous.is_some()) {
+        check_links(run, |receipt| keys.check_revocation(receipt))?;
+    }
+    Ok(keys)
+}
+
+/// Open log file positioned for appending
+pub struct ProvenanceLog {
+    path: PathBuf,
+    file: File,
+    head: [u8; 32],
+    len: u64,
+    rotations: u64,
+    /// Outgoing key of the first rotation, i.e. the key the log started with
+    root: Option<String>,
+    certificates: u64,
+}
+
+impl ProvenanceLog {
+    /// Open the log at `path`, creating it on first use. A torn tail left by
+    /// a crash is truncated; any other damage refuses the log.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            Self::create(path)?;
+        } else {
+            Self::recover(path)?;
+        }
+        let records = Self::read(path)?;
+        let head = verify_records(&records)?;
+        let file = OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map_err(|e| io_error("provenance log open", path, e))?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            head,
+            len: records.len() as u64,
+            rotations: records
+                .iter()
+                .filter(|r| r.kind == RecordKind::Rotation)
+                .count() as u64,
+            root: records.iter().find_map(|r| r.rotation().ok()).map(|r| r.from),
+            certificates: records
+                .iter()
+                .filter(|r| r.kind == RecordKind::Quorum)
+                .count() as u64,
+        })
+    }
+
+    /// Write the header to a temporary file and rename it into place, so a
+    /// crash never leaves a log without a complete header
+    fn create(path: &Path) -> Result<()> {
+        let fail = |e| io_error("provenance log create", path, e);
+        let parent = path.parent().filter(|p| !p.as_os_str().is_empty());
+        if let Some(parent) = parent {
+            fs::create_dir_all(parent).map_err(fail)?;
+        }
+        let staging = path.with_extension("tmp");
+        let mut header = LOG_MAGIC.to_vec();
+        header.extend_from_slice(&LOG_VERSION.to_le_bytes());
+        File::create(&staging)
+            .and_then(|mut file| {
+                file.write_all(&header)?;
+                file.sync_all()
+            })
+            .and_then(|_| fs::rename(&staging, path))
+            .map_err(fail)?;
+        // Persist the directory entry as well as the contents
+        #[cfg(unix)]
+        File::open(parent.unwrap_or(Path::new(".")))
+            .and_then(|dir| dir.sync_all())
+            .map_err(fail)?;
+        Ok(())
+    }
+
+    /// Truncate a frame torn by an interrupted append; returns the number of
+    /// bytes removed. Damage followed by further data is not a torn write and
+    /// is reported instead.
+    pub fn recover(path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| io_error("provenance log recover", path, e))?;
+        let scan = scan(&bytes)?;
+        let Some(error) = scan.error else {
+            return Ok(0);
+        };
+        let torn = match error {
+            LogError::TornFrame { offset } => !resumes_after(&bytes, offset, &scan),
+            // A frame reaching the end of the file was its last write
+            LogError::ChecksumMismatch { offset } => {
+                frame_end(&bytes, offset) >= bytes.len() && !resumes_after(&bytes, offset, &scan)
+            }
+            _ => false,
+        };
+        if !torn {
+            return Err(error.into());
+        }
+        let removed = bytes.len() - scan.valid_len;
+        OpenOptions::new()
+            .write(true)
+            .open(path)
+            .and_then(|file| {
+                file.set_len(scan.valid_len as u64)?;
+                file.sync_all()
+            })
+            .map_err(|e| io_error("provenance log recover", path, e))?;
+        warn!(
+            "   -> Provenance log {}: truncated {} bytes of torn tail ({})",
+            path.display(),
+            removed,
+            error
+        );
+        Ok(removed)
+    }
+
+    /// Read every record; a torn or damaged frame is an error
+    pub fn read(path: impl AsRef<Path>) -> Result<Vec<LogRecord>> {
+        let path = path.as_ref();
+        let bytes = fs::read(path).map_err(|e| io_error("provenance log read", path, e))?;
+        let scan = scan(&bytes)?;
+        match scan.error {
+            Some(error) => Err(error.into()),
+            None => Ok(scan.records),
+        }
+    }
+
+    /// Verify framing and links of the whole log and, given the root
+    /// provenance key, its signed contents (see [`verify_signatures`]).
+    /// Returns the number of records.
+    pub fn verify(path: impl AsRef<Path>, root: Option<&ProvenanceVerifier>) -> Result<u64> {
+        let records = Self::read(path)?;
+        verify_records(&records)?;
+        if let Some(root) = root {
+            verify_signatures(&records, root)?;
+        }
+        Ok(records.len() as u64)
+    }
+
+    /// Append a record and `fsync` it; returns its hash. A failed write is
+    /// truncated back to the previous end of the log.
+    pub fn append(&mut self, kind: RecordKind, payload: &[u8]) -> Result<[u8; 32]> {
+        let record = LogRecord {
+            sequence: self.len,
+            previous: self.head,
+            kind,
+            payload: payload.to_vec(),
+        };
+        let body = record.body();
+        if body.len() > MAX_RECORD_LEN {
+            return Err(DeoxysError::config(
+                "provenance record",
+                format!("{} bytes exceeds {}", body.len(), MAX_RECORD_LEN),
+            ));
+        }
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + body.len());
+        frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        frame.extend_from_slice(&crc32fast::hash(&body).to_le_bytes());
+        frame.extend_from_slice(&body);
+        let fail = |e| io_error("provenance log append", &self.path, e);
+        let committed = self.file.metadata().map_err(fail)?.len();
+        if let Err(e) = self
+            .file
+            .write_all(&frame)
+            .and_then(|_| self.file.sync_data())
+        {
+            // Drop any partial frame so the next append links to the head
+            // this log still reports
+            if let Err(undo) = self.file.set_len(committed) {
+                warn!(
+                    "   -> Provenance log {}: could not truncate failed append ({})",
+                    self.path.display(),
+                    undo
+                );
+            }
+            return Err(fail(e));
+        }
+        self.head = Sha256::digest(&body).into();
+        self.len += 1;
+        Ok(self.head)
+    }
+
+    fn append_json(&mut self, kind: RecordKind, value: &impl Serialize) -> Result<[u8; 32]> {
+        let payload = serde_json::to_vec(value)
+            .map_err(|e| DeoxysError::crypto("provenance log append", e))?;
+        self.append(kind, &payload)
+    }
+
+    pub fn append_receipt(&mut self, receipt: &ReceiptRecord) -> Result<[u8; 32]> {
+        self.append_json(RecordKind::Receipt, receipt)
+    }
+
+    pub fn append_epoch(&mut self, epoch: &EpochCommitment) -> Result<[u8; 32]> {
+        self.append_json(RecordKind::Epoch, epoch)
+    }
+
+    pub fn append_rotation(&mut self, rotation: &RotationRecord) -> Result<[u8; 32]> {
+        let hash = self.append_json(RecordKind::Rotation, rotation)?;
+        self.rotations += 1;
+        self.root.get_or_insert_with(|| rotation.from.clone());
+        Ok(hash)
+    }
+
+    pub fn append_revocation(&mut self, revocation: &RevocationRecord) -> Result<[u8; 32]> {
+        self.append_json(RecordKind::Revocation, revocation)
+    }
+
+    pub fn append_decision(&mut self, decision: &Decision) -> Result<[u8; 32]> {
+        self.append_json(RecordKind::Decision, decision)
+    }
+
+    pub fn append_governance(&mut self, governance: &GovernanceRecord) -> Result<[u8; 32]> {
+        self.append_json(RecordKind::Governance, governance)
+    }
+
+    pub fn append_quorum(&mut self, certificate: &QuorumCertificate) -> Result<[u8; 32]> {
+        let hash = self.append_json(RecordKind::Quorum, certificate)?;
+        self.certificates += 1;
+        Ok(hash)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Hash of the last record; zeros while empty
+    pub fn head(&self) -> [u8; 32] {
+        self.head
+    }
+
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Number of key rotations recorded
+    pub fn rotations(&self) -> u64 {
+        self.rotations
+    }
+
+    /// Number of quorum certificates recorded
+    pub fn certificates(&self) -> u64 {
+        self.certificates
+    }
+
+    /// Fingerprint of the provenance key the log started with, known once a
+    /// rotation has handed signing over from it
+    pub fn root(&self) -> Option<&str> {
+        self.root.as_deref()
+    }
+}
+
+/// Whether an intact frame carrying a later record starts after the damaged
+/// frame at `offset`, i.e. the damage is not a torn last write: a corrupt
+/// length can make a middle frame appear to run past the end of the file
+fn resumes_after(bytes: &[u8], offset: usize, scan: &Scan) -> bool {
+    let next = scan.records.len() as u64;
+    (offset + 1..bytes.len()).any(|start| {
+        let sequence = bytes
+            .get(start + FRAME_HEADER_LEN..start + FRAME_HEADER_LEN + 8)
+            .map(|field| u64::from_le_bytes(field.try_into().unwrap()));
+        // Cheap filter before the checksum: only records past the damaged one
+        sequence.is_some_and(|sequence| sequence > next && sequence - next < bytes.len() as u64)
+            && frame(bytes, start)
+                .and_then(|(body, _)| LogRecord::decode(body, start))
+                .is_ok()
+    })
+}
+
+fn frame_end(bytes: &[u8], offset: usize) -> usize {
+    let length = u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+    offset + FRAME_HEADER_LEN + length as usize
+}
+
+fn io_error(operation: &'static str, path: &Path, error: std::io::Error) -> DeoxysError {
+    DeoxysError::crypto(operation, format!("{}: {}", path.display(), error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::{state_digest, ProvenanceSigner};
+    use crate::invariants::EntropyPolicy;
+    use crate::rik::CycleMode;
+    use ndarray::Array1;
+
+    fn temp_log(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("deoxys-ledger-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("provenance.log")
+    }
+
+    fn receipts(signer: &ProvenanceSigner, cycles: u64) -> Vec<ReceiptRecord> {
+        let mut chain: Vec<ReceiptRecord> = Vec::new();
+        for cycle in 1..=cycles {
+            let state = Array1::from_elem(4, cycle as f64 * 0.01);
+            let mut receipt = ReceiptRecord {
+                cycle,
+                hash: String::new(),
+                signer: String::new(),
+                state_digest: hex::encode(state_digest(&state)),
+                previous: chain.last().map(|r| r.hash.clone()),
+                mode: CycleMode::Nominal,
+                ckks_parameters: "ckks-n8192-d2-128".into(),
+                entropy_policy: EntropyPolicy::default(),
+                approval: None,
+            };
+            signer.sign_receipt(&mut receipt).unwrap();
+            chain.push(receipt);
+        }
+        chain
+    }
+
+    fn write_records(path: &Path, records: &[LogRecord]) {
+        let mut bytes = LOG_MAGIC.to_vec();
+        bytes.extend_from_slice(&LOG_VERSION.to_le_bytes());
+        for record in records {
+            let body = record.body();
+            bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&crc32fast::hash(&body).to_le_bytes());
+            bytes.extend_from_slice(&body);
+        }
+        fs::write(path, bytes).unwrap();
+    }
+
+    #[test]
+    fn test_append_reopen_and_verify() {
+        let path = temp_log("reopen");
+        let signer = ProvenanceSigner::from_seed(&[5; 32]);
+        let verifier = ProvenanceVerifier::from(&signer);
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        assert!(log.is_empty());
+        for receipt in receipts(&signer, 3) {
+            log.append_receipt(&receipt).unwrap();
+        }
+        let head = log.head();
+        drop(log);
+
+        // A restarted engine begins a new run of receipts in the same log
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        assert_eq!((log.len(), log.head()), (3, head));
+        for receipt in receipts(&signer, 2) {
+            log.append_receipt(&receipt).unwrap();
+        }
+        assert_eq!(ProvenanceLog::verify(&path, Some(&verifier)).unwrap(), 5);
+        let records = ProvenanceLog::read(&path).unwrap();
+        assert_eq!(records[4].receipt().unwrap().cycle, 2);
+        assert_eq!(verify_records(&records), Ok(log.head()));
+
+        let other = ProvenanceVerifier::from(&ProvenanceSigner::from_seed(&[6; 32]));
+        let error = ProvenanceLog::verify(&path, Some(&other)).unwrap_err();
+        assert_eq!(error.code(), "provenance_failure");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_recover_truncates_torn_tail() {
+        let path = temp_log("torn");
+        let signer = ProvenanceSigner::from_seed(&[5; 32]);
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        for receipt in receipts(&signer, 3) {
+            log.append_receipt(&receipt).unwrap();
+        }
+        drop(log);
+        let intact = fs::read(&path).unwrap();
+        let records = ProvenanceLog::read(&path).unwrap();
+        let tail_offset = intact.len() - FRAME_HEADER_LEN - records[2].body().len();
+
+        // Body cut short, then a frame header cut short
+        for cut in [intact.len() - 10, tail_offset + 3] {
+            fs::write(&path, &intact[..cut]).unwrap();
+            let error = ProvenanceLog::read(&path).unwrap_err();
+            assert_eq!(
+                error,
+                LogError::TornFrame {
+                    offset: tail_offset
+                }
+                .into()
+            );
+            assert_eq!(ProvenanceLog::recover(&path).unwrap(), cut - tail_offset);
+            assert_eq!(ProvenanceLog::read(&path).unwrap(), records[..2]);
+        }
+
+        // Full-length last frame whose contents never reached the disk
+        let mut garbled = intact.clone();
+        let end = garbled.len();
+        garbled[end - 20..].fill(0);
+        fs::write(&path, &garbled).unwrap();
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        assert_eq!(log.len(), 2);
+        log.append_receipt(&receipts(&signer, 3)[2]).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), intact);
+        drop(log);
+
+        // A corrupt length in the first frame runs past the end of the file,
+        // but the records after it show it is not a torn tail
+        let mut corrupted = intact.clone();
+        let length = (intact.len() as u32).to_le_bytes();
+        corrupted[HEADER_LEN..HEADER_LEN + 4].copy_from_slice(&length);
+        fs::write(&path, &corrupted).unwrap();
+        let error = ProvenanceLog::open(&path).err().unwrap();
+        assert_eq!(error, LogError::TornFrame { offset: HEADER_LEN }.into());
+        assert_eq!(fs::read(&path).unwrap(), corrupted);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_tampering_is_detected() {
+        let path = temp_log("tamper");
+        let signer = ProvenanceSigner::from_seed(&[5; 32]);
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        for receipt in receipts(&signer, 4) {
+            log.append_receipt(&receipt).unwrap();
+        }
+        drop(log);
+        let records = ProvenanceLog::read(&path).unwrap();
+
+        // Flipped byte inside an earlier frame is corruption, not a torn write
+        let mut bytes = fs::read(&path).unwrap();
+        bytes[HEADER_LEN + FRAME_HEADER_LEN + 50] ^= 1;
+        fs::write(&path, &bytes).unwrap();
+        let error = ProvenanceLog::recover(&path).unwrap_err();
+        assert_eq!(
+            error,
+            LogError::ChecksumMismatch { offset: HEADER_LEN }.into()
+        );
+        assert!(ProvenanceLog::open(&path).is_err());
+
+        let mut removed = records.clone();
+        removed.remove(1);
+        assert_eq!(
+            verify_records(&removed),
+            Err(LogError::SequenceGap {
+                expected: 1,
+                found: 2
+            })
+        );
+
+        let mut reordered = records.clone();
+        reordered.swap(1, 2);
+        assert_eq!(
+            verify_records(&reordered),
+            Err(LogError::SequenceGap {
+                expected: 1,
+                found: 2
+            })
+        );
+
+        // Renumbered to fill the gap, the links still break
+        let mut inserted = records.clone();
+        inserted.insert(
+            2,
+            LogRecord {
+                payload: b"{}".to_vec(),
+                ..records[2].clone()
+            },
+        );
+        for (sequence, record) in (0u64..).zip(inserted.iter_mut()) {
+            record.sequence = sequence;
+        }
+        assert_eq!(
+            verify_records(&inserted),
+            Err(LogError::BrokenLink { sequence: 3 })
+        );
+
+        let mut modified = records.clone();
+        modified[1].payload[10] ^= 1;
+        assert_eq!(
+            verify_records(&modified),
+            Err(LogError::BrokenLink { sequence: 2 })
+        );
+
+        // Rewriting the whole chain around a forged receipt still fails its signature
+        let mut forged = receipts(&signer, 4);
+        forged[3].state_digest = forged[0].state_digest.clone();
+        let mut rewritten: Vec<LogRecord> = records[..3].to_vec();
+        rewritten.push(LogRecord {
+            payload: serde_json::to_vec(&forged[3]).unwrap(),
+            ..records[3].clone()
+        });
+        write_records(&path, &rewritten);
+        assert!(ProvenanceLog::verify(&path, None).is_ok());
+        let error =
+            ProvenanceLog::verify(&path, Some(&ProvenanceVerifier::from(&signer))).unwrap_err();
+        assert_eq!(error.code(), "provenance_failure");
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Deoxys Core library: RIK engine, invariants, and cryptographic provenance.
+//! The `deoxys-core` binary and the FFI layer are thin shells over these modules.
+
+pub mod error;
+pub mod ffi;
+pub mod rik;
+pub mod invariants;
+pub mod policy;
+pub mod entropy;
+pub mod registry;
+pub mod stl;
+pub mod control;
+pub mod neural;
+pub mod roa;
+pub mod stability;
+pub mod approval;
+pub mod audit;
+pub mod canonical;
+pub mod ckks;
+pub mod crypto;
+pub mod keystore;
+pub mod ledger;
+pub mod merkle;
+pub mod quorum;
+pub mod rotation;
+pub mod substrate;
+pub mod wire;
@@ -2,23 +2,51 @@
 // Author: Alexis Adams <sovereign@axiomhive.net>
 // SPDX-License-Identifier: Proprietary
 
-mod rik;
-mod invariants;
-mod crypto;
-mod substrate;
-
-use crate::rik::{RikEngine, OperatorBounds};
-use crate::substrate::SovereignState;
+use deoxys_core::approval::{Decision, Ruling};
+use deoxys_core::crypto::{ProvenanceSigner, ProvenanceVerifier};
+use deoxys_core::error::DeoxysError;
+use deoxys_core::keystore::{read_passphrase_file, Keystore};
+use deoxys_core::ledger::ProvenanceLog;
+use deoxys_core::rik::{CycleMode, RikEngine, OperatorBounds};
+use deoxys_core::substrate::SovereignState;
 use log::{info, error, warn};
 use std::time::{Duration, Instant};
 use std::io::{self, Write};
+use std::path::Path;
 use tokio::time;
+use zeroize::Zeroizing;
+
+/// Encrypted keystore used when `DEOXYS_KEYSTORE` is unset
+const DEFAULT_KEYSTORE: &str = "keys/keystore.dxks";
+/// Plaintext provenance key imported on first run; `DEOXYS_SIGNING_KEY` overrides
+const DEFAULT_SIGNING_KEY: &str = "keys/provenance.pem";
+/// Receipt log used when `DEOXYS_PROVENANCE_LOG` is unset
+const DEFAULT_PROVENANCE_LOG: &str = "logs/provenance.log";
+/// Receipts per signed Merkle epoch
+const EPOCH_LENGTH: u64 = 64;
+/// Operator public key exported by `deoxys-approve keygen`; `DEOXYS_OPERATOR_KEY`
+/// overrides. The private half never reaches the engine.
+const DEFAULT_OPERATOR_KEY: &str = "keys/operator.pem.pub";
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // 0. Environment Setup
     std::env::set_var("RUST_LOG", "info");
     env_logger::init();
+
+    let keystore_path = std::env::var("DEOXYS_KEYSTORE")
+        .unwrap_or_else(|_| DEFAULT_KEYSTORE.to_string());
+    if std::env::args().nth(1).as_deref() == Some("change-passphrase") {
+        let current = prompt_passphrase("Current keystore passphrase: ")?;
+        let mut keystore = Keystore::unlock(&keystore_path, current.as_bytes())?;
+        let new = prompt_passphrase("New keystore passphrase: ")?;
+        if new.is_empty() || *new != *prompt_passphrase("Repeat new passphrase: ")? {
+            anyhow::bail!("new passphrases are empty or do not match");
+        }
+        keystore.change_passphrase(new.as_bytes())?;
+        info!(">> Keystore {} re-encrypted under the new passphrase", keystore_path);
+        return Ok(());
+    }
     
     info!(">> DEOXYS HEGEMONY FORGE v2.0 - INITIALIZING");
     info!(">> ROOT AUTHORITY: Alexis Adams (C == XNXAlexis)");
@@ -64,7 +92,7 @@ async fn main() -> anyhow::Result<()> {
     
     match OperatorBounds::new(min_bound, max_bound) {
         Ok(bounds) => {
-            engine.set_operator_bounds(bounds);
+            engine.set_operator_bounds(bounds)?;
             info!(">> Operator bounds set: [{}, {}]", bounds.min, bounds.max);
             info!(">> All subsequent outputs will be strictly bounded by this intent");
         }
@@ -74,21 +102,85 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
+    let entropy = engine.config().entropy;
+    info!(
+        ">> Zero Entropy Law: mode={:?}, variance threshold={}, warm-up={:?}",
+        entropy.mode, entropy.threshold, entropy.warm_up
+    );
+    info!(">> CKKS parameter set: {}", engine.ckks().context().params().id());
+
+    // Keystore: signing keys are only ever stored encrypted under a passphrase
+    let passphrase = match std::env::var("DEOXYS_KEYSTORE_PASSPHRASE_FILE") {
+        Ok(path) => read_passphrase_file(path)?,
+        Err(_) => Zeroizing::new(prompt_passphrase("Keystore passphrase: ")?.as_bytes().to_vec()),
+    };
+    let mut keystore = Keystore::open_or_create(&keystore_path, &passphrase)?;
+    drop(passphrase);
+    info!(">> Keystore unlocked: {}", keystore_path);
+
+    // Persistent provenance key: receipts stay verifiable across restarts
+    let key_path = std::env::var("DEOXYS_SIGNING_KEY")
+        .unwrap_or_else(|_| DEFAULT_SIGNING_KEY.to_string());
+    let signer = keystore_signer(&mut keystore, "provenance", &key_path)?;
+    signer.export_public_key(format!("{}.pub", key_path))?;
+    info!(">> Provenance key: {} (fingerprint {})", key_path, signer.fingerprint());
+    // Audit bundles trace every signing key back to this one
+    let root_key = ProvenanceVerifier::from(&signer);
+    engine.set_signer(signer)?;
+
+    let log_path = std::env::var("DEOXYS_PROVENANCE_LOG")
+        .unwrap_or_else(|_| DEFAULT_PROVENANCE_LOG.to_string());
+    engine.set_provenance_log(ProvenanceLog::open(&log_path)?)?;
+    engine.enable_epochs(EPOCH_LENGTH)?;
+
+    drop(keystore);
+
+    // Operator public key: approvals and denials are signed apart from the
+    // engine with deoxys-approve, and only verified here
+    let operator_path = std::env::var("DEOXYS_OPERATOR_KEY")
+        .unwrap_or_else(|_| DEFAULT_OPERATOR_KEY.to_string());
+    let operator = ProvenanceVerifier::load(&operator_path)?;
+    info!(">> Operator key: {} (fingerprint {})", operator_path, operator.fingerprint());
+    engine.authorize_operator(operator)?;
+
     // 3. The Human-Supervised Loop
-    let mut cycle_count = 0u64;
+    let mut failure = None;
     loop {
-        cycle_count += 1;
-        
-        // HUMAN APPROVAL GATE: Require explicit human approval before execution
+        let proposal = engine.proposal();
+        let cycle_count = proposal.cycle;
+
+        // HUMAN APPROVAL GATE: Require a signed human approval before execution
         info!("\n=== CYCLE {} APPROVAL REQUEST ===", cycle_count);
-        print!("Approve cycle execution? (y/n): ");
+        info!(">> Proposed action: {}", hex::encode(proposal.action));
+        info!(
+            ">> Sign with: deoxys-approve <operator.pem> {} {} (--approve | --deny) --out <decision.json>",
+            cycle_count,
+            hex::encode(proposal.action)
+        );
+        print!("Signed decision file (or 'exit'): ");
         io::stdout().flush().unwrap();
-        
-        let mut approval = String::new();
-        io::stdin().read_line(&mut approval).unwrap();
-        let approval = approval.trim().to_lowercase();
-        
-        if approval != "y" && approval != "yes" {
+
+        let mut decision_path = String::new();
+        io::stdin().read_line(&mut decision_path).unwrap();
+        let decision_path = decision_path.trim();
+        if decision_path == "exit" {
+            info!(">> SYSTEM SHUTDOWN: Terminated by human operator");
+            break;
+        }
+        let decision = match load_decision(decision_path) {
+            Ok(decision) => decision,
+            Err(e) => {
+                error!("!! Unreadable decision {}: {}", decision_path, e);
+                continue;
+            }
+        };
+        let ruling = decision.ruling;
+        if let Err(e) = engine.submit_decision(decision) {
+            error!("!! DECISION REJECTED [{}]: {}", e.code(), e);
+            continue;
+        }
+
+        if ruling == Ruling::Deny {
             warn!("!! CYCLE {} DENIED: Human operator rejected execution", cycle_count);
             info!(">> Enter 'exit' to terminate system, or any other key to continue to next approval cycle:");
             
@@ -106,13 +198,25 @@ async fn main() -> anyhow::Result<()> {
 
         match engine.execute_cycle().await {
             Ok(receipt) => {
-                info!("<< CYCLE COMPLETE: Hash={} | Latency={:?}", receipt.hash, cycle_start.elapsed());
+                info!(
+                    "<< CYCLE {} COMPLETE: Hash={} | Entropy enforced={} | Latency={:?}",
+                    receipt.cycle, receipt.hash, receipt.entropy_enforced, cycle_start.elapsed()
+                );
+                if receipt.mode != CycleMode::Nominal {
+                    warn!(">> Cycle {} ran in {:?} mode", receipt.cycle, receipt.mode);
+                }
+            }
+            Err(e @ DeoxysError::Lockdown { .. }) => {
+                error!("!! LOCKDOWN [{}]: {}", e.code(), e);
+                error!("!! FAILURE RECORD: {}", serde_json::to_string(&e).unwrap_or_default());
+                break;
             }
             Err(e) => {
-                error!("!! CYCLE FAILURE: Invariant breach detected: {}", e);
-                // In production, this triggers the "Irreversible Covenant" lockdown
-                // For now, we panic to enforce the Zero Entropy Law (Fail-Stop)
-                panic!("Zero Entropy Law Violation: System Halted.");
+                error!("!! CYCLE FAILURE [{}]: {}", e.code(), e);
+                error!("!! FAILURE RECORD: {}", serde_json::to_string(&e).unwrap_or_default());
+                // Fail-stop under the Zero Entropy Law, once the run is exported
+                failure = Some(e);
+                break;
             }
         }
 
@@ -124,6 +228,51 @@ async fn main() -> anyhow::Result<()> {
             info!("!! CYCLE OVERRUN: {:?} > 50ms", elapsed);
         }
     }
-    
+
+    // Signed audit bundle of this run, for verification with deoxys-audit
+    if let Ok(bundle_path) = std::env::var("DEOXYS_AUDIT_BUNDLE") {
+        match engine.last_receipt().map(|receipt| receipt.cycle) {
+            Some(last) => {
+                engine.export_audit(1, last, &root_key)?.save(&bundle_path)?;
+                info!(">> Audit bundle of cycles 1..={} written to {}", last, bundle_path);
+            }
+            None => warn!(">> No cycle executed; no audit bundle written"),
+        }
+    }
+
+    if let Some(e) = failure {
+        anyhow::bail!("Zero Entropy Law violation, system halted: {}", e);
+    }
+
     Ok(())
 }
+
+/// Operator decision written by deoxys-approve
+fn load_decision(path: &str) -> anyhow::Result<Decision> {
+    Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+}
+
+/// Read a passphrase from the terminal without echo
+fn prompt_passphrase(prompt: &str) -> io::Result<Zeroizing<String>> {
+    rpassword::prompt_password(prompt).map(Zeroizing::new)
+}
+
+/// Key `name` from the keystore. On first run a plaintext key left at `legacy`
+/// by earlier versions is imported, otherwise a new key is generated.
+fn keystore_signer(
+    keystore: &mut Keystore,
+    name: &str,
+    legacy: &str,
+) -> deoxys_core::error::Result<ProvenanceSigner> {
+    if keystore.contains(name) {
+        return keystore.signer(name);
+    }
+    let signer = if Path::new(legacy).exists() {
+        warn!(">> Importing plaintext key {} into the keystore; delete it once backed up", legacy);
+        ProvenanceSigner::load(legacy)?
+    } else {
+        ProvenanceSigner::new()
+    };
+    keystore.insert(name, &signer, legacy)?;
+    Ok(signer)
+}
@@ -0,0 +1,744 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Merkle tree commitments over cycle receipts (RFC 6962 / RFC 9162).
+//!
+//! Receipts are appended to a single tree. Every `epoch_length` receipts the
+//! tree head (size and root) is signed as an [`EpochCommitment`], so one
+//! signature covers a whole epoch. Any receipt can then be proven against an
+//! epoch with an [`InclusionProof`] of O(log n) hashes, and a
+//! [`ConsistencyProof`] shows that a later epoch's tree extends an earlier
+//! one, as in certificate transparency logs.
+//!
+//! Leaves hash as SHA-256(0x00 || data) and interior nodes as
+//! SHA-256(0x01 || left || right); the empty tree's root is SHA-256("").
+
+use crate::canonical::{Domain, Encoder};
+use crate::crypto::{ProvenanceError, ProvenanceSigner, ProvenanceVerifier, ReceiptRecord};
+use crate::error::{DeoxysError, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub type Hash = [u8; 32];
+
+pub fn leaf_hash(data: &[u8]) -> Hash {
+    Sha256::new()
+        .chain_update([0])
+        .chain_update(data)
+        .finalize()
+        .into()
+}
+
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    Sha256::new()
+        .chain_update([1])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Leaf committing to the canonical encoding of a receipt record
+pub fn receipt_leaf(receipt: &ReceiptRecord) -> Hash {
+    let encoder = Encoder::new(Domain::ReceiptRecord)
+        .u64(receipt.cycle)
+        .str(&receipt.hash)
+        .str(&receipt.signer)
+        .str(&receipt.state_digest)
+        .option(receipt.previous.as_deref(), Encoder::str)
+        .u8(receipt.mode.code())
+        .str(&receipt.ckks_parameters);
+    let record = receipt
+        .entropy_policy
+        .encode(encoder)
+        .option(receipt.approval.as_ref(), |encoder, decision| decision.encode(encoder))
+        .finish();
+    leaf_hash(&record)
+}
+
+/// Largest power of two strictly below `n` (n >= 2)
+fn split(n: usize) -> usize {
+    1 << (usize::BITS - 1 - (n - 1).leading_zeros())
+}
+
+fn root_of(leaves: &[Hash]) -> Hash {
+    match leaves.len() {
+        0 => Sha256::digest([]).into(),
+        1 => leaves[0],
+        n => {
+            let k = split(n);
+            node_hash(&root_of(&leaves[..k]), &root_of(&leaves[k..]))
+        }
+    }
+}
+
+fn inclusion_path(index: usize, leaves: &[Hash], path: &mut Vec<Hash>) {
+    let n = leaves.len();
+    if n <= 1 {
+        return;
+    }
+    let k = split(n);
+    if index < k {
+        inclusion_path(index, &leaves[..k], path);
+        path.push(root_of(&leaves[k..]));
+    } else {
+        inclusion_path(index - k, &leaves[k..], path);
+        path.push(root_of(&leaves[..k]));
+    }
+}
+
+fn consistency_path(m: usize, leaves: &[Hash], complete: bool, path: &mut Vec<Hash>) {
+    let n = leaves.len();
+    if m == n {
+        if !complete {
+            path.push(root_of(leaves));
+        }
+        return;
+    }
+    let k = split(n);
+    if m <= k {
+        consistency_path(m, &leaves[..k], complete, path);
+        path.push(root_of(&leaves[k..]));
+    } else {
+        consistency_path(m - k, &leaves[k..], false, path);
+        path.push(root_of(&leaves[..k]));
+    }
+}
+
+/// Append-only Merkle tree over leaf hashes
+#[derive(Debug, Clone, Default)]
+pub struct MerkleTree {
+    leaves: Vec<Hash>,
+    /// Roots of the perfect subtrees along the right edge, largest first
+    frontier: Vec<Hash>,
+}
+
+impl MerkleTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a leaf hash; returns its index
+    pub fn push(&mut self, leaf: Hash) -> u64 {
+        let index = self.leaves.len() as u64;
+        // Merge the subtrees the new leaf completes, one per trailing one bit
+        let mut node = leaf;
+        let mut size = index;
+        while size & 1 == 1 {
+            let left = self.frontier.pop().expect("frontier holds one root per set bit");
+            node = node_hash(&left, &node);
+            size >>= 1;
+        }
+        self.frontier.push(node);
+        self.leaves.push(leaf);
+        index
+    }
+
+    pub fn len(&self) -> u64 {
+        self.leaves.len() as u64
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    pub fn leaf(&self, index: u64) -> Option<&Hash> {
+        self.leaves.get(index as usize)
+    }
+
+    /// Root of the whole tree, folded from the frontier in O(log n)
+    pub fn root(&self) -> Hash {
+        match self.frontier.split_last() {
+            None => Sha256::digest([]).into(),
+            Some((last, rest)) => rest
+                .iter()
+                .rev()
+                .fold(*last, |right, left| node_hash(left, &right)),
+        }
+    }
+
+    /// Root of the tree as it was when it held `size` leaves
+    pub fn root_at(&self, size: u64) -> Result<Hash> {
+        Ok(root_of(self.prefix(size)?))
+    }
+
+    /// Proof that leaf `index` is in the tree of the first `tree_size` leaves
+    pub fn inclusion_proof(&self, index: u64, tree_size: u64) -> Result<InclusionProof> {
+        let leaves = self.prefix(tree_size)?;
+        if index >= tree_size {
+            return Err(DeoxysError::config(
+                "inclusion proof",
+                format!("leaf {} is outside the tree of size {}", index, tree_size),
+            ));
+        }
+        let mut path = Vec::new();
+        inclusion_path(index as usize, leaves, &mut path);
+        Ok(InclusionProof {
+            leaf_index: index,
+            tree_size,
+            path,
+        })
+    }
+
+    /// Proof that the tree of `to_size` leaves extends the one of `from_size`
+    pub fn consistency_proof(&self, from_size: u64, to_size: u64) -> Result<ConsistencyProof> {
+        let leaves = self.prefix(to_size)?;
+        if from_size > to_size {
+            return Err(DeoxysError::config(
+                "consistency proof",
+                format!("size {} is larger than {}", from_size, to_size),
+            ));
+        }
+        let mut path = Vec::new();
+        if from_size > 0 {
+            consistency_path(from_size as usize, leaves, true, &mut path);
+        }
+        Ok(ConsistencyProof {
+            from_size,
+            to_size,
+            path,
+        })
+    }
+
+    fn prefix(&self, size: u64) -> Result<&[Hash]> {
+        self.leaves.get(..size as usize).ok_or_else(|| {
+            DeoxysError::config(
+                "tree size",
+                format!("{} exceeds the {} leaves appended", size, self.leaves.len()),
+            )
+        })
+    }
+}
+
+/// Audit path from a leaf to the root of a tree of `tree_size` leaves
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub leaf_index: u64,
+    pub tree_size: u64,
+    #[serde(with = "hex_hashes")]
+    pub path: Vec<Hash>,
+}
+
+impl InclusionProof {
+    /// RFC 9162 §2.1.3.2
+    pub fn verify(&self, leaf: &Hash, root: &Hash) -> Result<(), ProvenanceError> {
+        let not_included = ProvenanceError::NotIncluded {
+            leaf: self.leaf_index,
+            tree_size: self.tree_size,
+        };
+        if self.leaf_index >= self.tree_size {
+            return Err(not_included);
+        }
+        let (mut f, mut s) = (self.leaf_index, self.tree_size - 1);
+        let mut r = *leaf;
+        for p in &self.path {
+            if s == 0 {
+                return Err(not_included);
+            }
+            if f & 1 == 1 || f == s {
+                r = node_hash(p, &r);
+                while f & 1 == 0 && f != 0 {
+                    f >>= 1;
+                    s >>= 1;
+                }
+            } else {
+                r = node_hash(&r, p);
+            }
+            f >>= 1;
+            s >>= 1;
+        }
+        if s == 0 && r == *root {
+            Ok(())
+        } else {
+            Err(not_included)
+        }
+    }
+}
+
+/// Hashes showing the tree of `to_size` leaves is an extension of the tree
+/// of `from_size` leaves
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsistencyProof {
+    pub from_size: u64,
+    pub to_size: u64,
+    #[serde(with = "hex_hashes")]
+    pub path: Vec<Hash>,
+}
+
+impl ConsistencyProof {
+    /// RFC 9162 §2.1.4.2; every tree extends the empty tree
+    pub fn verify(&self, from_root: &Hash, to_root: &Hash) -> Result<(), ProvenanceError> {
+        let inconsistent = ProvenanceError::Inconsistent {
+            from_size: self.from_size,
+            to_size: self.to_size,
+        };
+        let ok = match (self.from_size, self.to_size) {
+            (from, to) if from > to => false,
+            (0, _) => self.path.is_empty(),
+            (from, to) if from == to => self.path.is_empty() && from_root == to_root,
+            (from, to) => self.verify_path(from, to, from_root, to_root),
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(inconsistent)
+        }
+    }
+
+    fn verify_path(&self, from: u64, to: u64, from_root: &Hash, to_root: &Hash) -> bool {
+        let mut path = self.path.iter();
+        let seed = if from.is_power_of_two() {
+            Some(from_root)
+        } else {
+            path.next()
+        };
+        let Some(&seed) = seed else {
+            return false;
+        };
+        let (mut f, mut s) = (from - 1, to - 1);
+        while f & 1 == 1 {
+            f >>= 1;
+            s >>= 1;
+        }
+        let (mut fr, mut sr) = (seed, seed);
+        for c in path {
+            if s == 0 {
+                return false;
+            }
+            if f & 1 == 1 || f == s {
+                fr = node_hash(c, &fr);
+                sr = node_hash(c, &sr);
+                while f & 1 == 0 && f != 0 {
+                    f >>= 1;
+                    s >>= 1;
+                }
+            } else {
+                sr = node_hash(&sr, c);
+            }
+            f >>= 1;
+            s >>= 1;
+        }
+        s == 0 && fr == *from_root && sr == *to_root
+    }
+}
+
+/// Signed tree head closing an epoch
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochCommitment {
+    pub epoch: u64,
+    pub tree_size: u64,
+    #[serde(with = "hex_hash")]
+    pub root: Hash,
+    /// Hash of the provenance log record the commitment follows, hex; none
+    /// when the engine keeps no log
+    pub previous: Option<String>,
+    /// Fingerprint of the signing key
+    pub signer: String,
+    pub signature: String,
+}
+
+impl EpochCommitment {
+    fn message(&self) -> Hash {
+        Encoder::new(Domain::Epoch)
+            .u64(self.epoch)
+            .u64(self.tree_size)
+            .bytes(&self.root)
+            .option(self.previous.as_deref(), Encoder::str)
+            .digest()
+    }
+
+    /// Sign the tree head, bound to the log record `previous` it will follow
+    pub fn sign(
+        signer: &ProvenanceSigner,
+        epoch: u64,
+        tree_size: u64,
+        root: Hash,
+        previous: Option<Hash>,
+    ) -> Self {
+        let mut commitment = Self {
+            epoch,
+            tree_size,
+            root,
+            previous: previous.map(hex::encode),
+            signer: signer.fingerprint().to_string(),
+            signature: String::new(),
+        };
+        commitment.signature = signer.sign_digest(&commitment.message());
+        commitment
+    }
+
+    pub fn verify(&self, verifier: &ProvenanceVerifier) -> Result<(), ProvenanceError> {
+        verifier.check_signer(&self.signer)?;
+        let message = self.message();
+        if verifier.verify_digest(&message, &self.signature) {
+            Ok(())
+        } else {
+            Err(ProvenanceError::BadEpochSignature { epoch: self.epoch })
+        }
+    }
+
+    /// Check the signature and that `receipt` is leaf `proof.leaf_index` of this epoch's tree
+    pub fn verify_receipt(
+        &self,
+        verifier: &ProvenanceVerifier,
+        receipt: &ReceiptRecord,
+        proof: &InclusionProof,
+    ) -> Result<(), ProvenanceError> {
+        self.verify(verifier)?;
+        verifier.verify_receipt(receipt)?;
+        if proof.tree_size != self.tree_size {
+            return Err(ProvenanceError::NotIncluded {
+                leaf: proof.leaf_index,
+                tree_size: self.tree_size,
+            });
+        }
+        proof.verify(&receipt_leaf(receipt), &self.root)
+    }
+
+    /// Check both signatures and that `later` extends this epoch's tree
+    pub fn verify_extension(
+        &self,
+        later: &EpochCommitment,
+        verifier: &ProvenanceVerifier,
+        proof: &ConsistencyProof,
+    ) -> Result<(), ProvenanceError> {
+        self.verify(verifier)?;
+        later.verify(verifier)?;
+        if (proof.from_size, proof.to_size) != (self.tree_size, later.tree_size) {
+            return Err(ProvenanceError::Inconsistent {
+                from_size: self.tree_size,
+                to_size: later.tree_size,
+            });
+        }
+        proof.verify(&self.root, &later.root)
+    }
+}
+
+/// Receipt tree cut into epochs of `epoch_length` receipts
+#[derive(Debug, Clone)]
+pub struct EpochTree {
+    epoch_length: u64,
+    tree: MerkleTree,
+    epochs: Vec<EpochCommitment>,
+}
+
+impl EpochTree {
+    pub fn new(epoch_length: u64) -> Result<Self> {
+        if epoch_length == 0 {
+            return Err(DeoxysError::config(
+                "epoch length",
+                "must be at least one receipt",
+            ));
+        }
+        Ok(Self {
+            epoch_length,
+            tree: MerkleTree::new(),
+            epochs: Vec::new(),
+        })
+    }
+
+    pub fn epoch_length(&self) -> u64 {
+        self.epoch_length
+    }
+
+    pub fn tree(&self) -> &MerkleTree {
+        &self.tree
+    }
+
+    pub fn epochs(&self) -> &[EpochCommitment] {
+        &self.epochs
+    }
+
+    /// Append a receipt; returns the commitment when it completes an epoch.
+    /// `log_head` is the hash of the last provenance log record, if any.
+    pub fn push(
+        &mut self,
+        receipt: &ReceiptRecord,
+        signer: &ProvenanceSigner,
+        log_head: Option<Hash>,
+    ) -> Option<&EpochCommitment> {
+        self.tree.push(receipt_leaf(receipt));
+        if self.tree.len().is_multiple_of(self.epoch_length) {
+            self.seal(signer, log_head)
+        } else {
+            None
+        }
+    }
+
+    /// Close the current epoch early (e.g. at shutdown); `None` when no
+    /// receipt was appended since the last commitment
+    pub fn seal(
+        &mut self,
+        signer: &ProvenanceSigner,
+        log_head: Option<Hash>,
+    ) -> Option<&EpochCommitment> {
+        let committed = self.epochs.last().map_or(0, |e| e.tree_size);
+        if self.tree.len() == committed {
+            return None;
+        }
+        let epoch = self.epochs.len() as u64;
+        self.epochs.push(EpochCommitment::sign(
+            signer,
+            epoch,
+            self.tree.len(),
+            self.tree.root(),
+            log_head,
+        ));
+        self.epochs.last()
+    }
+
+    /// Proof of receipt `leaf` against the commitment of `epoch`
+    pub fn inclusion_proof(&self, leaf: u64, epoch: u64) -> Result<InclusionProof> {
+        self.tree
+            .inclusion_proof(leaf, self.commitment(epoch)?.tree_size)
+    }
+
+    /// Proof that epoch `to`'s tree extends epoch `from`'s
+    pub fn consistency_proof(&self, from: u64, to: u64) -> Result<ConsistencyProof> {
+        let from_size = self.commitment(from)?.tree_size;
+        self.tree
+            .consistency_proof(from_size, self.commitment(to)?.tree_size)
+    }
+
+    fn commitment(&self, epoch: u64) -> Result<&EpochCommitment> {
+        self.epochs
+            .get(epoch as usize)
+            .ok_or_else(|| DeoxysError::config("epoch", format!("epoch {} is not sealed", epoch)))
+    }
+}
+
+mod hex_hash {
+    use super::Hash;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(hash))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        let bytes = hex::decode(&text).map_err(D::Error::custom)?;
+        bytes
+            .try_into()
+            .map_err(|_| D::Error::custom("expected a 32-byte hash"))
+    }
+}
+
+mod hex_hashes {
+    use super::Hash;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(hashes: &[Hash], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(hashes.iter().map(hex::encode))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Hash>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|text| {
+                let bytes = hex::decode(text).map_err(D::Error::custom)?;
+                bytes
+                    .try_into()
+                    .map_err(|_| D::Error::custom("expected a 32-byte hash"))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Inputs and roots of the certificate-transparency reference tests
+    const LEAVES: [&str; 8] = [
+        "",
+        "00",
+        "10",
+        "2021",
+        "3031",
+        "40414243",
+        "5051525354555657",
+        "606162636465666768696a6b6c6d6e6f",
+    ];
+    const ROOTS: [&str; 8] = [
+        "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+        "fac54203e7cc696cf0dfcb42c92a1d9dbaf70ad9e621f4bd8d98662f00e3c125",
+        "aeb6bcfe274b70a14fb067a5e5578264db0fa9b51af5e0ba159158f329e06e77",
+        "d37ee418976dd95753c1c73862b9398fa2a2cf9b4ff0fdfe8b30cd95209614b7",
+        "4e3bbb1f7b478dcfe71fb631631519a3bca12c9aefca1612bfce4c13a86264d4",
+        "76e67dadbcdf1e10e1b74ddc608abd2f98dfb16fbce75277b5232a127f2087ef",
+        "ddb89be403809e325750d3d263cd78929c2942b7942a34b77e122c9594a74c8c",
+        "5dc9da79a70659a9ad559cb701ded9a2ab9d823aad2f4960cfe370eff4604328",
+    ];
+
+    fn tree(size: usize) -> MerkleTree {
+        let mut tree = MerkleTree::new();
+        for i in 0..size {
+            tree.push(leaf_hash(&(i as u64).to_le_bytes()));
+        }
+        tree
+    }
+
+    #[test]
+    fn test_reference_roots() {
+        let mut tree = MerkleTree::new();
+        assert_eq!(
+            hex::encode(tree.root()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        for (leaf, root) in LEAVES.iter().zip(ROOTS) {
+            tree.push(leaf_hash(&hex::decode(leaf).unwrap()));
+            assert_eq!(hex::encode(tree.root()), root);
+        }
+
+        // The frontier agrees with hashing the leaves at every size
+        let mut tree = MerkleTree::new();
+        for size in 1..=33u64 {
+            tree.push(leaf_hash(&size.to_le_bytes()));
+            assert_eq!(tree.root(), tree.root_at(size).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_inclusion_proofs() {
+        let tree = tree(21);
+        for size in 1..=21 {
+            let root = tree.root_at(size).unwrap();
+            for index in 0..size {
+                let proof = tree.inclusion_proof(index, size).unwrap();
+                let leaf = tree.leaf(index).unwrap();
+                assert_eq!(proof.verify(leaf, &root), Ok(()));
+                assert!(proof.path.len() <= 5);
+                assert!(proof.verify(&leaf_hash(b"forged"), &root).is_err());
+                if size > 1 {
+                    let moved = InclusionProof {
+                        leaf_index: (index + 1) % size,
+                        ..proof.clone()
+                    };
+                    assert!(moved.verify(leaf, &root).is_err());
+                    let mut bent = proof.clone();
+                    bent.path[0][0] ^= 1;
+                    assert!(bent.verify(leaf, &root).is_err());
+                    let mut short = proof;
+                    short.path.pop();
+                    assert!(short.verify(leaf, &root).is_err());
+                }
+            }
+        }
+        assert!(tree.inclusion_proof(5, 5).is_err());
+        assert!(tree.inclusion_proof(0, 22).is_err());
+    }
+
+    #[test]
+    fn test_consistency_proofs() {
+        let tree = tree(21);
+        let mut other = MerkleTree::new();
+        for i in 0..21u64 {
+            other.push(leaf_hash(&(i ^ 4).to_le_bytes()));
+        }
+        for to in 1..=21 {
+            let to_root = tree.root_at(to).unwrap();
+            for from in 0..=to {
+                let proof = tree.consistency_proof(from, to).unwrap();
+                let from_root = tree.root_at(from).unwrap();
+                assert_eq!(
+                    proof.verify(&from_root, &to_root),
+                    Ok(()),
+                    "{} -> {}",
+                    from,
+                    to
+                );
+                if from > 0 && from < to {
+                    // A rewritten history does not extend the earlier tree
+                    let forged_root = other.root_at(from).unwrap();
+                    assert!(proof.verify(&forged_root, &to_root).is_err());
+                    let mut bent = proof.clone();
+                    bent.path[0][31] ^= 1;
+                    assert!(bent.verify(&from_root, &to_root).is_err());
+                }
+            }
+        }
+        assert!(tree.consistency_proof(3, 2).is_err());
+    }
+
+    #[test]
+    fn test_epoch_commitments() {
+        use crate::invariants::EntropyPolicy;
+        use crate::rik::CycleMode;
+
+        let signer = ProvenanceSigner::from_seed(&[9; 32]);
+        let verifier = ProvenanceVerifier::from(&signer);
+        let mut epochs = EpochTree::new(4).unwrap();
+        assert!(EpochTree::new(0).is_err());
+        let mut receipts: Vec<ReceiptRecord> = Vec::new();
+        let mut sealed = 0;
+        for cycle in 1..=10u64 {
+            let state = ndarray::Array1::from_elem(2, cycle as f64);
+            let mut receipt = ReceiptRecord {
+                cycle,
+                hash: String::new(),
+                signer: String::new(),
+                state_digest: hex::encode(crate::crypto::state_digest(&state)),
+                previous: receipts.last().map(|r| r.hash.clone()),
+                mode: CycleMode::Nominal,
+                ckks_parameters: "ckks-n8192-d2-128".into(),
+                entropy_policy: EntropyPolicy::default(),
+                approval: None,
+            };
+            signer.sign_receipt(&mut receipt).unwrap();
+            if epochs.push(&receipt, &signer, None).is_some() {
+                sealed += 1;
+            }
+            receipts.push(receipt);
+        }
+        assert_eq!(sealed, 2);
+        assert_eq!(epochs.seal(&signer, None).unwrap().tree_size, 10);
+        assert!(epochs.seal(&signer, None).is_none());
+
+        let [first, second, last] = epochs.epochs() else {
+            panic!("expected three epochs");
+        };
+        let proof = epochs.inclusion_proof(5, 1).unwrap();
+        assert_eq!(
+            second.verify_receipt(&verifier, &receipts[5], &proof),
+            Ok(())
+        );
+        assert!(second
+            .verify_receipt(&verifier, &receipts[6], &proof)
+            .is_err());
+        assert!(first
+            .verify_receipt(&verifier, &receipts[5], &proof)
+            .is_err());
+        let proof = epochs.consistency_proof(0, 2).unwrap();
+        assert_eq!(first.verify_extension(last, &verifier, &proof), Ok(()));
+        assert!(epochs.inclusion_proof(0, 3).is_err());
+
+        // Commitments and proofs survive a JSON round trip
+        let json = serde_json::to_string(&(last, &proof)).unwrap();
+        let (parsed, parsed_proof): (EpochCommitment, ConsistencyProof) =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            first.verify_extension(&parsed, &verifier, &parsed_proof),
+            Ok(())
+        );
+
+        let mut forged = last.clone();
+        forged.tree_size += 1;
+        assert_eq!(
+            forged.verify(&verifier),
+            Err(ProvenanceError::BadEpochSignature { epoch: 2 })
+        );
+        let mut moved = last.clone();
+        moved.previous = Some(hex::encode([7; 32]));
+        assert_eq!(
+            moved.verify(&verifier),
+            Err(ProvenanceError::BadEpochSignature { epoch: 2 })
+        );
+        let stranger = ProvenanceVerifier::from(&ProvenanceSigner::from_seed(&[8; 32]));
+        assert!(matches!(
+            first.verify(&stranger),
+            Err(ProvenanceError::WrongKey { .. })
+        ));
+    }
+}
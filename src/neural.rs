@@ -0,0 +1,417 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Dense MLP policies with polynomial activations, evaluable on CKKS
+//! ciphertexts. Each layer is one affine transform (one level) followed by
+//! its activation polynomial (see [`polynomial_depth`]); the model's total
+//! depth must fit the parameter set's modulus chain, which is checked before
+//! any ciphertext is touched.
+//!
+//! Models load from JSON:
+//!
+//! ```json
+//! { "layers": [
+//!     { "weights": [[0.5, -0.2], [0.1, 0.3]], "bias": [0.0, 0.1], "activation": "tanh3" },
+//!     { "weights": [[1.0, -1.0]], "bias": [0.0], "activation": "identity" }
+//! ] }
+//! ```
+
+use crate::ckks::{
+    polynomial_depth, Ciphertext, CkksContext, CkksParameters, GaloisKeys, LinearTransform,
+    RelinKey,
+};
+use crate::error::{DeoxysError, Result};
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Activation applied slot-wise after a layer's affine map. The named
+/// approximations are least-squares fits, accurate only on their interval.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Activation {
+    Identity,
+    /// tanh on [-2, 2], degree 3, max error 0.077
+    Tanh3,
+    /// Logistic sigmoid on [-4, 4], degree 3, max error 0.038
+    Sigmoid3,
+    /// ReLU on [-range, range], degree 2, max error 3 range / 16
+    Relu2 {
+        range: f64,
+    },
+    /// Coefficients c_0, c_1, ... in ascending powers
+    Polynomial {
+        coefficients: Vec<f64>,
+    },
+}
+
+impl Activation {
+    /// Ascending coefficients; empty for the identity
+    pub fn coefficients(&self) -> Vec<f64> {
+        match self {
+            Self::Identity => Vec::new(),
+            Self::Tanh3 => vec![0.0, 0.866_424_884_5, 0.0, -0.105_666_951_4],
+            Self::Sigmoid3 => vec![0.5, 0.216_606_221_1, 0.0, -0.006_604_184_5],
+            Self::Relu2 { range } => vec![3.0 * range / 16.0, 0.5, 15.0 / (64.0 * range)],
+            Self::Polynomial { coefficients } => coefficients.clone(),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        match self {
+            Self::Identity => 0,
+            _ => polynomial_depth(&self.coefficients()),
+        }
+    }
+
+    pub fn apply(&self, x: f64) -> f64 {
+        match self {
+            Self::Identity => x,
+            _ => self
+                .coefficients()
+                .iter()
+                .rev()
+                .fold(0.0, |acc, c| acc * x + c),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let valid = match self {
+            Self::Relu2 { range } => range.is_finite() && *range > 0.0,
+            Self::Polynomial { coefficients } => {
+                !coefficients.is_empty() && coefficients.iter().all(|c| c.is_finite())
+            }
+            _ => true,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(DeoxysError::config(
+                "activation",
+                format!("{:?} is not a valid polynomial", self),
+            ))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LayerSpec {
+    weights: Vec<Vec<f64>>,
+    bias: Vec<f64>,
+    activation: Activation,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ModelSpec {
+    layers: Vec<LayerSpec>,
+}
+
+/// y = activation(W x + b)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseLayer {
+    weights: Array2<f64>,
+    bias: Array1<f64>,
+    activation: Activation,
+    transform: LinearTransform,
+}
+
+impl DenseLayer {
+    pub fn new(weights: Array2<f64>, bias: Array1<f64>, activation: Activation) -> Result<Self> {
+        activation.validate()?;
+        let transform = LinearTransform::new(&weights)?.with_offset(&bias)?;
+        Ok(Self {
+            weights,
+            bias,
+            activation,
+            transform,
+        })
+    }
+
+    pub fn weights(&self) -> &Array2<f64> {
+        &self.weights
+    }
+
+    pub fn bias(&self) -> &Array1<f64> {
+        &self.bias
+    }
+
+    pub fn activation(&self) -> &Activation {
+        &self.activation
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.weights.ncols()
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.weights.nrows()
+    }
+
+    /// Affine map plus activation
+    pub fn depth(&self) -> usize {
+        1 + self.activation.depth()
+    }
+}
+
+/// Feed-forward policy mapping the state to a control proposal
+#[derive(Debug, Clone, PartialEq)]
+pub struct NeuralPolicy {
+    layers: Vec<DenseLayer>,
+}
+
+impl NeuralPolicy {
+    pub fn new(layers: Vec<DenseLayer>) -> Result<Self> {
+        if layers.is_empty() {
+            return Err(DeoxysError::config(
+                "neural policy",
+                "needs at least one layer",
+            ));
+        }
+        for pair in layers.windows(2) {
+            if pair[1].inputs() != pair[0].outputs() {
+                return Err(DeoxysError::DimensionMismatch {
+                    context: "neural policy layer inputs",
+                    expected: pair[0].outputs(),
+                    actual: pair[1].inputs(),
+                });
+            }
+        }
+        Ok(Self { layers })
+    }
+
+    pub fn from_json(json: &str) -> Result<Self> {
+        let spec: ModelSpec = serde_json::from_str(json)
+            .map_err(|e| DeoxysError::config("neural policy", e.to_string()))?;
+        let layers = spec
+            .layers
+            .into_iter()
+            .map(|layer| {
+                let rows = layer.weights.len();
+                let cols = layer.weights.first().map_or(0, Vec::len);
+                if let Some(row) = layer.weights.iter().find(|row| row.len() != cols) {
+                    return Err(DeoxysError::DimensionMismatch {
+                        context: "neural policy weight row",
+                        expected: cols,
+                        actual: row.len(),
+                    });
+                }
+                let weights = Array2::from_shape_vec((rows, cols), layer.weights.concat())
+                    .map_err(|e| DeoxysError::config("neural policy", e.to_string()))?;
+                DenseLayer::new(weights, Array1::from_vec(layer.bias), layer.activation)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(layers)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            DeoxysError::config("neural policy", format!("{}: {}", path.display(), e))
+        })?;
+        Self::from_json(&json)
+    }
+
+    pub fn to_json(&self) -> String {
+        let spec = ModelSpec {
+            layers: self
+                .layers
+                .iter()
+                .map(|layer| LayerSpec {
+                    weights: layer.weights.outer_iter().map(|row| row.to_vec()).collect(),
+                    bias: layer.bias.to_vec(),
+                    activation: layer.activation.clone(),
+                })
+                .collect(),
+        };
+        serde_json::to_string(&spec).expect("model spec serializes")
+    }
+
+    pub fn layers(&self) -> &[DenseLayer] {
+        &self.layers
+    }
+
+    pub fn inputs(&self) -> usize {
+        self.layers[0].inputs()
+    }
+
+    pub fn outputs(&self) -> usize {
+        self.layers[self.layers.len() - 1].outputs()
+    }
+
+    /// Multiplicative depth: levels consumed by one encrypted evaluation
+    pub fn depth(&self) -> usize {
+        self.layers.iter().map(DenseLayer::depth).sum()
+    }
+
+    /// Slot rotations needed across all layers, ascending
+    pub fn rotations(&self) -> Vec<i64> {
+        let mut steps: Vec<i64> = self
+            .layers
+            .iter()
+            .flat_map(|layer| layer.transform.rotations())
+            .collect();
+        steps.sort_unstable();
+        steps.dedup();
+        steps
+    }
+
+    /// Refuse parameters whose modulus chain is too short for the model
+    pub fn check(&self, params: &CkksParameters) -> Result<()> {
+        if self.depth() > params.max_level() {
+            return Err(DeoxysError::config(
+                "neural policy depth",
+                format!(
+                    "model needs {} levels, parameter set {} provides {}",
+                    self.depth(),
+                    params.id(),
+                    params.max_level()
+                ),
+            ));
+        }
+        let width = self
+            .layers
+            .iter()
+            .map(|layer| layer.inputs().max(layer.outputs()))
+            .max()
+            .unwrap_or(0);
+        if width > params.slots() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "ckks slots",
+                expected: params.slots(),
+                actual: width,
+            });
+        }
+        Ok(())
+    }
+
+    /// Plaintext forward pass with the same polynomial activations
+    pub fn forward(&self, state: &Array1<f64>) -> Array1<f64> {
+        self.layers.iter().fold(state.clone(), |x, layer| {
+            layer
+                .transform
+                .apply(&x)
+                .mapv(|v| layer.activation.apply(v))
+        })
+    }
+
+    /// Encrypted forward pass; outputs occupy slots 0..outputs()
+    pub fn evaluate(
+        &self,
+        context: &CkksContext,
+        state: &Ciphertext,
+        relin: &RelinKey,
+        galois: &GaloisKeys,
+    ) -> Result<Ciphertext> {
+        self.check(context.params())?;
+        if state.level() < self.depth() {
+            return Err(DeoxysError::crypto(
+                "neural policy",
+                format!(
+                    "needs {} levels, ciphertext has {}",
+                    self.depth(),
+                    state.level()
+                ),
+            ));
+        }
+        self.layers.iter().try_fold(state.clone(), |x, layer| {
+            let y = layer.transform.evaluate(context, &x, galois)?;
+            match layer.activation {
+                Activation::Identity => Ok(y),
+                ref activation => {
+                    context.evaluate_polynomial(&y, &activation.coefficients(), relin)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ckks::KeyGenerator;
+    use ndarray::array;
+
+    const MODEL: &str = r#"{ "layers": [
+        { "weights": [[0.5, -0.2, 0.1], [0.1, 0.3, -0.4], [-0.6, 0.2, 0.2], [0.3, 0.3, 0.3]],
+          "bias": [0.0, 0.1, -0.1, 0.05], "activation": "tanh3" },
+        { "weights": [[1.0, -1.0, 0.5, 0.2], [0.4, 0.0, -0.3, 1.0]],
+          "bias": [0.0, -0.2], "activation": "identity" }
+    ] }"#;
+
+    #[test]
+    fn test_load_and_depth() {
+        let policy = NeuralPolicy::from_json(MODEL).unwrap();
+        assert_eq!((policy.inputs(), policy.outputs()), (3, 2));
+        // Affine + degree-3 activation (2 levels), then affine
+        assert_eq!(policy.depth(), 4);
+        assert_eq!(NeuralPolicy::from_json(&policy.to_json()).unwrap(), policy);
+
+        let relu = Activation::Relu2 { range: 4.0 };
+        assert!((relu.apply(0.0) - 0.75).abs() < 1e-12);
+        assert_eq!(relu.depth(), 2);
+        assert!((Activation::Tanh3.apply(1.0) - 1f64.tanh()).abs() < 0.077);
+        assert!((Activation::Sigmoid3.apply(-2.0) - 1.0 / (1.0 + 2f64.exp())).abs() < 0.038);
+
+        let mismatched = r#"{ "layers": [
+            { "weights": [[1.0, 0.0]], "bias": [0.0], "activation": "identity" },
+            { "weights": [[1.0, 0.0]], "bias": [0.0], "activation": "identity" } ] }"#;
+        assert!(NeuralPolicy::from_json(mismatched).is_err());
+        let ragged = r#"{ "layers": [ { "weights": [[1.0, 0.0], [1.0]], "bias": [0.0, 0.0],
+            "activation": "identity" } ] }"#;
+        assert!(NeuralPolicy::from_json(ragged).is_err());
+        let bad = r#"{ "layers": [ { "weights": [[1.0]], "bias": [0.0],
+            "activation": { "relu2": { "range": -1.0 } } } ] }"#;
+        assert!(NeuralPolicy::from_json(bad).is_err());
+    }
+
+    #[test]
+    fn test_refuses_insufficient_modulus_chain() {
+        let policy = NeuralPolicy::from_json(MODEL).unwrap();
+        // Default preset has depth 2
+        let err = policy.check(&CkksParameters::default()).unwrap_err();
+        assert_eq!(err.code(), "invalid_configuration");
+        assert!(policy
+            .check(&CkksParameters::preset(
+                crate::ckks::ParameterSet::N16384Depth6Bits128
+            ))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_encrypted_inference_matches_plaintext() {
+        let context =
+            CkksContext::new(CkksParameters::generate(10, &[60, 40, 40, 40, 40], 60, 40).unwrap());
+        let policy = NeuralPolicy::from_json(MODEL).unwrap();
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let relin = keygen.relin_key(&secret);
+        let galois = keygen.galois_keys(&secret, &policy.rotations(), false);
+
+        let x = array![0.8, -0.5, 1.2];
+        let ct = context.encrypt(
+            &context
+                .encode(&x.to_vec(), 4, context.params().scale())
+                .unwrap(),
+            &public,
+        );
+        let u = policy.evaluate(&context, &ct, &relin, &galois).unwrap();
+        assert_eq!(u.level(), 0);
+        let decrypted = context.decode(&context.decrypt(&u, &secret));
+        let expected = policy.forward(&x);
+        for j in 0..2 {
+            assert!(
+                (decrypted[j] - expected[j]).abs() < 1e-4,
+                "{} vs {}",
+                decrypted[j],
+                expected[j]
+            );
+        }
+
+        let shallow = context.drop_level(&ct, 3).unwrap();
+        assert!(policy
+            .evaluate(&context, &shallow, &relin, &galois)
+            .is_err());
+    }
+}
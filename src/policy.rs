@@ -0,0 +1,225 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use crate::error::{DeoxysError, Result};
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Engine response once an invariant escalates, ordered by severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailurePolicy {
+    /// Record and continue
+    Warn,
+    /// Continue with reduced function (A2A exchange suspended)
+    Degrade,
+    /// Replace the control output with the safe state
+    SafeState,
+    /// Refuse every cycle until an authorized reset
+    Lockdown,
+    /// Fail-stop: the cycle error is returned and the engine stays halted
+    Halt,
+}
+
+/// How many failures an invariant may absorb before it escalates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Debounce {
+    /// Escalate on the first failure
+    Immediate,
+    /// Escalate once `n` of the last `m` evaluations failed
+    Window { n: u32, m: u32 },
+    /// Escalate once `limit` failures have accumulated since the last reset
+    Budget { limit: u32 },
+}
+
+/// Failure policy and debouncing declared for one invariant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvariantPolicy {
+    pub action: FailurePolicy,
+    pub debounce: Debounce,
+}
+
+impl InvariantPolicy {
+    pub fn new(action: FailurePolicy, debounce: Debounce) -> Result<Self> {
+        match debounce {
+            Debounce::Window { n, m } if n == 0 || n > m => {
+                return Err(DeoxysError::config(
+                    "debounce window",
+                    format!("need 1 <= n <= m, got {} of {}", n, m),
+                ));
+            }
+            Debounce::Budget { limit: 0 } => {
+                return Err(DeoxysError::config(
+                    "debounce budget",
+                    "must allow at least one failure",
+                ));
+            }
+            _ => {}
+        }
+        Ok(Self { action, debounce })
+    }
+}
+
+impl Default for InvariantPolicy {
+    /// Fail-stop on the first breach (Zero Entropy Law)
+    fn default() -> Self {
+        Self {
+            action: FailurePolicy::Halt,
+            debounce: Debounce::Immediate,
+        }
+    }
+}
+
+/// Latched escalation of one invariant
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Escalation {
+    pub invariant: String,
+    pub action: FailurePolicy,
+    /// Cycle at which the debounce threshold was crossed
+    pub cycle: u64,
+    /// Breach that crossed the threshold
+    pub error: DeoxysError,
+}
+
+/// Debounce history and latched escalation of one invariant
+///
+/// Escalation latches: later passing cycles do not clear it, only `reset`.
+#[derive(Debug, Clone, Default)]
+pub struct EscalationState {
+    recent: VecDeque<bool>,
+    failures: u64,
+    escalation: Option<Escalation>,
+}
+
+impl EscalationState {
+    /// Record one evaluation; returns true when this failure triggers escalation
+    pub fn record(
+        &mut self,
+        invariant: &str,
+        policy: &InvariantPolicy,
+        cycle: u64,
+        failure: Option<&DeoxysError>,
+    ) -> bool {
+        if let Debounce::Window { m, .. } = policy.debounce {
+            if self.recent.len() == m as usize {
+                self.recent.pop_front();
+            }
+            self.recent.push_back(failure.is_some());
+        }
+        let Some(error) = failure else {
+            return false;
+        };
+        self.failures += 1;
+        if self.escalation.is_some() {
+            return false;
+        }
+
+        let tripped = match policy.debounce {
+            Debounce::Immediate => true,
+            Debounce::Window { n, .. } => self.recent_failures() >= n as usize,
+            Debounce::Budget { limit } => self.failures >= u64::from(limit),
+        };
+        if tripped {
+            self.escalation = Some(Escalation {
+                invariant: invariant.to_string(),
+                action: policy.action,
+                cycle,
+                error: error.clone(),
+            });
+        }
+        tripped
+    }
+
+    pub fn escalation(&self) -> Option<&Escalation> {
+        self.escalation.as_ref()
+    }
+
+    /// Failures since the last reset
+    pub fn failures(&self) -> u64 {
+        self.failures
+    }
+
+    /// Failures inside the current debounce window
+    pub fn recent_failures(&self) -> usize {
+        self.recent.iter().filter(|&&failed| failed).count()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breach(cycle: u64) -> DeoxysError {
+        DeoxysError::InvariantViolation {
+            invariant: "probe".to_string(),
+            cycle,
+            detail: "noisy sample".to_string(),
+        }
+    }
+
+    fn run(policy: InvariantPolicy, pattern: &[bool]) -> Option<u64> {
+        let mut state = EscalationState::default();
+        for (i, &failed) in pattern.iter().enumerate() {
+            let cycle = i as u64 + 1;
+            let error = breach(cycle);
+            state.record("probe", &policy, cycle, failed.then_some(&error));
+        }
+        state.escalation().map(|e| e.cycle)
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        let window = |n, m| InvariantPolicy::new(FailurePolicy::Halt, Debounce::Window { n, m });
+        assert!(window(0, 3).is_err());
+        assert!(window(4, 3).is_err());
+        assert!(InvariantPolicy::new(FailurePolicy::Halt, Debounce::Budget { limit: 0 }).is_err());
+        assert!(InvariantPolicy::new(FailurePolicy::Warn, Debounce::Window { n: 2, m: 3 }).is_ok());
+    }
+
+    #[test]
+    fn test_immediate_escalates_on_first_failure() {
+        assert_eq!(run(InvariantPolicy::default(), &[false, true, false]), Some(2));
+    }
+
+    #[test]
+    fn test_window_absorbs_isolated_noise() {
+        let debounce = Debounce::Window { n: 2, m: 3 };
+        let policy = InvariantPolicy::new(FailurePolicy::Lockdown, debounce).unwrap();
+        // Isolated failures never fall into the same 3-cycle window
+        assert_eq!(run(policy, &[true, false, false, true, false, false, true]), None);
+        assert_eq!(run(policy, &[true, false, false, true, false, true]), Some(6));
+    }
+
+    #[test]
+    fn test_budget_accumulates_until_reset() {
+        let debounce = Debounce::Budget { limit: 3 };
+        let policy = InvariantPolicy::new(FailurePolicy::Degrade, debounce).unwrap();
+        assert_eq!(run(policy, &[true, false, false, false, true, false, true]), Some(7));
+
+        let mut state = EscalationState::default();
+        let error = breach(1);
+        for cycle in 1..=3 {
+            state.record("probe", &policy, cycle, Some(&error));
+        }
+        assert_eq!(state.escalation().map(|e| e.action), Some(FailurePolicy::Degrade));
+        // Latched across passing cycles
+        state.record("probe", &policy, 4, None);
+        assert!(state.escalation().is_some());
+        state.reset();
+        assert!(state.escalation().is_none());
+        assert_eq!(state.failures(), 0);
+    }
+
+    #[test]
+    fn test_severity_order() {
+        assert!(FailurePolicy::Halt > FailurePolicy::Lockdown);
+        assert!(FailurePolicy::Lockdown > FailurePolicy::SafeState);
+        assert!(FailurePolicy::SafeState > FailurePolicy::Degrade);
+        assert!(FailurePolicy::Degrade > FailurePolicy::Warn);
+    }
+}
@@ -0,0 +1,721 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! M-of-N operator quorum for high-consequence configuration changes.
+//!
+//! A [`CommandRequest`] names an [`OperatorCommand`] and the quorum sequence
+//! number it applies at. Each authorized operator signs it with a timestamped
+//! [`Endorsement`]; once `threshold` distinct operators have endorsed the
+//! same request within the policy window, [`Quorum::endorse`] assembles a
+//! [`QuorumCertificate`] and advances the sequence, so a certificate can
+//! never be replayed. Partial approvals are kept as [`PendingCommand`]s and
+//! expire when the window closes.
+//!
+//! The operators and policy in force are recorded in the provenance log as
+//! [`GovernanceRecord`]s, signed by the provenance key, and changed only by
+//! certified [`OperatorCommand::SetQuorumPolicy`] commands. [`Governance`]
+//! replays both, so an offline audit can check every operator decision and
+//! certificate against the authority in force when it was recorded.
+
+use crate::approval::Decision;
+use crate::canonical::{Domain, Encoder};
+use crate::crypto::{ProvenanceError, ProvenanceSigner, ProvenanceVerifier};
+use crate::error::{DeoxysError, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Configuration change that needs a quorum
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum OperatorCommand {
+    /// Replace the operator bounds with wider ones
+    SetBounds { min: f64, max: f64 },
+    /// Clear the latched lockdown or halt escalation of an invariant
+    ResetEscalation { invariant: String },
+    /// Allow provenance signing to rotate to this public key, hex
+    RotateSigner { to_key: String },
+    /// Replace the quorum policy
+    SetQuorumPolicy { terms: QuorumTerms },
+}
+
+impl OperatorCommand {
+    /// Canonical digest identifying the command
+    pub fn digest(&self) -> [u8; 32] {
+        let encoder = Encoder::new(Domain::OperatorCommand);
+        match self {
+            Self::SetBounds { min, max } => encoder.str("set_bounds").f64(*min).f64(*max),
+            Self::ResetEscalation { invariant } => encoder.str("reset_escalation").str(invariant),
+            Self::RotateSigner { to_key } => encoder.str("rotate_signer").str(to_key),
+            Self::SetQuorumPolicy { terms } => terms.encode(encoder.str("set_quorum_policy")),
+        }
+        .digest()
+    }
+}
+
+/// [`QuorumPolicy`] as recorded in commands and governance records
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuorumTerms {
+    pub threshold: u64,
+    /// Operator public keys, hex
+    pub operators: Vec<String>,
+    pub window_secs: u64,
+}
+
+impl QuorumTerms {
+    fn encode(&self, encoder: Encoder) -> Encoder {
+        encoder.u64(self.threshold).strs(&self.operators).u64(self.window_secs)
+    }
+}
+
+/// Command to be endorsed at a given quorum sequence number of one engine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandRequest {
+    /// Fingerprint of the provenance key the engine's log started with, so
+    /// a certificate cannot be replayed on another deployment
+    pub root: String,
+    /// Number of certificates assembled before this one
+    pub sequence: u64,
+    pub command: OperatorCommand,
+}
+
+/// One operator's timestamped signature over a request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Endorsement {
+    /// Operator public key, hex
+    pub operator_key: String,
+    /// Unix time of signing, in seconds
+    pub signed_at: u64,
+    pub signature: String,
+}
+
+impl Endorsement {
+    fn message(request: &CommandRequest, signed_at: u64) -> [u8; 32] {
+        Encoder::new(Domain::Endorsement)
+            .str(&request.root)
+            .u64(request.sequence)
+            .bytes(&request.command.digest())
+            .u64(signed_at)
+            .digest()
+    }
+
+    pub fn sign(operator: &ProvenanceSigner, request: &CommandRequest, signed_at: u64) -> Self {
+        Self {
+            operator_key: hex::encode(operator.verifying_key().to_bytes()),
+            signed_at,
+            signature: operator.sign_digest(&Self::message(request, signed_at)),
+        }
+    }
+
+    /// Check the signature over `request` and return the operator's key
+    pub fn verify(&self, request: &CommandRequest) -> Result<ProvenanceVerifier, ProvenanceError> {
+        let invalid = ProvenanceError::BadEndorsement {
+            sequence: request.sequence,
+        };
+        let operator = ProvenanceVerifier::from_hex(&self.operator_key).ok_or(invalid.clone())?;
+        if operator.verify_digest(&Self::message(request, self.signed_at), &self.signature) {
+            Ok(operator)
+        } else {
+            Err(invalid)
+        }
+    }
+}
+
+/// Request endorsed by fewer operators than the threshold so far
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PendingCommand {
+    pub request: CommandRequest,
+    pub endorsements: Vec<Endorsement>,
+}
+
+impl PendingCommand {
+    /// Unix time, in seconds, after which the partial approval is dropped
+    pub fn expires_at(&self, window: Duration) -> u64 {
+        self.endorsements
+            .iter()
+            .map(|e| e.signed_at)
+            .min()
+            .unwrap_or(0)
+            .saturating_add(window.as_secs())
+    }
+}
+
+/// Request endorsed by a quorum of distinct operators within the window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuorumCertificate {
+    pub request: CommandRequest,
+    pub threshold: u64,
+    pub window_secs: u64,
+    pub endorsements: Vec<Endorsement>,
+}
+
+impl QuorumCertificate {
+    /// Check every endorsement, that at least `threshold` distinct keys
+    /// signed, and that all signatures fall within the window. Returns the
+    /// endorsing keys.
+    pub fn verify(&self) -> Result<Vec<ProvenanceVerifier>, ProvenanceError> {
+        let sequence = self.request.sequence;
+        let mut operators: Vec<ProvenanceVerifier> = Vec::new();
+        for endorsement in &self.endorsements {
+            let operator = endorsement.verify(&self.request)?;
+            if operators
+                .iter()
+                .any(|known| known.fingerprint() == operator.fingerprint())
+            {
+                return Err(ProvenanceError::BadEndorsement { sequence });
+            }
+            operators.push(operator);
+        }
+        let times = self.endorsements.iter().map(|e| e.signed_at);
+        let spread = times.clone().max().unwrap_or(0) - times.min().unwrap_or(0);
+        if self.threshold == 0
+            || (operators.len() as u64) < self.threshold
+            || spread > self.window_secs
+        {
+            return Err(ProvenanceError::QuorumNotMet {
+                sequence,
+                endorsements: operators.len() as u64,
+                threshold: self.threshold,
+            });
+        }
+        Ok(operators)
+    }
+
+    /// Verify against a policy: every endorser is authorized and the
+    /// threshold and window are at least as strict as the policy's
+    pub fn verify_policy(&self, policy: &QuorumPolicy) -> Result<(), ProvenanceError> {
+        for operator in self.verify()? {
+            policy.authorize(&operator)?;
+        }
+        if self.threshold < policy.threshold() as u64 || self.window_secs > policy.window.as_secs()
+        {
+            return Err(ProvenanceError::QuorumNotMet {
+                sequence: self.request.sequence,
+                endorsements: self.endorsements.len() as u64,
+                threshold: policy.threshold() as u64,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Which operators may endorse, how many must, and within what time
+#[derive(Debug, Clone)]
+pub struct QuorumPolicy {
+    threshold: usize,
+    operators: Vec<ProvenanceVerifier>,
+    window: Duration,
+}
+
+impl QuorumPolicy {
+    pub fn new(
+        threshold: usize,
+        operators: Vec<ProvenanceVerifier>,
+        window: Duration,
+    ) -> Result<Self> {
+        if threshold == 0 || threshold > operators.len() {
+            return Err(DeoxysError::config(
+                "quorum",
+                format!(
+                    "threshold {} must be between 1 and the {} operators",
+                    threshold,
+                    operators.len()
+                ),
+            ));
+        }
+        for (i, operator) in operators.iter().enumerate() {
+            if operators[..i]
+                .iter()
+                .any(|other| other.fingerprint() == operator.fingerprint())
+            {
+                return Err(DeoxysError::config(
+                    "quorum",
+                    format!("operator {} listed twice", operator.fingerprint()),
+                ));
+            }
+        }
+        if window.as_secs() == 0 {
+            return Err(DeoxysError::config(
+                "quorum",
+                "window must be at least one second",
+            ));
+        }
+        Ok(Self {
+            threshold,
+            operators,
+            window,
+        })
+    }
+
+    pub fn from_terms(terms: &QuorumTerms) -> Result<Self> {
+        let operators = terms
+            .operators
+            .iter()
+            .map(|key| {
+                ProvenanceVerifier::from_hex(key).ok_or_else(|| {
+                    DeoxysError::config("quorum", format!("{} is not a public key", key))
+                })
+            })
+            .collect::<Result<_>>()?;
+        Self::new(
+            terms.threshold as usize,
+            operators,
+            Duration::from_secs(terms.window_secs),
+        )
+    }
+
+    pub fn terms(&self) -> QuorumTerms {
+        QuorumTerms {
+            threshold: self.threshold as u64,
+            operators: self.operators.iter().map(public_key_hex).collect(),
+            window_secs: self.window.as_secs(),
+        }
+    }
+
+    /// Command installing this policy, to be certified under the current one
+    pub fn command(&self) -> OperatorCommand {
+        OperatorCommand::SetQuorumPolicy {
+            terms: self.terms(),
+        }
+    }
+
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    pub fn operators(&self) -> &[ProvenanceVerifier] {
+        &self.operators
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    fn authorize(&self, operator: &ProvenanceVerifier) -> Result<(), ProvenanceError> {
+        if self
+            .operators
+            .iter()
+            .any(|key| key.fingerprint() == operator.fingerprint())
+        {
+            Ok(())
+        } else {
+            Err(ProvenanceError::UnauthorizedOperator {
+                fingerprint: operator.fingerprint().to_string(),
+            })
+        }
+    }
+}
+
+/// Quorum policy with its sequence number and partial approvals
+#[derive(Debug, Clone)]
+pub struct Quorum {
+    policy: QuorumPolicy,
+    root: String,
+    sequence: u64,
+    pending: Vec<PendingCommand>,
+}
+
+impl Quorum {
+    /// Start at `sequence`, the number of certificates already assembled
+    /// for the engine whose log started with the key fingerprinted `root`
+    pub fn new(policy: QuorumPolicy, root: impl Into<String>, sequence: u64) -> Self {
+        Self {
+            policy,
+            root: root.into(),
+            sequence,
+            pending: Vec::new(),
+        }
+    }
+
+    pub fn policy(&self) -> &QuorumPolicy {
+        &self.policy
+    }
+
+    pub fn root(&self) -> &str {
+        &self.root
+    }
+
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Partial approvals not yet expired at the last call to `expire` or `endorse`
+    pub fn pending(&self) -> &[PendingCommand] {
+        &self.pending
+    }
+
+    /// Request for `command` at the current sequence number
+    pub fn request(&self, command: OperatorCommand) -> CommandRequest {
+        CommandRequest {
+            root: self.root.clone(),
+            sequence: self.sequence,
+            command,
+        }
+    }
+
+    /// Drop partial approvals whose window closed before `now`; returns how many
+    pub fn expire(&mut self, now: u64) -> usize {
+        let window = self.policy.window;
+        let before = self.pending.len();
+        self.pending.retain(|pending| {
+            let live = pending.expires_at(window) >= now;
+            if !live {
+                warn!(
+                    "   -> Quorum request {} expired with {} of {} endorsements",
+                    pending.request.sequence,
+                    pending.endorsements.len(),
+                    self.policy.threshold
+                );
+            }
+            live
+        });
+        before - self.pending.len()
+    }
+
+    /// Record an endorsement made at or before `now`. Returns the certificate
+    /// once the threshold is reached; a repeated endorsement by the same
+    /// operator is ignored.
+    pub fn endorse(
+        &mut self,
+        request: &CommandRequest,
+        endorsement: Endorsement,
+        now: u64,
+    ) -> Result<Option<QuorumCertificate>, ProvenanceError> {
+        self.expire(now);
+        let sequence = request.sequence;
+        if sequence != self.sequence || request.root != self.root {
+            return Err(ProvenanceError::BadEndorsement { sequence });
+        }
+        let operator = endorsement.verify(request)?;
+        self.policy.authorize(&operator)?;
+        let window = self.policy.window.as_secs();
+        if endorsement.signed_at > now || endorsement.signed_at.saturating_add(window) < now {
+            return Err(ProvenanceError::EndorsementExpired { sequence });
+        }
+
+        let index = match self.pending.iter().position(|p| &p.request == request) {
+            Some(index) => index,
+            None => {
+                self.pending.push(PendingCommand {
+                    request: request.clone(),
+                    endorsements: Vec::new(),
+                });
+                self.pending.len() - 1
+            }
+        };
+        let pending = &mut self.pending[index];
+        if pending
+            .endorsements
+            .iter()
+            .any(|e| e.operator_key == endorsement.operator_key)
+        {
+            return Ok(None);
+        }
+        let times = pending.endorsements.iter().map(|e| e.signed_at);
+        let times = times.chain([endorsement.signed_at]);
+        if times.clone().max().unwrap_or(0) - times.min().unwrap_or(0) > window {
+            return Err(ProvenanceError::EndorsementExpired { sequence });
+        }
+        pending.endorsements.push(endorsement);
+        info!(
+            "   -> Quorum request {} endorsed by {} ({} of {})",
+            sequence,
+            operator.fingerprint(),
+            pending.endorsements.len(),
+            self.policy.threshold
+        );
+        if pending.endorsements.len() < self.policy.threshold {
+            return Ok(None);
+        }
+
+        let pending = self.pending.remove(index);
+        // Other requests were endorsed for this sequence number and are now stale
+        self.pending.clear();
+        self.sequence += 1;
+        Ok(Some(QuorumCertificate {
+            request: pending.request,
+            threshold: self.policy.threshold as u64,
+            window_secs: window,
+            endorsements: pending.endorsements,
+        }))
+    }
+}
+
+fn public_key_hex(key: &ProvenanceVerifier) -> String {
+    hex::encode(key.verifying_key().to_bytes())
+}
+
+/// Operators and quorum policy put in force by the engine, signed by the
+/// active provenance key. Each record replaces the previous one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GovernanceRecord {
+    /// Operators whose decisions gate cycles, public keys hex
+    pub operators: Vec<String>,
+    pub quorum: Option<QuorumTerms>,
+    /// Fingerprint of the provenance key signing the record
+    pub signer: String,
+    pub signature: String,
+}
+
+impl GovernanceRecord {
+    fn message(operators: &[String], quorum: Option<&QuorumTerms>) -> [u8; 32] {
+        Encoder::new(Domain::Governance)
+            .strs(operators)
+            .option(quorum, |encoder, terms| terms.encode(encoder))
+            .digest()
+    }
+
+    pub fn new(
+        signer: &ProvenanceSigner,
+        operators: &[ProvenanceVerifier],
+        quorum: Option<&QuorumPolicy>,
+    ) -> Self {
+        let operators: Vec<String> = operators.iter().map(public_key_hex).collect();
+        let quorum = quorum.map(QuorumPolicy::terms);
+        let signature = signer.sign_digest(&Self::message(&operators, quorum.as_ref()));
+        Self {
+            operators,
+            quorum,
+            signer: signer.fingerprint().to_string(),
+            signature,
+        }
+    }
+}
+
+/// Operators and quorum policy in force at a point of the provenance log,
+/// replayed from governance records and certified policy changes
+#[derive(Debug, Clone, Default)]
+pub struct Governance {
+    operators: Vec<ProvenanceVerifier>,
+    policy: Option<QuorumPolicy>,
+}
+
+impl Governance {
+    pub fn operators(&self) -> &[ProvenanceVerifier] {
+        &self.operators
+    }
+
+    pub fn policy(&self) -> Option<&QuorumPolicy> {
+        self.policy.as_ref()
+    }
+
+    /// Accept a governance record signed by the `active` provenance key
+    pub fn apply(
+        &mut self,
+        record: &GovernanceRecord,
+        active: &ProvenanceVerifier,
+    ) -> Result<(), ProvenanceError> {
+        let message = GovernanceRecord::message(&record.operators, record.quorum.as_ref());
+        if record.signer != active.fingerprint()
+            || !active.verify_digest(&message, &record.signature)
+        {
+            return Err(ProvenanceError::BadGovernance {
+                signer: record.signer.clone(),
+            });
+        }
+        let malformed = || ProvenanceError::BadGovernance {
+            signer: record.signer.clone(),
+        };
+        let operators = record
+            .operators
+            .iter()
+            .map(|key| ProvenanceVerifier::from_hex(key).ok_or_else(malformed))
+            .collect::<Result<_, _>>()?;
+        let policy = record
+            .quorum
+            .as_ref()
+            .map(|terms| QuorumPolicy::from_terms(terms).map_err(|_| malformed()))
+            .transpose()?;
+        self.operators = operators;
+        self.policy = policy;
+        Ok(())
+    }
+
+    /// Check a decision's signature and that an authorized operator made it
+    pub fn check_decision(&self, decision: &Decision) -> Result<(), ProvenanceError> {
+        let operator = decision.verify()?;
+        if !self
+            .operators
+            .iter()
+            .any(|key| key.fingerprint() == operator.fingerprint())
+        {
+            return Err(ProvenanceError::UnauthorizedOperator {
+                fingerprint: operator.fingerprint().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check a certificate for the engine whose log started with `root`
+    /// against the policy in force, then apply the policy change it
+    /// certifies, if any
+    pub fn certify(
+        &mut self,
+        certificate: &QuorumCertificate,
+        root: &ProvenanceVerifier,
+    ) -> Result<(), ProvenanceError> {
+        let sequence = certificate.request.sequence;
+        if certificate.request.root != root.fingerprint() {
+            return Err(ProvenanceError::BadEndorsement { sequence });
+        }
+        let policy = self
+            .policy
+            .as_ref()
+            .ok_or(ProvenanceError::NoQuorumPolicy { sequence })?;
+        certificate.verify_policy(policy)?;
+        if let OperatorCommand::SetQuorumPolicy { terms } = &certificate.request.command {
+            let successor = QuorumPolicy::from_terms(terms)
+                .map_err(|_| ProvenanceError::BadEndorsement { sequence })?;
+            self.policy = Some(successor);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operators() -> Vec<ProvenanceSigner> {
+        (1..=3)
+            .map(|i| ProvenanceSigner::from_seed(&[i; 32]))
+            .collect()
+    }
+
+    const ROOT: &str = "root-fingerprint";
+
+    fn quorum(signers: &[ProvenanceSigner]) -> Quorum {
+        let keys = signers.iter().map(ProvenanceVerifier::from).collect();
+        Quorum::new(
+            QuorumPolicy::new(2, keys, Duration::from_secs(60)).unwrap(),
+            ROOT,
+            0,
+        )
+    }
+
+    #[test]
+    fn test_policy_validation() {
+        let keys: Vec<_> = operators().iter().map(ProvenanceVerifier::from).collect();
+        let window = Duration::from_secs(60);
+        assert!(QuorumPolicy::new(0, keys.clone(), window).is_err());
+        assert!(QuorumPolicy::new(4, keys.clone(), window).is_err());
+        assert!(QuorumPolicy::new(2, keys.clone(), Duration::ZERO).is_err());
+        let twice = vec![keys[0].clone(), keys[0].clone()];
+        assert!(QuorumPolicy::new(1, twice, window).is_err());
+        let policy = QuorumPolicy::new(3, keys, window).unwrap();
+
+        // A policy survives the round trip through its recorded terms
+        let terms = policy.terms();
+        assert_eq!(QuorumPolicy::from_terms(&terms).unwrap().terms(), terms);
+        let malformed = QuorumTerms {
+            operators: vec!["00".into()],
+            threshold: 1,
+            ..terms
+        };
+        assert!(QuorumPolicy::from_terms(&malformed).is_err());
+    }
+
+    #[test]
+    fn test_two_of_three_within_window() {
+        let signers = operators();
+        let outsider = ProvenanceSigner::from_seed(&[9; 32]);
+        let mut quorum = quorum(&signers);
+        let request = quorum.request(OperatorCommand::SetBounds {
+            min: -2.0,
+            max: 2.0,
+        });
+
+        let forged = Endorsement::sign(&outsider, &request, 1000);
+        assert!(matches!(
+            quorum.endorse(&request, forged, 1000),
+            Err(ProvenanceError::UnauthorizedOperator { .. })
+        ));
+        let first = Endorsement::sign(&signers[0], &request, 1000);
+        assert_eq!(quorum.endorse(&request, first.clone(), 1000), Ok(None));
+        // The same operator endorsing twice does not count twice
+        assert_eq!(quorum.endorse(&request, first, 1010), Ok(None));
+        assert_eq!(quorum.pending().len(), 1);
+        assert_eq!(quorum.pending()[0].endorsements.len(), 1);
+        assert_eq!(
+            quorum.pending()[0].expires_at(Duration::from_secs(60)),
+            1060
+        );
+
+        let second = Endorsement::sign(&signers[2], &request, 1030);
+        let certificate = quorum.endorse(&request, second, 1030).unwrap().unwrap();
+        assert_eq!(quorum.sequence(), 1);
+        assert!(quorum.pending().is_empty());
+        assert_eq!(certificate.verify().unwrap().len(), 2);
+        assert!(certificate.verify_policy(quorum.policy()).is_ok());
+
+        // The certificate cannot be reassembled for the next sequence number
+        let replay = Endorsement::sign(&signers[1], &request, 1040);
+        assert_eq!(
+            quorum.endorse(&request, replay, 1040),
+            Err(ProvenanceError::BadEndorsement { sequence: 0 })
+        );
+
+        // Nor replayed on another deployment with the same operators, whose
+        // requests are bound to a different root key
+        let keys = signers.iter().map(ProvenanceVerifier::from).collect();
+        let policy = QuorumPolicy::new(2, keys, Duration::from_secs(60)).unwrap();
+        let mut elsewhere = Quorum::new(policy, "other-root", 0);
+        let foreign = Endorsement::sign(&signers[0], &request, 1040);
+        assert_eq!(
+            elsewhere.endorse(&request, foreign, 1040),
+            Err(ProvenanceError::BadEndorsement { sequence: 0 })
+        );
+        let mut rebound = certificate.clone();
+        rebound.request.root = "other-root".into();
+        assert!(rebound.verify().is_err());
+
+        // Stripping an endorsement or tampering with the command breaks it
+        let mut short = certificate.clone();
+        short.endorsements.pop();
+        assert!(matches!(
+            short.verify(),
+            Err(ProvenanceError::QuorumNotMet {
+                endorsements: 1,
+                ..
+            })
+        ));
+        let mut widened = certificate;
+        widened.request.command = OperatorCommand::SetBounds {
+            min: -5.0,
+            max: 5.0,
+        };
+        assert!(widened.verify().is_err());
+    }
+
+    #[test]
+    fn test_partial_approvals_expire() {
+        let signers = operators();
+        let mut quorum = quorum(&signers);
+        let request = quorum.request(OperatorCommand::ResetEscalation {
+            invariant: "lyapunov_decrease".into(),
+        });
+        let early = Endorsement::sign(&signers[0], &request, 1000);
+        quorum.endorse(&request, early, 1000).unwrap();
+        assert_eq!(quorum.expire(1060), 0);
+        assert_eq!(quorum.expire(1061), 1);
+        assert!(quorum.pending().is_empty());
+
+        // Stale or future-dated endorsements are refused outright
+        let stale = Endorsement::sign(&signers[1], &request, 1000);
+        assert_eq!(
+            quorum.endorse(&request, stale, 1100),
+            Err(ProvenanceError::EndorsementExpired { sequence: 0 })
+        );
+        let future = Endorsement::sign(&signers[1], &request, 2000);
+        assert!(quorum.endorse(&request, future, 1100).is_err());
+
+        // A late second signature cannot complete an expired request
+        let first = Endorsement::sign(&signers[0], &request, 1100);
+        quorum.endorse(&request, first, 1100).unwrap();
+        let late = Endorsement::sign(&signers[1], &request, 1200);
+        assert_eq!(quorum.endorse(&request, late, 1200), Ok(None));
+        assert_eq!(quorum.pending()[0].endorsements.len(), 1);
+        assert_eq!(quorum.sequence(), 0);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+use crate::error::{DeoxysError, Result};
+use crate::invariants::{
+    BoundAdherence, FiniteValues, Invariant, InvariantContext, LyapunovValidator, Verdict,
+};
+use crate::policy::{Escalation, EscalationState, InvariantPolicy};
+use crate::rik::EngineConfig;
+
+/// Verdict of one invariant within a registry pass
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvariantOutcome {
+    pub name: String,
+    pub verdict: Verdict,
+}
+
+/// Verdicts of every registered invariant, in registration order
+#[derive(Debug, Clone, Default)]
+pub struct RegistryReport {
+    pub outcomes: Vec<InvariantOutcome>,
+    /// Escalations latched after this pass, including earlier cycles'
+    pub escalations: Vec<Escalation>,
+}
+
+impl RegistryReport {
+    /// First failing invariant in evaluation order
+    pub fn first_failure(&self) -> Option<(&str, &DeoxysError)> {
+        self.failures().next()
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &DeoxysError)> {
+        self.outcomes.iter().filter_map(|o| match &o.verdict {
+            Verdict::Fail(error) => Some((o.name.as_str(), error)),
+            _ => None,
+        })
+    }
+
+    pub fn warnings(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.outcomes.iter().filter_map(|o| match &o.verdict {
+            Verdict::Warn(detail) => Some((o.name.as_str(), detail.as_str())),
+            _ => None,
+        })
+    }
+
+    pub fn passed(&self) -> bool {
+        self.first_failure().is_none()
+    }
+
+    /// Most severe latched escalation, which determines the engine response
+    pub fn action(&self) -> Option<&Escalation> {
+        self.escalations.iter().max_by_key(|e| e.action)
+    }
+}
+
+struct Entry {
+    invariant: Box<dyn Invariant>,
+    policy: InvariantPolicy,
+    escalation: EscalationState,
+}
+
+/// Ordered set of invariants evaluated once per cycle
+///
+/// Every invariant is evaluated on every pass, even after a failure, so that
+/// stateful invariants observe each cycle and the report is complete. Each
+/// invariant carries a failure policy; failures are debounced per invariant
+/// and escalations stay latched until `reset`.
+#[derive(Default)]
+pub struct InvariantRegistry {
+    entries: Vec<Entry>,
+}
+
+impl InvariantRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Built-in invariants for an engine configuration:
+    /// finite-values, the `LyapunovValidator` checks (energy-bound,
+    /// lyapunov-decrease, variance) and bound-adherence.
+    ///
+    /// `RateOfChange` is opt-in: a per-cycle slew limit depends on the plant
+    /// and sensors, and no default would be safe across deployments.
+    pub fn standard(config: &EngineConfig) -> Self {
+        Self::with_validator(LyapunovValidator::with_policy(config.decrease, config.entropy))
+    }
+
+    /// Built-in invariants around the checks of an existing validator
+    pub fn with_validator(validator: LyapunovValidator) -> Self {
+        let mut registry = Self::new();
+        let [energy, decrease, variance] = validator.into_invariants();
+        let builtins: [Box<dyn Invariant>; 5] =
+            [Box::new(FiniteValues), energy, decrease, variance, Box::new(BoundAdherence)];
+        for invariant in builtins {
+            registry
+                .register(invariant)
+                .expect("built-in invariant names are unique");
+        }
+        registry
+    }
+
+    /// Append an invariant with the default (fail-stop) policy
+    pub fn register(&mut self, invariant: Box<dyn Invariant>) -> Result<()> {
+        self.register_with_policy(invariant, InvariantPolicy::default())
+    }
+
+    /// Append an invariant; names must be unique within the registry
+    pub fn register_with_policy(
+        &mut self,
+        invariant: Box<dyn Invariant>,
+        policy: InvariantPolicy,
+    ) -> Result<()> {
+        if self.position(invariant.name()).is_some() {
+            return Err(DeoxysError::config(
+                "invariant registry",
+                format!("'{}' is already registered", invariant.name()),
+            ));
+        }
+        self.entries.push(Entry {
+            invariant,
+            policy,
+            escalation: EscalationState::default(),
+        });
+        Ok(())
+    }
+
+    /// Replace a registered invariant in place, keeping its slot, policy and escalation
+    pub fn replace(&mut self, invariant: Box<dyn Invariant>) -> Result<()> {
+        let index = self.require(invariant.name())?;
+        self.entries[index].invariant = invariant;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Box<dyn Invariant>> {
+        self.position(name)
+            .map(|index| self.entries.remove(index).invariant)
+    }
+
+    pub fn set_policy(&mut self, name: &str, policy: InvariantPolicy) -> Result<()> {
+        let index = self.require(name)?;
+        self.entries[index].policy = policy;
+        Ok(())
+    }
+
+    pub fn policy(&self, name: &str) -> Option<InvariantPolicy> {
+        self.position(name).map(|index| self.entries[index].policy)
+    }
+
+    /// Debounce history and latched escalation of the named invariant
+    pub fn escalation_state(&self, name: &str) -> Option<&EscalationState> {
+        self.position(name).map(|index| &self.entries[index].escalation)
+    }
+
+    /// All latched escalations, in registration order
+    pub fn escalations(&self) -> Vec<Escalation> {
+        self.entries
+            .iter()
+            .filter_map(|e| e.escalation.escalation().cloned())
+            .collect()
+    }
+
+    /// Clear the debounce history and latched escalation of the named invariant.
+    /// Callers are responsible for authorizing the reset.
+    pub fn reset(&mut self, name: &str) -> Result<()> {
+        let index = self.require(name)?;
+        self.entries[index].escalation.reset();
+        Ok(())
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.entries.iter().map(|e| e.invariant.name()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Whether the named invariant currently fails cycles on breach
+    pub fn is_enforcing(&self, name: &str) -> Option<bool> {
+        self.position(name)
+            .map(|index| self.entries[index].invariant.enforcing())
+    }
+
+    pub fn evaluate(&mut self, ctx: &InvariantContext) -> RegistryReport {
+        let outcomes = self
+            .entries
+            .iter_mut()
+            .map(|entry| {
+                let name = entry.invariant.name().to_string();
+                let verdict = entry.invariant.evaluate(ctx);
+                let failure = match &verdict {
+                    Verdict::Fail(error) => Some(error),
+                    _ => None,
+                };
+                entry
+                    .escalation
+                    .record(&name, &entry.policy, ctx.cycle, failure);
+                InvariantOutcome { name, verdict }
+            })
+            .collect();
+        RegistryReport {
+            outcomes,
+            escalations: self.escalations(),
+        }
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries.iter().position(|e| e.invariant.name() == name)
+    }
+
+    fn require(&self, name: &str) -> Result<usize> {
+        self.position(name).ok_or_else(|| {
+            DeoxysError::config(
+                "invariant registry",
+                format!("'{}' is not registered", name),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{Debounce, FailurePolicy};
+    use crate::rik::OperatorBounds;
+    use ndarray::{Array1, Array2};
+    use std::collections::VecDeque;
+
+    fn breach(detail: &str) -> Verdict {
+        Verdict::Fail(DeoxysError::InvariantViolation {
+            invariant: "fixed".to_string(),
+            cycle: 1,
+            detail: detail.to_string(),
+        })
+    }
+
+    struct Fixed(&'static str, Verdict);
+
+    impl Invariant for Fixed {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn evaluate(&mut self, _ctx: &InvariantContext) -> Verdict {
+            self.1.clone()
+        }
+    }
+
+    fn evaluate(registry: &mut InvariantRegistry) -> RegistryReport {
+        let state = Array1::from_vec(vec![0.1; 4]);
+        let covariance = Array2::eye(4);
+        let history = VecDeque::new();
+        registry.evaluate(&InvariantContext {
+            cycle: 1,
+            state: &state,
+            control: &state,
+            covariance: &covariance,
+            history: &history,
+            bounds: OperatorBounds::default(),
+        })
+    }
+
+    #[test]
+    fn test_standard_registry_order() {
+        let registry = InvariantRegistry::standard(&EngineConfig::default());
+        assert_eq!(
+            registry.names(),
+            vec![
+                "finite-values",
+                "energy-bound",
+                "lyapunov-decrease",
+                "variance",
+                "bound-adherence",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_names_rejected() {
+        let mut registry = InvariantRegistry::new();
+        assert!(registry.register(Box::new(FiniteValues)).is_ok());
+        assert!(registry.register(Box::new(FiniteValues)).is_err());
+        assert!(registry.replace(Box::new(BoundAdherence)).is_err());
+    }
+
+    #[test]
+    fn test_report_collects_all_verdicts_in_order() {
+        let mut registry = InvariantRegistry::new();
+        registry.register(Box::new(Fixed("a", Verdict::Warn("soft".into())))).unwrap();
+        registry.register(Box::new(Fixed("b", breach("first")))).unwrap();
+        registry.register(Box::new(Fixed("c", breach("second")))).unwrap();
+
+        let report = evaluate(&mut registry);
+        assert_eq!(report.outcomes.len(), 3);
+        let (name, error) = report.first_failure().unwrap();
+        assert_eq!(name, "b");
+        assert!(error.to_string().ends_with("first"));
+        assert_eq!(report.warnings().collect::<Vec<_>>(), vec![("a", "soft")]);
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn test_replace_keeps_slot() {
+        let mut registry = InvariantRegistry::new();
+        registry.register(Box::new(Fixed("a", Verdict::Pass))).unwrap();
+        registry.register(Box::new(Fixed("b", Verdict::Pass))).unwrap();
+        registry.replace(Box::new(Fixed("a", breach("now")))).unwrap();
+
+        assert_eq!(registry.names(), vec!["a", "b"]);
+        let report = evaluate(&mut registry);
+        assert_eq!(report.first_failure().map(|(name, _)| name), Some("a"));
+        assert!(registry.remove("a").is_some());
+        assert_eq!(registry.names(), vec!["b"]);
+    }
+
+    #[test]
+    fn test_debounced_failures_escalate_per_policy() {
+        let mut registry = InvariantRegistry::new();
+        let debounce = Debounce::Window { n: 2, m: 3 };
+        let policy = InvariantPolicy::new(FailurePolicy::SafeState, debounce).unwrap();
+        registry
+            .register_with_policy(Box::new(Fixed("noisy", breach("spike"))), policy)
+            .unwrap();
+        registry.register(Box::new(Fixed("steady", Verdict::Pass))).unwrap();
+
+        let first = evaluate(&mut registry);
+        assert!(!first.passed());
+        assert!(first.action().is_none());
+        assert_eq!(registry.escalation_state("noisy").unwrap().recent_failures(), 1);
+
+        let second = evaluate(&mut registry);
+        let escalation = second.action().unwrap();
+        assert_eq!(
+            (escalation.invariant.as_str(), escalation.action),
+            ("noisy", FailurePolicy::SafeState)
+        );
+
+        registry.reset("noisy").unwrap();
+        assert!(registry.escalations().is_empty());
+        assert!(registry.reset("missing").is_err());
+    }
+
+    #[test]
+    fn test_most_severe_escalation_wins() {
+        let mut registry = InvariantRegistry::new();
+        let warn = InvariantPolicy::new(FailurePolicy::Warn, Debounce::Immediate).unwrap();
+        let lockdown = InvariantPolicy::new(FailurePolicy::Lockdown, Debounce::Immediate).unwrap();
+        registry.register_with_policy(Box::new(Fixed("a", breach("x"))), warn).unwrap();
+        registry.register_with_policy(Box::new(Fixed("b", breach("y"))), lockdown).unwrap();
+
+        let report = evaluate(&mut registry);
+        assert_eq!(report.escalations.len(), 2);
+        assert_eq!(report.action().unwrap().action, FailurePolicy::Lockdown);
+    }
+}
@@ -3,18 +3,102 @@
 // SPDX-License-Identifier: Proprietary
 
 use crate::substrate::SovereignState;
-use crate::invariants::LyapunovValidator;
-use crate::crypto::{CkksProvider, ProvenanceSigner};
-use ndarray::Array1;
-use anyhow::Result;
-use log::info;
+use crate::approval::{Decision, Proposal, Ruling};
+use crate::audit::{AuditBundle, ConfigSnapshot, QuorumSnapshot};
+use crate::canonical::{Domain, Encoder};
+use crate::invariants::{
+    DecreaseCondition, EnergyBound, EntropyPolicy, Invariant, InvariantContext, LyapunovDecrease,
+    LyapunovValidator, VarianceBound,
+};
+use crate::policy::{Escalation, FailurePolicy, InvariantPolicy};
+use crate::registry::InvariantRegistry;
+use crate::control::{LinearPlanner, PlantModel};
+use crate::roa::{RoaAnalysis, RoaReport};
+use crate::stability::{StabilityPolicy, StabilityReport, SAMPLE_PERIOD};
+use crate::crypto::{
+    state_digest, CkksProvider, ProvenanceError, ProvenanceSigner, ProvenanceVerifier,
+    ReceiptRecord,
+};
+use crate::error::{DeoxysError, Result};
+use crate::ledger::ProvenanceLog;
+use crate::merkle::EpochTree;
+use crate::quorum::{
+    CommandRequest, Endorsement, GovernanceRecord, OperatorCommand, Quorum, QuorumCertificate,
+    QuorumPolicy,
+};
+use crate::rotation::{RevocationRecord, RotationRecord};
+use ndarray::{Array1, Array2};
+use log::{info, warn};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Committed belief states retained for history-aware invariants
+pub const HISTORY_CAPACITY: usize = 64;
+/// Per-cycle process noise variance of the simplified Kalman model
+const PROCESS_NOISE: f64 = 1e-4;
+/// Observation noise variance of the simplified Kalman model
+const MEASUREMENT_NOISE: f64 = 1e-2;
+/// Per-channel level the deterministic stub observation settles the state at
+const STUB_SETPOINT: f64 = 0.02;
+/// Fraction of the distance to `STUB_SETPOINT` the stub observation reports
+const STUB_GAIN: f64 = 0.5;
+
+#[derive(Debug, Clone)]
 pub struct CycleReceipt {
+    /// Ed25519 signature over the cycle index, `previous`, `approval`, `mode`,
+    /// `ckks_parameters`, `entropy_policy` and `state_digest`
     pub hash: String,
+    pub cycle: u64,
+    /// Entropy policy in force for this cycle
+    pub entropy_policy: EntropyPolicy,
+    /// Whether strict entropy enforcement was active (warm-up complete)
+    pub entropy_enforced: bool,
+    /// Invariant warnings raised during the cycle, as `name: detail`
+    pub warnings: Vec<String>,
+    pub mode: CycleMode,
+    /// Invariant failures absorbed by debouncing or a non-halting policy
+    pub violations: Vec<DeoxysError>,
+    /// Escalations latched at the end of the cycle
+    pub escalations: Vec<Escalation>,
+    /// CKKS parameter set id the belief state is encrypted under
+    pub ckks_parameters: String,
+    /// Fingerprint of the key that signed `hash`
+    pub signer: String,
+    /// SHA-256 of the committed belief state
+    pub state_digest: String,
+    /// `hash` of the preceding receipt of this run; `None` for the first cycle
+    pub previous: Option<String>,
+    /// Operator approval the cycle ran under, when supervised
+    pub approval: Option<Decision>,
+}
+
+/// How the cycle's control output was produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CycleMode {
+    Nominal,
+    /// A `Degrade` escalation is latched: A2A exchange suspended
+    Degraded,
+    /// A `SafeState` escalation is latched: control replaced by the safe state
+    SafeState,
+}
+
+impl CycleMode {
+    /// Stable code used in canonical encodings
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Nominal => 0,
+            Self::Degraded => 1,
+            Self::SafeState => 2,
+        }
+    }
 }
 
 /// Operator-specified bounds for output control
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct OperatorBounds {
     pub min: f64,
     pub max: f64,
@@ -23,74 +107,827 @@ pub struct OperatorBounds {
 impl OperatorBounds {
     pub fn new(min: f64, max: f64) -> Result<Self> {
         if min >= max {
-            anyhow::bail!("Invalid bounds: min ({}) must be less than max ({})", min, max);
+            return Err(DeoxysError::config(
+                "bounds",
+                format!("min ({}) must be less than max ({})", min, max),
+            ));
         }
         Ok(Self { min, max })
     }
-    
+
+    /// Canonical digest of an operator command applying these bounds
+    pub fn command_digest(&self) -> [u8; 32] {
+        OperatorCommand::SetBounds { min: self.min, max: self.max }.digest()
+    }
+
+    /// Whether either bound extends beyond `current`
+    pub fn widens(&self, current: &OperatorBounds) -> bool {
+        self.min < current.min || self.max > current.max
+    }
+}
+
+impl Default for OperatorBounds {
     /// Default bounds for backward compatibility
-    pub fn default() -> Self {
+    fn default() -> Self {
         Self { min: -1.0, max: 1.0 }
     }
 }
 
+/// Engine configuration: output bounds, Zero Entropy Law enforcement and
+/// the response to defective control law designs
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EngineConfig {
+    pub bounds: OperatorBounds,
+    pub decrease: DecreaseCondition,
+    pub entropy: EntropyPolicy,
+    pub stability: StabilityPolicy,
+}
+
 pub struct RikEngine {
     state: SovereignState,
-    validator: LyapunovValidator,
+    invariants: InvariantRegistry,
     ckks: CkksProvider,
     signer: ProvenanceSigner,
     belief_state: Array1<f64>,
-    operator_bounds: OperatorBounds,
+    covariance: Array2<f64>,
+    history: VecDeque<Array1<f64>>,
+    /// Closed loop the energy bound was derived from; analysis only
+    design_model: Option<(PlantModel, LinearPlanner)>,
+    /// Wire envelope of the last committed state, for A2A exchange
+    encrypted_state: Option<Vec<u8>>,
+    /// Receipt of the last cycle, linked from the next one
+    last_receipt: Option<CycleReceipt>,
+    /// Append-only audit trail of receipts, when configured
+    log: Option<ProvenanceLog>,
+    /// Merkle commitments over receipts, signed once per epoch
+    epochs: Option<EpochTree>,
+    /// Operator keys whose signed approval gates every cycle; empty when unsupervised
+    operators: Vec<ProvenanceVerifier>,
+    /// Verified approval of the current proposal
+    approval: Option<Decision>,
+    /// Random value bound into every proposal of this run, so a decision
+    /// cannot be replayed after a restart
+    run_nonce: [u8; 32],
+    /// Operator quorum guarding high-consequence changes, when configured
+    quorum: Option<Quorum>,
+    /// Incoming signing key certified by the quorum, hex
+    certified_rotation: Option<String>,
+    config: EngineConfig,
+    cycle_count: u64,
 }
 
 impl RikEngine {
     pub fn new(state: SovereignState) -> Self {
+        Self::with_config(state, EngineConfig::default())
+    }
+
+    /// Engine whose energy bound is set to the level of a closed-loop
+    /// design's verified region of attraction, see `set_design_model`
+    pub fn with_design_model(
+        state: SovereignState,
+        config: EngineConfig,
+        plant: PlantModel,
+        planner: LinearPlanner,
+    ) -> Result<Self> {
+        let mut engine = Self::with_config(state, config);
+        engine.set_design_model(plant, planner)?;
+        Ok(engine)
+    }
+
+    pub fn with_config(state: SovereignState, config: EngineConfig) -> Self {
         Self {
             state,
-            validator: LyapunovValidator::new(),
+            invariants: InvariantRegistry::standard(&config),
             ckks: CkksProvider::init(),
             signer: ProvenanceSigner::new(),
             belief_state: Array1::zeros(10), // 10-dim state vector
-            operator_bounds: OperatorBounds::default(),
+            covariance: Array2::eye(10) * MEASUREMENT_NOISE,
+            history: VecDeque::with_capacity(HISTORY_CAPACITY),
+            design_model: None,
+            encrypted_state: None,
+            last_receipt: None,
+            log: None,
+            epochs: None,
+            operators: Vec::new(),
+            approval: None,
+            run_nonce: {
+                let mut nonce = [0u8; 32];
+                OsRng.fill_bytes(&mut nonce);
+                nonce
+            },
+            quorum: None,
+            certified_rotation: None,
+            config,
+            cycle_count: 0,
+        }
+    }
+
+    /// Append an invariant to the end of the evaluation order
+    pub fn register_invariant(&mut self, invariant: Box<dyn Invariant>) -> Result<()> {
+        self.register_invariant_with_policy(invariant, InvariantPolicy::default())
+    }
+
+    pub fn register_invariant_with_policy(
+        &mut self,
+        invariant: Box<dyn Invariant>,
+        policy: InvariantPolicy,
+    ) -> Result<()> {
+        info!("   -> Invariant registered: {} ({:?})", invariant.name(), policy);
+        self.invariants.register_with_policy(invariant, policy)
+    }
+
+    /// Set the failure policy and debouncing of a registered invariant
+    pub fn set_invariant_policy(&mut self, name: &str, policy: InvariantPolicy) -> Result<()> {
+        info!("   -> Invariant policy updated: {} ({:?})", name, policy);
+        self.invariants.set_policy(name, policy)
+    }
+
+    /// Proposal an authorized operator signs to reset the escalation of `name`
+    /// while the engine stands at its current cycle
+    pub fn reset_proposal(&self, name: &str) -> Proposal {
+        let command = OperatorCommand::ResetEscalation { invariant: name.to_string() };
+        let action = Encoder::new(Domain::ResetProposal)
+            .u64(self.cycle_count)
+            .bytes(&command.digest());
+        Proposal { cycle: self.cycle_count, action: self.bind_to_run(action) }
+    }
+
+    /// Clear a latched escalation on an authorized operator's signed approval
+    /// of `reset_proposal(name)`, appended to the provenance log first. Under
+    /// a quorum policy a lockdown or halt is only cleared by a quorum
+    /// certificate, see `endorse_command`.
+    pub fn reset_escalation(&mut self, name: &str, decision: &Decision) -> Result<()> {
+        let locked = self
+            .invariants
+            .escalations()
+            .iter()
+            .any(|e| e.invariant == name && e.action >= FailurePolicy::Lockdown);
+        if locked && self.quorum.is_some() {
+            return Err(DeoxysError::QuorumRequired { action: "Reset from lockdown" });
         }
+        let operator = self.authorized(decision)?;
+        if !decision.answers(&self.reset_proposal(name)) {
+            return Err(ProvenanceError::StaleDecision { cycle: decision.cycle }.into());
+        }
+        if decision.ruling != Ruling::Approve {
+            return Err(ProvenanceError::BadDecision { cycle: decision.cycle }.into());
+        }
+        if let Some(log) = &mut self.log {
+            log.append_decision(decision)?;
+        }
+        self.invariants.reset(name)?;
+        info!("   -> Escalation of '{}' reset by operator {}", name, operator.fingerprint());
+        Ok(())
+    }
+
+    pub fn invariants(&self) -> &InvariantRegistry {
+        &self.invariants
     }
 
-    /// Set operator-specified bounds for output control
-    pub fn set_operator_bounds(&mut self, bounds: OperatorBounds) {
+    pub fn config(&self) -> &EngineConfig {
+        &self.config
+    }
+
+    /// Set operator-specified bounds for output control. Under a quorum
+    /// policy only narrowing is allowed here; widening needs a certificate.
+    pub fn set_operator_bounds(&mut self, bounds: OperatorBounds) -> Result<()> {
+        if self.quorum.is_some() && bounds.widens(&self.config.bounds) {
+            return Err(DeoxysError::QuorumRequired { action: "Widening the operator bounds" });
+        }
         info!("   -> Operator bounds updated: [{}, {}]", bounds.min, bounds.max);
-        self.operator_bounds = bounds;
+        self.config.bounds = bounds;
+        Ok(())
+    }
+
+    /// Set the Lyapunov decrease condition enforced between consecutive cycles
+    pub fn set_decrease_condition(&mut self, decrease: DecreaseCondition) {
+        info!(
+            "   -> Lyapunov decrease updated: rate={}, terminal={}, tolerance={}",
+            decrease.rate, decrease.terminal_energy, decrease.tolerance
+        );
+        self.config.decrease = decrease;
+        self.invariants
+            .replace(Box::new(LyapunovDecrease::new(decrease)))
+            .unwrap_or_else(|e| warn!("!! {}", e));
+    }
+
+    /// Override the V(x) limit of the energy-bound invariant
+    pub fn set_energy_limit(&mut self, limit: f64) -> Result<()> {
+        let bound = EnergyBound::new(limit)?;
+        info!("   -> Energy limit updated: V(x) <= {}", limit);
+        self.invariants.replace(Box::new(bound))
+    }
+
+    /// Analyse a plant model and feedback gain as the design the engine is
+    /// certified against. The closed loop is analysed at the RIK sample rate
+    /// first; unstable or unobservable designs are refused or only logged, per
+    /// `EngineConfig::stability`. The energy bound is then set to the level of
+    /// the loop's verified region of attraction within the operator bounds.
+    ///
+    /// This is analysis only: cycles still commit the clamped belief update
+    /// and never run `planner`, so the plant need not match the state size.
+    pub fn set_design_model(
+        &mut self,
+        plant: PlantModel,
+        planner: LinearPlanner,
+    ) -> Result<StabilityReport> {
+        let report = StabilityReport::analyze(&plant, &planner, SAMPLE_PERIOD)?;
+        info!("   -> Control law stability report: {}", report.to_json());
+        if !report.controllable() {
+            warn!(
+                "!! Control law: controllability rank {} < {}",
+                report.controllability_rank, report.states
+            );
+        }
+        let mut defects = report.defects();
+        let roa = self.region_of_attraction(&plant, &planner);
+        let validator = roa.and_then(|roa| {
+            info!(
+                "   -> Region of attraction: {}",
+                serde_json::to_string(&roa).unwrap_or_default()
+            );
+            LyapunovValidator::with_policy(self.config.decrease, self.config.entropy)
+                .with_region_of_attraction(&roa)
+        });
+        let validator = match validator {
+            Ok(validator) => Some(validator),
+            Err(e) => {
+                defects.push(e.to_string());
+                None
+            }
+        };
+        if !defects.is_empty() {
+            if self.config.stability == StabilityPolicy::Refuse {
+                return Err(DeoxysError::config("control law", defects.join("; ")));
+            }
+            for defect in &defects {
+                warn!("!! Control law accepted despite defect: {}", defect);
+            }
+        }
+        if let Some(validator) = validator {
+            info!(
+                "   -> Energy limit from region of attraction: V(x) <= {}",
+                validator.energy_limit()
+            );
+            let [energy, ..] = validator.into_invariants();
+            self.invariants.replace(energy)?;
+        }
+        self.design_model = Some((plant, planner));
+        Ok(report)
+    }
+
+    /// Region of attraction of the saturated loop inside the operator bounds,
+    /// under the engine's decrease condition
+    fn region_of_attraction(
+        &self,
+        plant: &PlantModel,
+        planner: &LinearPlanner,
+    ) -> Result<RoaReport> {
+        let bounds = self.config.bounds;
+        Ok(RoaAnalysis::new(plant.clone(), planner.clone(), bounds, bounds)?
+            .with_decrease(self.config.decrease)?
+            .run())
+    }
+
+    pub fn design_model(&self) -> Option<(&PlantModel, &LinearPlanner)> {
+        self.design_model.as_ref().map(|(plant, planner)| (plant, planner))
+    }
+
+    /// Replace the CKKS provider, e.g. with loaded keys or another parameter
+    /// set; only named parameter sets can be exchanged on the wire
+    pub fn set_ckks_provider(&mut self, provider: CkksProvider) -> Result<()> {
+        let params = provider.context().params();
+        if params.parameter_set().is_none() {
+            return Err(DeoxysError::config(
+                "ckks provider",
+                format!("{} is not a named parameter set", params.id()),
+            ));
+        }
+        info!("   -> CKKS parameters: {}", params.id());
+        self.ckks = provider;
+        Ok(())
+    }
+
+    pub fn ckks(&self) -> &CkksProvider {
+        &self.ckks
+    }
+
+    /// Replace the ephemeral receipt signer with a persistent key, before a
+    /// provenance log or quorum policy is set. From then on the key only
+    /// changes through [`Self::rotate_signer`], which leaves a rotation
+    /// record behind and, under a quorum, needs its certificate.
+    pub fn set_signer(&mut self, signer: ProvenanceSigner) -> Result<()> {
+        if self.log.is_some() || self.quorum.is_some() {
+            return Err(DeoxysError::config(
+                "provenance signer",
+                "a provenance log or quorum policy is set; use a key rotation",
+            ));
+        }
+        info!("   -> Provenance signer: {}", signer.fingerprint());
+        self.signer = signer;
+        Ok(())
+    }
+
+    pub fn signer(&self) -> &ProvenanceSigner {
+        &self.signer
+    }
+
+    /// Hand receipt signing over to `next`. The open epoch is sealed with the
+    /// outgoing key, then a rotation record signed by both keys is appended
+    /// to the provenance log, which is required. Under a quorum policy the
+    /// incoming key must have been certified by the operators.
+    pub fn rotate_signer(&mut self, next: ProvenanceSigner) -> Result<RotationRecord> {
+        let log = self
+            .log
+            .as_mut()
+            .ok_or_else(|| DeoxysError::config("key rotation", "requires a provenance log"))?;
+        if self.quorum.is_some() {
+            let incoming = hex::encode(next.verifying_key().to_bytes());
+            if self.certified_rotation.as_deref() != Some(incoming.as_str()) {
+                return Err(DeoxysError::QuorumRequired { action: "Key rotation" });
+            }
+        }
+        let head = log.head();
+        if let Some(epoch) = self.epochs.as_mut().and_then(|e| e.seal(&self.signer, Some(head))) {
+            log.append_epoch(epoch)?;
+        }
+        let rotation = RotationRecord::new(log.rotations(), self.cycle_count, &self.signer, &next);
+        log.append_rotation(&rotation)?;
+        info!(
+            "   -> Provenance key rotated after cycle {}: {} -> {}",
+            self.cycle_count,
+            self.signer.fingerprint(),
+            next.fingerprint()
+        );
+        self.signer = next;
+        self.certified_rotation = None;
+        Ok(rotation)
+    }
+
+    /// Record, under the current key, that `fingerprint` is compromised for
+    /// receipts from cycle `effective_from` onwards
+    pub fn revoke_key(
+        &mut self,
+        fingerprint: &str,
+        effective_from: u64,
+        reason: &str,
+    ) -> Result<RevocationRecord> {
+        let log = self
+            .log
+            .as_mut()
+            .ok_or_else(|| DeoxysError::config("key revocation", "requires a provenance log"))?;
+        let revocation = RevocationRecord::new(&self.signer, fingerprint, effective_from, reason);
+        log.append_revocation(&revocation)?;
+        warn!(
+            "   -> Provenance key {} revoked from cycle {}: {}",
+            fingerprint, effective_from, reason
+        );
+        Ok(revocation)
+    }
+
+    /// Append every subsequent receipt to `log` before it is returned. The
+    /// operators and quorum policy already configured are recorded first.
+    pub fn set_provenance_log(&mut self, log: ProvenanceLog) -> Result<()> {
+        info!("   -> Provenance log: {} ({} records)", log.path().display(), log.len());
+        self.log = Some(log);
+        if let Some(quorum) = self.quorum.take() {
+            let sequence = self.log.as_ref().map_or(0, ProvenanceLog::certificates);
+            let root = self.root_fingerprint();
+            self.quorum = Some(Quorum::new(quorum.policy().clone(), root, sequence));
+        }
+        if !self.operators.is_empty() || self.quorum.is_some() {
+            self.log_governance()?;
+        }
+        Ok(())
+    }
+
+    /// Fingerprint of the key the provenance log started with, which quorum
+    /// requests are bound to: the key of its first rotation, or the signer
+    /// while there has been none
+    fn root_fingerprint(&self) -> String {
+        self.log
+            .as_ref()
+            .and_then(ProvenanceLog::root)
+            .unwrap_or_else(|| self.signer.fingerprint())
+            .to_string()
+    }
+
+    /// Record the operators and quorum policy now in force, so an audit can
+    /// check later decisions and certificates against them
+    fn log_governance(&mut self) -> Result<()> {
+        if let Some(log) = &mut self.log {
+            let policy = self.quorum.as_ref().map(Quorum::policy);
+            log.append_governance(&GovernanceRecord::new(&self.signer, &self.operators, policy))?;
+        }
+        Ok(())
+    }
+
+    pub fn provenance_log(&self) -> Option<&ProvenanceLog> {
+        self.log.as_ref()
+    }
+
+    /// Commit receipts to a Merkle tree, signing its root every `epoch_length` cycles
+    pub fn enable_epochs(&mut self, epoch_length: u64) -> Result<()> {
+        self.epochs = Some(EpochTree::new(epoch_length)?);
+        info!("   -> Receipt epochs of {} cycles", epoch_length);
+        Ok(())
+    }
+
+    pub fn epochs(&self) -> Option<&EpochTree> {
+        self.epochs.as_ref()
+    }
+
+    pub fn last_receipt(&self) -> Option<&CycleReceipt> {
+        self.last_receipt.as_ref()
+    }
+
+    /// Configuration recorded in audit bundles
+    pub fn config_snapshot(&self) -> ConfigSnapshot {
+        let fingerprints = |keys: &[ProvenanceVerifier]| {
+            keys.iter().map(|key| key.fingerprint().to_string()).collect()
+        };
+        let config = &self.config;
+        ConfigSnapshot {
+            bounds: [config.bounds.min, config.bounds.max],
+            decrease: [
+                config.decrease.rate,
+                config.decrease.terminal_energy,
+                config.decrease.tolerance,
+            ],
+            entropy: format!("{:?}", config.entropy),
+            stability: format!("{:?}", config.stability),
+            ckks_parameters: self.ckks.context().params().id(),
+            epoch_length: self.epochs.as_ref().map_or(0, EpochTree::epoch_length),
+            operators: fingerprints(&self.operators),
+            quorum: self.quorum.as_ref().map(|quorum| QuorumSnapshot {
+                threshold: quorum.policy().threshold() as u64,
+                operators: fingerprints(quorum.policy().operators()),
+                window_secs: quorum.policy().window().as_secs(),
+            }),
+        }
+    }
+
+    /// Seal the open epoch and export cycles `first..=last` of this run as a
+    /// signed audit bundle. `root` is the provenance key the log started with.
+    pub fn export_audit(
+        &mut self,
+        first: u64,
+        last: u64,
+        root: &ProvenanceVerifier,
+    ) -> Result<AuditBundle> {
+        let config = self.config_snapshot();
+        let log = self
+            .log
+            .as_mut()
+            .ok_or_else(|| DeoxysError::config("audit export", "requires a provenance log"))?;
+        let epochs = self
+            .epochs
+            .as_mut()
+            .ok_or_else(|| DeoxysError::config("audit export", "requires receipt epochs"))?;
+        if let Some(epoch) = epochs.seal(&self.signer, Some(log.head())) {
+            log.append_epoch(epoch)?;
+        }
+        let records = ProvenanceLog::read(log.path())?;
+        let bundle =
+            AuditBundle::export(&records, epochs.tree(), first, last, root, config, &self.signer)?;
+        info!(
+            "   -> Audit bundle of cycles {}..={}: {} records, {} proofs",
+            first,
+            last,
+            bundle.records.len(),
+            bundle.proofs.len()
+        );
+        Ok(bundle)
+    }
+
+    /// Reserve bounds widening, lockdown resets and key rotation to a quorum
+    /// of operators. Numbering continues from the certificates in the log.
+    /// Once installed, the policy only changes through a certified
+    /// [`OperatorCommand::SetQuorumPolicy`], see `endorse_command`.
+    pub fn set_quorum_policy(&mut self, policy: QuorumPolicy) -> Result<()> {
+        if self.quorum.is_some() {
+            return Err(DeoxysError::QuorumRequired { action: "Quorum policy change" });
+        }
+        info!(
+            "   -> Quorum policy: {} of {} operators within {:?}",
+            policy.threshold(),
+            policy.operators().len(),
+            policy.window()
+        );
+        let sequence = self.log.as_ref().map_or(0, ProvenanceLog::certificates);
+        self.quorum = Some(Quorum::new(policy, self.root_fingerprint(), sequence));
+        self.log_governance()
+    }
+
+    /// Quorum policy, sequence number and partial approvals
+    pub fn quorum(&self) -> Option<&Quorum> {
+        self.quorum.as_ref()
+    }
+
+    /// Record an operator endorsement, signed now
+    pub fn endorse_command(
+        &mut self,
+        request: &CommandRequest,
+        endorsement: Endorsement,
+    ) -> Result<Option<QuorumCertificate>> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| DeoxysError::config("quorum", e.to_string()))?
+            .as_secs();
+        self.endorse_command_at(request, endorsement, now)
+    }
+
+    /// Record an operator endorsement at unix time `now`. When it completes
+    /// the quorum, the certificate is appended to the provenance log, which
+    /// is required, and the command takes effect.
+    pub fn endorse_command_at(
+        &mut self,
+        request: &CommandRequest,
+        endorsement: Endorsement,
+        now: u64,
+    ) -> Result<Option<QuorumCertificate>> {
+        if self.log.is_none() {
+            return Err(DeoxysError::config("quorum", "requires a provenance log"));
+        }
+        match &request.command {
+            OperatorCommand::SetBounds { min, max } => {
+                OperatorBounds::new(*min, *max)?;
+            }
+            OperatorCommand::ResetEscalation { invariant } => {
+                if !self.invariants.names().contains(&invariant.as_str()) {
+                    return Err(DeoxysError::config(
+                        "quorum",
+                        format!("no invariant named '{}'", invariant),
+                    ));
+                }
+            }
+            OperatorCommand::RotateSigner { to_key } => {
+                if ProvenanceVerifier::from_hex(to_key).is_none() {
+                    return Err(DeoxysError::config("quorum", "rotation key is not a public key"));
+                }
+            }
+            OperatorCommand::SetQuorumPolicy { terms } => {
+                QuorumPolicy::from_terms(terms)?;
+            }
+        }
+        let quorum = self
+            .quorum
+            .as_mut()
+            .ok_or_else(|| DeoxysError::config("quorum", "no quorum policy set"))?;
+        let Some(certificate) = quorum.endorse(request, endorsement, now)? else {
+            return Ok(None);
+        };
+        if let Some(log) = &mut self.log {
+            log.append_quorum(&certificate)?;
+        }
+        info!("   -> Quorum certificate {} assembled", certificate.request.sequence);
+        match &certificate.request.command {
+            OperatorCommand::SetBounds { min, max } => {
+                self.config.bounds = OperatorBounds::new(*min, *max)?;
+                info!("   -> Operator bounds widened by quorum: [{}, {}]", min, max);
+            }
+            OperatorCommand::ResetEscalation { invariant } => {
+                self.invariants.reset(invariant)?;
+                info!("   -> Escalation of '{}' reset by quorum", invariant);
+            }
+            OperatorCommand::RotateSigner { to_key } => {
+                self.certified_rotation = Some(to_key.clone());
+                info!("   -> Rotation to key {} certified by quorum", to_key);
+            }
+            OperatorCommand::SetQuorumPolicy { terms } => {
+                let policy = QuorumPolicy::from_terms(terms)?;
+                let sequence = self.quorum.as_ref().map_or(0, Quorum::sequence);
+                let root = self.root_fingerprint();
+                info!(
+                    "   -> Quorum policy replaced by quorum: {} of {} operators within {:?}",
+                    policy.threshold(),
+                    policy.operators().len(),
+                    policy.window()
+                );
+                self.quorum = Some(Quorum::new(policy, root, sequence));
+            }
+        }
+        Ok(Some(certificate))
+    }
+
+    /// Accept decisions signed by `operator`. Once any operator is
+    /// authorized, every cycle requires a signed approval.
+    pub fn authorize_operator(&mut self, operator: ProvenanceVerifier) -> Result<()> {
+        info!("   -> Operator authorized: {}", operator.fingerprint());
+        self.operators.push(operator);
+        self.log_governance()
+    }
+
+    /// The next cycle and a digest of the action it will take: its index,
+    /// the state it starts from, the operator bounds and the receipt it
+    /// will link to
+    pub fn proposal(&self) -> Proposal {
+        let cycle = self.cycle_count + 1;
+        let previous = self.last_receipt.as_ref().map(|receipt| receipt.hash.as_str());
+        let action = Encoder::new(Domain::Proposal)
+            .u64(cycle)
+            .bytes(&state_digest(&self.belief_state))
+            .bytes(&self.config.bounds.command_digest())
+            .option(previous, Encoder::str);
+        Proposal { cycle, action: self.bind_to_run(action) }
+    }
+
+    /// Close a proposal digest over the provenance key and the run nonce, so
+    /// its decision only answers this engine in this run
+    fn bind_to_run(&self, action: Encoder) -> [u8; 32] {
+        action
+            .str(self.signer.fingerprint())
+            .bytes(&self.run_nonce)
+            .digest()
+    }
+
+    /// Verify an operator decision on the current proposal. An approval
+    /// unlocks the next cycle and is embedded in its receipt; a denial is
+    /// appended to the provenance log and leaves the cycle locked. Without a
+    /// log a denial still locks the cycle but is reported as an error, since
+    /// it would leave no trace.
+    pub fn submit_decision(&mut self, decision: Decision) -> Result<()> {
+        let operator = self.authorized(&decision)?;
+        if !decision.answers(&self.proposal()) {
+            return Err(ProvenanceError::StaleDecision { cycle: decision.cycle }.into());
+        }
+        match decision.ruling {
+            Ruling::Approve => {
+                info!("   -> Cycle {} approved by {}", decision.cycle, operator.fingerprint());
+                self.approval = Some(decision);
+            }
+            Ruling::Deny => {
+                warn!("   -> Cycle {} denied by {}", decision.cycle, operator.fingerprint());
+                self.approval = None;
+                self.log
+                    .as_mut()
+                    .ok_or_else(|| {
+                        DeoxysError::config("operator denial", "requires a provenance log")
+                    })?
+                    .append_decision(&decision)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Verify a decision's signature and that its key is an authorized operator
+    fn authorized(&self, decision: &Decision) -> Result<ProvenanceVerifier> {
+        let operator = decision.verify()?;
+        if !self.operators.iter().any(|key| key.fingerprint() == operator.fingerprint()) {
+            return Err(ProvenanceError::UnauthorizedOperator {
+                fingerprint: operator.fingerprint().to_string(),
+            }
+            .into());
+        }
+        Ok(operator)
+    }
+
+    /// Verify the last receipt against the signer's public key and the
+    /// committed belief state; trivially true before the first cycle
+    pub fn verify_provenance(&self) -> Result<()> {
+        match &self.last_receipt {
+            Some(receipt) => {
+                let verifier = ProvenanceVerifier::from(&self.signer);
+                Ok(verifier.verify_state(&receipt.into(), &self.belief_state)?)
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Wire envelope of the state committed by the last cycle; `None` before
+    /// the first cycle and while degraded
+    pub fn encrypted_state(&self) -> Option<&[u8]> {
+        self.encrypted_state.as_deref()
+    }
+
+    /// Set the entropy policy; strict mode warm-up restarts from the next cycle
+    pub fn set_entropy_policy(&mut self, entropy: EntropyPolicy) {
+        info!(
+            "   -> Entropy policy updated: mode={:?}, threshold={}, warm-up={:?}",
+            entropy.mode, entropy.threshold, entropy.warm_up
+        );
+        self.config.entropy = entropy;
+        self.invariants
+            .replace(Box::new(VarianceBound::new(entropy)))
+            .unwrap_or_else(|e| warn!("!! {}", e));
+    }
+
+    /// Belief state committed by the last cycle
+    pub fn state(&self) -> &Array1<f64> {
+        &self.belief_state
+    }
+
+    /// Cycles executed so far
+    pub fn cycle_count(&self) -> u64 {
+        self.cycle_count
     }
 
     pub async fn execute_cycle(&mut self) -> Result<CycleReceipt> {
+        // 1. OBSERVE (Simulated deterministic input for core logic proof)
+        let observation = self.observe_environment();
+        self.execute_cycle_with(observation).await
+    }
+
+    /// Run one cycle on an externally supplied observation, e.g. over FFI
+    pub async fn execute_cycle_with(&mut self, observation: Array1<f64>) -> Result<CycleReceipt> {
         // Verify sovereign state integrity at cycle start
         if !self.state.verify_integrity() {
-            anyhow::bail!("Sovereign state integrity violation detected");
+            return Err(DeoxysError::IntegrityViolation {
+                reason: "root signature mismatch at cycle start".to_string(),
+            });
         }
 
-        // 1. OBSERVE (Simulated deterministic input for core logic proof)
-        let observation = self.observe_environment();
+        // Latched lockdown/halt escalations refuse every cycle until reset
+        if let Some(blocking) = self
+            .invariants
+            .escalations()
+            .into_iter()
+            .find(|e| e.action >= FailurePolicy::Lockdown)
+        {
+            return Err(Self::lockdown(blocking));
+        }
+
+        if observation.len() != self.belief_state.len() {
+            return Err(DeoxysError::DimensionMismatch {
+                context: "observation",
+                expected: self.belief_state.len(),
+                actual: observation.len(),
+            });
+        }
+
+        // Supervised cycles run only under an approval of this exact proposal,
+        // consumed only once nothing else can refuse the cycle
+        let approval = if self.operators.is_empty() {
+            None
+        } else {
+            let proposal = self.proposal();
+            if !self.approval.as_ref().is_some_and(|a| a.answers(&proposal)) {
+                return Err(DeoxysError::ApprovalRequired { cycle: proposal.cycle });
+            }
+            self.approval.take()
+        };
+        self.cycle_count += 1;
 
         // 2. BAYES UPDATE
         self.belief_state = &self.belief_state + &observation; // Simplified Kalman update
+        self.update_covariance();
 
         // 3. STATE ESTIMATE & 4. PLANNER PROPOSE (Fused)
         // 5. ACTUATOR MAP
-        // 6. MINIMIZE LAGRANGIAN (Enforced by Validator)
-        self.validator.check_stability(&self.belief_state)?;
+        // 6. MINIMIZE LAGRANGIAN & 7. SAFETY PROJECT (Clamp values to operator-specified bounds)
+        let bounds = self.config.bounds;
+        let mut control = self.belief_state.mapv(|x| x.clamp(bounds.min, bounds.max));
 
-        // 7. SAFETY PROJECT (Clamp values to operator-specified bounds)
-        let bounds = self.operator_bounds;
-        self.belief_state.mapv_inplace(|x| x.clamp(bounds.min, bounds.max));
-        
-        // Verify all outputs are strictly bounded by operator's intent
-        for &val in self.belief_state.iter() {
-            if val < bounds.min || val > bounds.max {
-                anyhow::bail!(
-                    "Output violation: value {} exceeds operator bounds [{}, {}]",
-                    val, bounds.min, bounds.max
-                );
+        // Invariant gate: every registered invariant, in registration order
+        let report = self.invariants.evaluate(&InvariantContext {
+            cycle: self.cycle_count,
+            state: &self.belief_state,
+            control: &control,
+            covariance: &self.covariance,
+            history: &self.history,
+            bounds,
+        });
+        let mode = match report.action() {
+            Some(escalation) if escalation.action == FailurePolicy::Halt => {
+                // Fail-stop: surface the breach itself in the escalating cycle
+                return Err(if escalation.cycle == self.cycle_count {
+                    escalation.error.clone()
+                } else {
+                    Self::lockdown(escalation.clone())
+                });
+            }
+            Some(escalation) if escalation.action == FailurePolicy::Lockdown => {
+                return Err(Self::lockdown(escalation.clone()));
+            }
+            Some(escalation) if escalation.action == FailurePolicy::SafeState => {
+                warn!("   -> Safe state engaged by '{}'", escalation.invariant);
+                control = Self::safe_state(control.len(), bounds);
+                CycleMode::SafeState
+            }
+            Some(escalation) if escalation.action == FailurePolicy::Degrade => {
+                warn!("   -> Degraded operation due to '{}'", escalation.invariant);
+                CycleMode::Degraded
             }
+            _ => CycleMode::Nominal,
+        };
+        let violations: Vec<DeoxysError> = report.failures().map(|(_, e)| e.clone()).collect();
+        for violation in &violations {
+            warn!("   -> Invariant violation absorbed by policy: {}", violation);
         }
+        let warnings: Vec<String> = report
+            .warnings()
+            .map(|(name, detail)| format!("{}: {}", name, detail))
+            .collect();
+        for warning in &warnings {
+            warn!("   -> Invariant warning: {}", warning);
+        }
+
+        // Commit the projected state
+        self.belief_state = control;
+        if self.history.len() == HISTORY_CAPACITY {
+            self.history.pop_front();
+        }
+        self.history.push_back(self.belief_state.clone());
 
         // 8. EXECUTE (GATED) -> Human approval required in main loop before this point
         // This step is now truly gated - execution only proceeds with explicit human approval
@@ -99,26 +936,86 @@ impl RikEngine {
         // 9. MEASURE
         // 10. UPDATE DUALS (Skipped in V2.0 MVP, implicit in clamp)
         
-        // 11. A2A/DFL (Encrypted State Exchange)
-        let _encrypted_state = self.ckks.encrypt_state(&self.belief_state);
+        // 11. A2A/DFL (Encrypted State Exchange, suspended while degraded)
+        self.encrypted_state = if mode != CycleMode::Degraded {
+            Some(self.ckks.seal_state(&self.belief_state)?)
+        } else {
+            None
+        };
 
         // 12. LOG PROVENANCE
-        let receipt_hash = self.signer.sign_cycle(&self.belief_state);
+        let mut receipt = CycleReceipt {
+            hash: String::new(),
+            cycle: self.cycle_count,
+            entropy_policy: self.config.entropy,
+            entropy_enforced: self.invariants.is_enforcing(VarianceBound::NAME).unwrap_or(false),
+            warnings,
+            mode,
+            violations,
+            escalations: report.escalations,
+            ckks_parameters: self.ckks.context().params().id(),
+            signer: String::new(),
+            state_digest: hex::encode(state_digest(&self.belief_state)),
+            previous: self.last_receipt.as_ref().map(|receipt| receipt.hash.clone()),
+            approval,
+        };
+        let mut record = ReceiptRecord::from(&receipt);
+        self.signer.sign_receipt(&mut record)?;
+        receipt.hash = record.hash.clone();
+        receipt.signer = record.signer.clone();
+        if let Some(log) = &mut self.log {
+            log.append_receipt(&record)?;
+        }
+        let head = self.log.as_ref().map(ProvenanceLog::head);
+        let epoch = self.epochs.as_mut().and_then(|e| e.push(&record, &self.signer, head));
+        if let Some(epoch) = epoch {
+            info!("   -> Epoch {} sealed at {} receipts", epoch.epoch, epoch.tree_size);
+            if let Some(log) = &mut self.log {
+                log.append_epoch(epoch)?;
+            }
+        }
+        self.last_receipt = Some(receipt.clone());
+        Ok(receipt)
+    }
+
+    fn lockdown(escalation: Escalation) -> DeoxysError {
+        DeoxysError::Lockdown {
+            invariant: escalation.invariant,
+            since_cycle: escalation.cycle,
+            cause: Box::new(escalation.error),
+        }
+    }
 
-        Ok(CycleReceipt { hash: receipt_hash })
+    /// Equilibrium projected into the operator bounds
+    fn safe_state(dim: usize, bounds: OperatorBounds) -> Array1<f64> {
+        Array1::zeros(dim).mapv(|x: f64| x.clamp(bounds.min, bounds.max))
+    }
+
+    /// Diagonal Kalman covariance: predict with process noise, correct with measurement noise
+    fn update_covariance(&mut self) {
+        self.covariance.diag_mut().mapv_inplace(|p| {
+            let predicted = p + PROCESS_NOISE;
+            let gain = predicted / (predicted + MEASUREMENT_NOISE);
+            (1.0 - gain) * predicted
+        });
     }
 
     fn observe_environment(&self) -> Array1<f64> {
         // In production, this reads from sensors/API. 
-        // Deterministic stub for stability testing (NO RANDOMNESS ALLOWED in Core Logic)
-        Array1::from_vec(vec![0.01; 10])
+        // Deterministic stub for stability testing (NO RANDOMNESS ALLOWED in Core Logic).
+        // It settles the state at V = 10 * 0.02^2 = 0.004, inside the default
+        // terminal neighbourhood, so the default engine runs indefinitely.
+        self.belief_state.mapv(|x| STUB_GAIN * (STUB_SETPOINT - x))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::invariants::{Verdict, WarmUp};
+    use crate::policy::Debounce;
     use crate::substrate::SovereignState;
+    use std::time::Duration;
 
     #[test]
     fn test_operator_bounds_validation() {
@@ -150,15 +1047,15 @@ mod tests {
         let mut engine = RikEngine::new(substrate);
         
         // Check default bounds
-        assert_eq!(engine.operator_bounds.min, -1.0);
-        assert_eq!(engine.operator_bounds.max, 1.0);
+        assert_eq!(engine.config.bounds.min, -1.0);
+        assert_eq!(engine.config.bounds.max, 1.0);
         
         // Set custom bounds
         let custom_bounds = OperatorBounds::new(-0.5, 0.5).unwrap();
-        engine.set_operator_bounds(custom_bounds);
+        engine.set_operator_bounds(custom_bounds).unwrap();
         
-        assert_eq!(engine.operator_bounds.min, -0.5);
-        assert_eq!(engine.operator_bounds.max, 0.5);
+        assert_eq!(engine.config.bounds.min, -0.5);
+        assert_eq!(engine.config.bounds.max, 0.5);
     }
 
     #[tokio::test]
@@ -168,7 +1065,7 @@ mod tests {
         
         // Set tight bounds
         let bounds = OperatorBounds::new(-0.5, 0.5).unwrap();
-        engine.set_operator_bounds(bounds);
+        engine.set_operator_bounds(bounds).unwrap();
         
         // Execute cycle and verify it completes without error
         let result = engine.execute_cycle().await;
@@ -176,7 +1073,7 @@ mod tests {
         
         // Verify all values in belief_state are within bounds
         for &val in engine.belief_state.iter() {
-            assert!(val >= -0.5 && val <= 0.5, 
+            assert!((-0.5..=0.5).contains(&val),
                 "Value {} exceeds bounds [-0.5, 0.5]", val);
         }
     }
@@ -188,7 +1085,7 @@ mod tests {
         
         // Set wide bounds
         let bounds = OperatorBounds::new(-10.0, 10.0).unwrap();
-        engine.set_operator_bounds(bounds);
+        engine.set_operator_bounds(bounds).unwrap();
         
         // Execute cycle
         let result = engine.execute_cycle().await;
@@ -196,8 +1093,595 @@ mod tests {
         
         // Verify all values are within wide bounds
         for &val in engine.belief_state.iter() {
-            assert!(val >= -10.0 && val <= 10.0,
+            assert!((-10.0..=10.0).contains(&val),
                 "Value {} exceeds bounds [-10.0, 10.0]", val);
         }
     }
+
+    #[tokio::test]
+    async fn test_default_engine_settles() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+
+        // Past cycle 32, where a constant-drift stub breaches the unity energy bound
+        for _ in 0..40 {
+            assert!(engine.execute_cycle().await.is_ok());
+        }
+        assert!(engine.belief_state.iter().all(|&x| (x - STUB_SETPOINT).abs() < 1e-9));
+    }
+
+    #[tokio::test]
+    async fn test_cycle_enforces_lyapunov_decrease() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        // No terminal neighbourhood: V must fall from the first cycle on
+        engine.set_decrease_condition(DecreaseCondition::new(0.05, 0.0, 0.0).unwrap());
+
+        // The stub observation raises V from 0.001 to 0.00225 in cycle 2
+        engine.execute_cycle().await.unwrap();
+        let failure = engine.execute_cycle().await.err().unwrap().to_string();
+        assert!(failure.contains("Lyapunov Decrease Violation at cycle 2"), "{}", failure);
+    }
+
+    #[tokio::test]
+    async fn test_relaxed_decrease_condition() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        engine.set_decrease_condition(DecreaseCondition::new(0.0, 0.5, 0.0).unwrap());
+
+        for _ in 0..10 {
+            assert!(engine.execute_cycle().await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_receipt_records_entropy_policy() {
+        let policy = EntropyPolicy::strict(0.5, WarmUp::Cycles(1)).unwrap();
+        let config = EngineConfig { entropy: policy, ..EngineConfig::default() };
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::with_config(substrate, config);
+
+        let first = engine.execute_cycle().await.unwrap();
+        assert_eq!(first.cycle, 1);
+        assert_eq!(first.entropy_policy, policy);
+        assert!(!first.entropy_enforced);
+
+        let second = engine.execute_cycle().await.unwrap();
+        assert_eq!(second.cycle, 2);
+        assert!(second.entropy_enforced);
+        assert_eq!(second.ckks_parameters, "ckks-n8192-d2-128");
+
+        // The policy is part of the signed record: relaxing it breaks the signature
+        let verifier = ProvenanceVerifier::from(engine.signer());
+        let mut record = ReceiptRecord::from(&second);
+        assert_eq!(record.entropy_policy, policy);
+        assert!(verifier.verify_receipt(&record).is_ok());
+        record.entropy_policy = EntropyPolicy::default();
+        assert_eq!(
+            verifier.verify_receipt(&record),
+            Err(ProvenanceError::BadSignature { cycle: 2 })
+        );
+
+        let envelope = crate::wire::WireEnvelope::parse(engine.encrypted_state().unwrap()).unwrap();
+        assert_eq!(envelope.key_id, &engine.ckks().key_id());
+        let received = engine.ckks().open(engine.encrypted_state().unwrap()).unwrap();
+        let decrypted = engine.ckks().decrypt_state(&received, 10).unwrap();
+        assert!(decrypted
+            .iter()
+            .zip(engine.belief_state.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-6));
+    }
+
+    #[tokio::test]
+    async fn test_receipt_names_persistent_signer() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        let seed = [3u8; 32];
+        engine.set_signer(ProvenanceSigner::from_seed(&seed)).unwrap();
+
+        let receipt = engine.execute_cycle().await.unwrap();
+        assert_eq!(receipt.signer, ProvenanceSigner::from_seed(&seed).fingerprint());
+        assert_eq!(receipt.signer, engine.signer().fingerprint());
+        assert!(receipt.previous.is_none());
+        assert!(engine.verify_provenance().is_ok());
+
+        // Receipts verify offline, without the engine, against the public key alone
+        let mut receipts = vec![receipt];
+        for _ in 0..3 {
+            receipts.push(engine.execute_cycle().await.unwrap());
+        }
+        let verifier = ProvenanceVerifier::new(ProvenanceSigner::from_seed(&seed).verifying_key());
+        assert!(verifier.verify_chain(receipts.iter().map(ReceiptRecord::from)).is_ok());
+        let last = ReceiptRecord::from(&receipts[3]);
+        assert!(verifier.verify_state(&last, &engine.belief_state).is_ok());
+
+        engine.belief_state[0] += 1e-9;
+        let error = engine.verify_provenance().unwrap_err();
+        assert_eq!(error.code(), "provenance_failure");
+    }
+
+    #[tokio::test]
+    async fn test_receipts_logged_and_committed_in_epochs() {
+        let dir = std::env::temp_dir().join(format!("deoxys-rik-log-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("provenance.log");
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        engine.set_provenance_log(ProvenanceLog::open(&path).unwrap()).unwrap();
+        engine.enable_epochs(2).unwrap();
+
+        let mut receipts = Vec::new();
+        for _ in 0..4 {
+            receipts.push(ReceiptRecord::from(&engine.execute_cycle().await.unwrap()));
+        }
+        let verifier = ProvenanceVerifier::from(engine.signer());
+        assert_eq!(ProvenanceLog::verify(&path, Some(&verifier)).unwrap(), 6);
+
+        let epochs = engine.epochs().unwrap();
+        let proof = epochs.inclusion_proof(2, 1).unwrap();
+        let commitment = &epochs.epochs()[1];
+        assert!(commitment.verify_receipt(&verifier, &receipts[2], &proof).is_ok());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cycles_gated_by_signed_approvals() {
+        let dir = std::env::temp_dir().join(format!("deoxys-rik-approve-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("provenance.log");
+        let operator = ProvenanceSigner::from_seed(&[5; 32]);
+        let intruder = ProvenanceSigner::from_seed(&[6; 32]);
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        engine.set_provenance_log(ProvenanceLog::open(&path).unwrap()).unwrap();
+        engine.authorize_operator(ProvenanceVerifier::from(&operator)).unwrap();
+
+        let error = engine.execute_cycle().await.unwrap_err();
+        assert_eq!(error, DeoxysError::ApprovalRequired { cycle: 1 });
+        let proposal = engine.proposal();
+        let forged = Decision::sign(&intruder, &proposal, Ruling::Approve);
+        assert_eq!(engine.submit_decision(forged).unwrap_err().code(), "provenance_failure");
+
+        // A signed denial is logged after the operator's authorization
+        engine.submit_decision(Decision::sign(&operator, &proposal, Ruling::Deny)).unwrap();
+        assert!(engine.execute_cycle().await.is_err());
+        assert_eq!(engine.provenance_log().unwrap().len(), 2);
+
+        let approval = Decision::sign(&operator, &proposal, Ruling::Approve);
+        engine.submit_decision(approval.clone()).unwrap();
+
+        // A malformed observation is refused without spending the approval
+        let error = engine.execute_cycle_with(Array1::zeros(3)).await.unwrap_err();
+        assert_eq!(error.code(), "dimension_mismatch");
+        assert_eq!(engine.proposal(), proposal);
+        let receipt = engine.execute_cycle().await.unwrap();
+        assert_eq!(receipt.approval.as_ref(), Some(&approval));
+        assert!(engine.verify_provenance().is_ok());
+
+        // Approvals are single use and bound to their proposal
+        let error = engine.execute_cycle().await.unwrap_err();
+        assert_eq!(error, DeoxysError::ApprovalRequired { cycle: 2 });
+        let error = engine.submit_decision(approval).unwrap_err();
+        assert_eq!(error, ProvenanceError::StaleDecision { cycle: 1 }.into());
+
+        // Nor does a decision carry over to another run in the same state
+        let mut rerun = RikEngine::new(SovereignState::new("C_EQUALS_XNXALEXIS_ROOT"));
+        rerun.authorize_operator(ProvenanceVerifier::from(&operator)).unwrap();
+        let replayed = Decision::sign(&operator, &proposal, Ruling::Approve);
+        assert_eq!(rerun.proposal().cycle, proposal.cycle);
+        let error = rerun.submit_decision(replayed).unwrap_err();
+        assert_eq!(error, ProvenanceError::StaleDecision { cycle: 1 }.into());
+
+        // A denial with nowhere to record it is an error, and still locks the cycle
+        let approval = Decision::sign(&operator, &rerun.proposal(), Ruling::Approve);
+        rerun.submit_decision(approval).unwrap();
+        let denial = Decision::sign(&operator, &rerun.proposal(), Ruling::Deny);
+        assert_eq!(rerun.submit_decision(denial).unwrap_err().code(), "invalid_configuration");
+        let error = rerun.execute_cycle().await.unwrap_err();
+        assert_eq!(error, DeoxysError::ApprovalRequired { cycle: 1 });
+
+        let verifier = ProvenanceVerifier::from(engine.signer());
+        assert_eq!(ProvenanceLog::verify(&path, Some(&verifier)).unwrap(), 3);
+        let records = ProvenanceLog::read(&path).unwrap();
+        assert_eq!(records[0].governance().unwrap().operators.len(), 1);
+        assert_eq!(records[1].decision().unwrap().ruling, Ruling::Deny);
+        let mut stripped = records[2].receipt().unwrap();
+        stripped.approval = None;
+        assert_eq!(
+            verifier.verify_receipt(&stripped),
+            Err(ProvenanceError::BadSignature { cycle: 1 })
+        );
+
+        // An audit refuses a well-signed decision by a key never authorized
+        drop(engine);
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        log.append_decision(&Decision::sign(&intruder, &proposal, Ruling::Deny)).unwrap();
+        assert_eq!(
+            ProvenanceLog::verify(&path, Some(&verifier)).unwrap_err(),
+            ProvenanceError::UnauthorizedOperator {
+                fingerprint: intruder.fingerprint().to_string()
+            }
+            .into()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_rotation_keeps_history_verifiable() {
+        let dir = std::env::temp_dir().join(format!("deoxys-rik-rotate-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("provenance.log");
+        let root = ProvenanceSigner::from_seed(&[1; 32]);
+        let next = ProvenanceSigner::from_seed(&[2; 32]);
+        let root_key = ProvenanceVerifier::from(&root);
+        let mut engine = RikEngine::new(SovereignState::new("C_EQUALS_XNXALEXIS_ROOT"));
+        engine.set_decrease_condition(DecreaseCondition::new(0.0, 0.5, 0.0).unwrap());
+        engine.set_signer(ProvenanceSigner::from_seed(&[1; 32])).unwrap();
+        assert!(engine.rotate_signer(ProvenanceSigner::from_seed(&[2; 32])).is_err());
+        engine.set_provenance_log(ProvenanceLog::open(&path).unwrap()).unwrap();
+        assert!(engine.set_signer(ProvenanceSigner::from_seed(&[2; 32])).is_err());
+        engine.enable_epochs(2).unwrap();
+
+        for _ in 0..3 {
+            engine.execute_cycle().await.unwrap();
+        }
+        // Swapping the key without a rotation record would orphan the log
+        assert!(engine.set_signer(ProvenanceSigner::from_seed(&[2; 32])).is_err());
+        let rotation = engine.rotate_signer(ProvenanceSigner::from_seed(&[2; 32])).unwrap();
+        assert_eq!((rotation.index, rotation.cycle), (0, 3));
+        for _ in 0..3 {
+            assert_eq!(engine.execute_cycle().await.unwrap().signer, next.fingerprint());
+        }
+        // Receipts on both sides of the rotation verify from the root key alone
+        let records = ProvenanceLog::read(&path).unwrap();
+        let keys = crate::ledger::verify_signatures(&records, &root_key).unwrap();
+        assert_eq!(keys.active().fingerprint(), next.fingerprint());
+        let epochs: Vec<_> = records.iter().filter_map(|r| r.epoch().ok()).collect();
+        assert_eq!(epochs.iter().map(|e| e.tree_size).collect::<Vec<_>>(), vec![2, 3, 4, 6]);
+        assert_eq!(epochs[1].signer, root.fingerprint());
+
+        // A commitment is bound to its place in the log and cannot be replayed
+        let replayed = dir.join("replayed.log");
+        std::fs::copy(&path, &replayed).unwrap();
+        ProvenanceLog::open(&replayed).unwrap().append_epoch(&epochs[3]).unwrap();
+        assert_eq!(
+            ProvenanceLog::verify(&replayed, Some(&root_key)).unwrap_err(),
+            DeoxysError::from(crate::crypto::ProvenanceError::MisplacedEpoch { epoch: 3 })
+        );
+
+        // The outgoing key can no longer sign receipts
+        let mut log = ProvenanceLog::open(&path).unwrap();
+        let mut stale = ReceiptRecord::from(engine.last_receipt().unwrap());
+        stale.cycle += 1;
+        stale.previous = Some(stale.hash.clone());
+        root.sign_receipt(&mut stale).unwrap();
+        log.append_receipt(&stale).unwrap();
+        let error = ProvenanceLog::verify(&path, Some(&root_key)).unwrap_err();
+        assert_eq!(
+            error,
+            DeoxysError::from(crate::crypto::ProvenanceError::WrongKey {
+                expected: next.fingerprint().to_string(),
+                found: root.fingerprint().to_string(),
+            })
+        );
+        drop(log);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // Revoking the old key from cycle 2 invalidates its receipts from then on
+        let mut engine = RikEngine::new(SovereignState::new("C_EQUALS_XNXALEXIS_ROOT"));
+        engine.set_decrease_condition(DecreaseCondition::new(0.0, 0.5, 0.0).unwrap());
+        engine.set_signer(ProvenanceSigner::from_seed(&[1; 32])).unwrap();
+        engine.set_provenance_log(ProvenanceLog::open(&path).unwrap()).unwrap();
+        for _ in 0..3 {
+            engine.execute_cycle().await.unwrap();
+        }
+        engine.rotate_signer(ProvenanceSigner::from_seed(&[2; 32])).unwrap();
+        engine.revoke_key(root.fingerprint(), 2, "key leaked").unwrap();
+        let error = ProvenanceLog::verify(&path, Some(&root_key)).unwrap_err();
+        assert_eq!(
+            error,
+            DeoxysError::from(crate::crypto::ProvenanceError::Revoked {
+                fingerprint: root.fingerprint().to_string(),
+                cycle: 2,
+                effective_from: 2,
+            })
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    struct Tripwire;
+
+    impl Invariant for Tripwire {
+        fn name(&self) -> &str {
+            "tripwire"
+        }
+
+        fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+            if ctx.cycle >= 2 {
+                Verdict::Fail(DeoxysError::InvariantViolation {
+                    invariant: "tripwire".to_string(),
+                    cycle: ctx.cycle,
+                    detail: "tripped".to_string(),
+                })
+            } else {
+                Verdict::Warn("armed".to_string())
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_invariant_runs_each_cycle() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        engine.register_invariant(Box::new(Tripwire)).unwrap();
+        assert!(engine.register_invariant(Box::new(Tripwire)).is_err());
+
+        let receipt = engine.execute_cycle().await.unwrap();
+        assert_eq!(receipt.warnings, vec!["tripwire: armed".to_string()]);
+
+        let err = engine.execute_cycle().await.unwrap_err();
+        assert_eq!(err.code(), "invariant_violation");
+        assert!(err.to_string().contains("'tripwire' violated at cycle 2"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_debounced_invariant_absorbs_first_failure() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        let policy =
+            InvariantPolicy::new(FailurePolicy::Halt, Debounce::Budget { limit: 2 }).unwrap();
+        engine.register_invariant_with_policy(Box::new(Tripwire), policy).unwrap();
+
+        // Cycle 1 warns, cycle 2 fails within budget, cycle 3 exhausts it
+        assert!(engine.execute_cycle().await.unwrap().violations.is_empty());
+        let receipt = engine.execute_cycle().await.unwrap();
+        assert_eq!(receipt.violations.len(), 1);
+        assert_eq!(engine.invariants().escalation_state("tripwire").unwrap().failures(), 1);
+
+        let err = engine.execute_cycle().await.unwrap_err();
+        assert_eq!(err.code(), "invariant_violation");
+        // Halt stays latched
+        assert_eq!(engine.execute_cycle().await.unwrap_err().code(), "lockdown");
+    }
+
+    #[tokio::test]
+    async fn test_lockdown_requires_authorized_reset() {
+        let dir = std::env::temp_dir().join(format!("deoxys-rik-reset-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("provenance.log");
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        engine.set_provenance_log(ProvenanceLog::open(&path).unwrap()).unwrap();
+        let policy = InvariantPolicy::new(FailurePolicy::Lockdown, Debounce::Immediate).unwrap();
+        engine.register_invariant_with_policy(Box::new(Tripwire), policy).unwrap();
+
+        engine.execute_cycle().await.unwrap();
+        assert_eq!(engine.execute_cycle().await.unwrap_err().code(), "lockdown");
+        assert_eq!(engine.execute_cycle().await.unwrap_err().code(), "lockdown");
+
+        // Anyone can forge the root substrate; it carries no operator key
+        let forged = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        assert!(forged.verify_integrity());
+        let proposal = engine.reset_proposal("tripwire");
+        let intruder = ProvenanceSigner::from_seed(&[6; 32]);
+        let err = engine
+            .reset_escalation("tripwire", &Decision::sign(&intruder, &proposal, Ruling::Approve))
+            .unwrap_err();
+        assert_eq!(err.code(), "provenance_failure");
+        assert!(!engine.invariants().escalations().is_empty());
+
+        let operator = ProvenanceSigner::from_seed(&[5; 32]);
+        engine.authorize_operator(ProvenanceVerifier::from(&operator)).unwrap();
+        let denial = Decision::sign(&operator, &proposal, Ruling::Deny);
+        assert!(engine.reset_escalation("tripwire", &denial).is_err());
+        let other = engine.reset_proposal(VarianceBound::NAME);
+        let misdirected = Decision::sign(&operator, &other, Ruling::Approve);
+        assert!(engine.reset_escalation("tripwire", &misdirected).is_err());
+        assert!(!engine.invariants().escalations().is_empty());
+
+        // A reset proposal is not a cycle proposal, even at the same cycle
+        let cycle = Decision::sign(&operator, &engine.proposal(), Ruling::Approve);
+        assert!(engine.reset_escalation("tripwire", &cycle).is_err());
+
+        // The reset is logged as the operator's decision before it takes effect
+        let approval = Decision::sign(&operator, &proposal, Ruling::Approve);
+        engine.reset_escalation("tripwire", &approval).unwrap();
+        assert!(engine.invariants().escalations().is_empty());
+        let verifier = ProvenanceVerifier::from(engine.signer());
+        let records = ProvenanceLog::read(&path).unwrap();
+        assert_eq!(records.last().unwrap().decision().unwrap(), approval);
+        assert!(ProvenanceLog::verify(&path, Some(&verifier)).is_ok());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_quorum_gates_high_consequence_changes() {
+        let dir = std::env::temp_dir().join(format!("deoxys-rik-quorum-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("provenance.log");
+        let operators: Vec<_> = (1..=3).map(|i| ProvenanceSigner::from_seed(&[i; 32])).collect();
+        let keys = operators.iter().map(ProvenanceVerifier::from).collect();
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        let policy = InvariantPolicy::new(FailurePolicy::Lockdown, Debounce::Immediate).unwrap();
+        engine.register_invariant_with_policy(Box::new(Tripwire), policy).unwrap();
+        let root = ProvenanceVerifier::from(engine.signer());
+        engine.set_provenance_log(ProvenanceLog::open(&path).unwrap()).unwrap();
+        let policy = QuorumPolicy::new(2, keys, Duration::from_secs(60)).unwrap();
+        engine.set_quorum_policy(policy.clone()).unwrap();
+
+        // The signer is fixed once a log or quorum policy is in place
+        assert!(engine.set_signer(ProvenanceSigner::from_seed(&[7; 32])).is_err());
+        let mut unlogged = RikEngine::new(SovereignState::new("C_EQUALS_XNXALEXIS_ROOT"));
+        unlogged.set_quorum_policy(policy).unwrap();
+        assert!(unlogged.set_signer(ProvenanceSigner::from_seed(&[7; 32])).is_err());
+
+        // A single operator may narrow the bounds but not widen them
+        let wide = OperatorBounds::new(-2.0, 2.0).unwrap();
+        let error = engine.set_operator_bounds(wide).unwrap_err();
+        assert_eq!(error.code(), "quorum_required");
+        engine.set_operator_bounds(OperatorBounds::new(-0.5, 0.5).unwrap()).unwrap();
+
+        let quorum = engine.quorum().unwrap();
+        let request = quorum.request(OperatorCommand::SetBounds { min: -2.0, max: 2.0 });
+        let endorse = |i: usize, at| Endorsement::sign(&operators[i], &request, at);
+        assert_eq!(engine.endorse_command_at(&request, endorse(0, 1000), 1000).unwrap(), None);
+        assert_eq!(engine.quorum().unwrap().pending()[0].endorsements.len(), 1);
+        assert_eq!(engine.config().bounds.max, 0.5);
+        let certificate = engine.endorse_command_at(&request, endorse(2, 1020), 1020).unwrap();
+        assert_eq!(engine.config().bounds, wide);
+
+        // Lockdown resets need a certificate, not a single operator's approval
+        engine.execute_cycle().await.unwrap();
+        assert_eq!(engine.execute_cycle().await.unwrap_err().code(), "lockdown");
+        let proposal = engine.reset_proposal("tripwire");
+        let approval = Decision::sign(&operators[0], &proposal, Ruling::Approve);
+        let error = engine.reset_escalation("tripwire", &approval).unwrap_err();
+        assert_eq!(error, DeoxysError::QuorumRequired { action: "Reset from lockdown" });
+        let reset = OperatorCommand::ResetEscalation { invariant: "tripwire".into() };
+        let request = engine.quorum().unwrap().request(reset);
+        for (i, at) in [(1, 2000), (0, 2030)] {
+            let endorsement = Endorsement::sign(&operators[i], &request, at);
+            engine.endorse_command_at(&request, endorsement, at).unwrap();
+        }
+        assert!(engine.invariants().escalations().is_empty());
+
+        // Rotation only to the key the quorum certified
+        let next = ProvenanceSigner::from_seed(&[8; 32]);
+        let error = engine.rotate_signer(ProvenanceSigner::from_seed(&[8; 32])).unwrap_err();
+        assert_eq!(error.code(), "quorum_required");
+        let to_key = hex::encode(next.verifying_key().to_bytes());
+        let request = engine.quorum().unwrap().request(OperatorCommand::RotateSigner { to_key });
+        for i in [0, 1] {
+            let endorsement = Endorsement::sign(&operators[i], &request, 3000);
+            engine.endorse_command_at(&request, endorsement, 3000).unwrap();
+        }
+        assert!(engine.rotate_signer(ProvenanceSigner::from_seed(&[9; 32])).is_err());
+        engine.rotate_signer(next).unwrap();
+
+        let records = ProvenanceLog::read(&path).unwrap();
+        let certificates: Vec<_> = records.iter().filter_map(|r| r.quorum().ok()).collect();
+        assert_eq!(certificates.len(), 3);
+        assert_eq!(Some(&certificates[0]), certificate.as_ref());
+        for certificate in &certificates {
+            certificate.verify_policy(engine.quorum().unwrap().policy()).unwrap();
+        }
+        let root_key = ProvenanceVerifier::from(&ProvenanceSigner::from_seed(&[0; 32]));
+        assert!(ProvenanceLog::verify(&path, None).is_ok());
+        assert!(ProvenanceLog::verify(&path, Some(&root_key)).is_err());
+
+        // The policy itself changes only under a certificate of the current one
+        let keys = vec![ProvenanceVerifier::from(&operators[2])];
+        let successor = QuorumPolicy::new(1, keys, Duration::from_secs(30)).unwrap();
+        let error = engine.set_quorum_policy(successor.clone()).unwrap_err();
+        assert_eq!(error, DeoxysError::QuorumRequired { action: "Quorum policy change" });
+        let request = engine.quorum().unwrap().request(successor.command());
+        for (i, at) in [(2, 4000), (1, 4010)] {
+            assert_eq!(engine.quorum().unwrap().policy().threshold(), 2);
+            let endorsement = Endorsement::sign(&operators[i], &request, at);
+            engine.endorse_command_at(&request, endorsement, at).unwrap();
+        }
+        let quorum = engine.quorum().unwrap();
+        assert_eq!((quorum.policy().threshold(), quorum.sequence()), (1, 4));
+        let request = quorum.request(OperatorCommand::SetBounds { min: -3.0, max: 3.0 });
+        let retired = Endorsement::sign(&operators[0], &request, 5000);
+        assert!(engine.endorse_command_at(&request, retired, 5000).is_err());
+        let endorsement = Endorsement::sign(&operators[2], &request, 5000);
+        assert!(engine.endorse_command_at(&request, endorsement, 5000).unwrap().is_some());
+        ProvenanceLog::verify(&path, Some(&root)).unwrap();
+
+        // An audit replays the policy in force and refuses a certificate
+        // assembled by keys outside it, however well signed
+        let outsiders: Vec<_> = (4..=5).map(|i| ProvenanceSigner::from_seed(&[i; 32])).collect();
+        let keys = outsiders.iter().map(ProvenanceVerifier::from).collect();
+        let sequence = engine.quorum().unwrap().sequence();
+        let forged_policy = QuorumPolicy::new(2, keys, Duration::from_secs(60)).unwrap();
+        let mut forger = Quorum::new(forged_policy, root.fingerprint(), sequence);
+        let request = forger.request(OperatorCommand::SetBounds { min: -9.0, max: 9.0 });
+        forger.endorse(&request, Endorsement::sign(&outsiders[0], &request, 6000), 6000).unwrap();
+        let endorsement = Endorsement::sign(&outsiders[1], &request, 6000);
+        let forged = forger.endorse(&request, endorsement, 6000).unwrap().unwrap();
+        drop(engine);
+        ProvenanceLog::open(&path).unwrap().append_quorum(&forged).unwrap();
+        assert_eq!(
+            ProvenanceLog::verify(&path, Some(&root)).unwrap_err(),
+            ProvenanceError::UnauthorizedOperator {
+                fingerprint: outsiders[0].fingerprint().to_string()
+            }
+            .into()
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_safe_state_policy_replaces_control() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        let policy = InvariantPolicy::new(FailurePolicy::SafeState, Debounce::Immediate).unwrap();
+        engine.register_invariant_with_policy(Box::new(Tripwire), policy).unwrap();
+
+        assert_eq!(engine.execute_cycle().await.unwrap().mode, CycleMode::Nominal);
+        let receipt = engine.execute_cycle().await.unwrap();
+        assert_eq!(receipt.mode, CycleMode::SafeState);
+        assert_eq!(receipt.escalations.len(), 1);
+        assert!(engine.belief_state.iter().all(|&x| x == 0.0));
+        let safe = RikEngine::safe_state(2, OperatorBounds::new(0.5, 1.0).unwrap());
+        assert_eq!(safe.to_vec(), vec![0.5, 0.5]);
+    }
+
+    #[tokio::test]
+    async fn test_energy_limit_from_region_of_attraction() {
+        use ndarray::array;
+
+        let bounds = OperatorBounds::new(-0.5, 0.5).unwrap();
+        let config = EngineConfig { bounds, ..EngineConfig::default() };
+        let plant = PlantModel::new(array![[1.1]], array![[1.0]]).unwrap();
+        let planner = LinearPlanner::new(array![[0.6]]).unwrap();
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::with_design_model(substrate, config, plant, planner).unwrap();
+
+        // The unsaturated loop recovers from the whole state box, V <= 0.5^2
+        match engine.execute_cycle_with(Array1::from_elem(10, 0.2)).await.unwrap_err() {
+            DeoxysError::StabilityBreach { energy, limit, .. } => {
+                assert!((energy - 0.4).abs() < 1e-12);
+                assert_eq!(limit, Some(0.25));
+            }
+            other => panic!("expected an energy bound breach, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_energy_limit_override() {
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut engine = RikEngine::new(substrate);
+        assert!(engine.set_energy_limit(0.0).is_err());
+
+        // Stub observation drives V to 10 * 0.015^2 = 0.00225 at cycle 2
+        engine.set_energy_limit(0.002).unwrap();
+        engine.execute_cycle().await.unwrap();
+        let err = engine.execute_cycle().await.unwrap_err();
+        assert_eq!(err.code(), "stability_breach");
+    }
+
+    #[test]
+    fn test_design_model_stability_policy() {
+        use ndarray::array;
+
+        let plant = PlantModel::new(array![[1.1]], array![[1.0]]).unwrap();
+        let weak = LinearPlanner::new(array![[0.05]]).unwrap();
+        let good = LinearPlanner::new(array![[0.6]]).unwrap();
+
+        let mut engine = RikEngine::new(SovereignState::new("C_EQUALS_XNXALEXIS_ROOT"));
+        let err = engine.set_design_model(plant.clone(), weak.clone()).unwrap_err();
+        assert_eq!(err.code(), "invalid_configuration");
+        assert!(engine.design_model().is_none());
+        let report = engine.set_design_model(plant.clone(), good).unwrap();
+        assert!(report.stable && report.observable());
+
+        let config = EngineConfig { stability: StabilityPolicy::Warn, ..EngineConfig::default() };
+        let substrate = SovereignState::new("C_EQUALS_XNXALEXIS_ROOT");
+        let mut lenient = RikEngine::with_config(substrate, config);
+        assert!(!lenient.set_design_model(plant, weak).unwrap().stable);
+        assert!(lenient.design_model().is_some());
+    }
 }
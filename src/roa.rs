@@ -0,0 +1,494 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Region-of-attraction estimation for the saturated closed loop
+//! x_{k+1} = A x_k + B sat(-K x_k).
+//!
+//! The estimate is the largest sublevel set {x : V(x) <= c} of the Lyapunov
+//! candidate V(x) = ||x||^2 that lies inside the state constraints and on which
+//! the engine's `DecreaseCondition` holds. Sampling gives an upper estimate of c;
+//! bisection then verifies candidate levels by interval arithmetic over a
+//! branch-and-bound cover of the sublevel set.
+
+use crate::control::{LinearPlanner, PlantModel};
+use crate::error::{DeoxysError, Result};
+use crate::invariants::{energy, DecreaseCondition};
+use crate::rik::OperatorBounds;
+use ndarray::{Array1, Array2};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Serialize;
+
+/// Relative slack covering floating point error in the interval bounds
+const ROUNDING_SLACK: f64 = 1e-9;
+
+/// Closed interval [lo, hi]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl Interval {
+    fn new(lo: f64, hi: f64) -> Self {
+        Self { lo, hi }
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.lo + other.lo, self.hi + other.hi)
+    }
+
+    fn scale(self, k: f64) -> Self {
+        if k >= 0.0 {
+            Self::new(k * self.lo, k * self.hi)
+        } else {
+            Self::new(k * self.hi, k * self.lo)
+        }
+    }
+
+    fn sqr(self) -> Self {
+        let (a, b) = (self.lo * self.lo, self.hi * self.hi);
+        if self.lo >= 0.0 {
+            Self::new(a, b)
+        } else if self.hi <= 0.0 {
+            Self::new(b, a)
+        } else {
+            Self::new(0.0, a.max(b))
+        }
+    }
+
+    fn clamp(self, bounds: OperatorBounds) -> Self {
+        Self::new(
+            self.lo.clamp(bounds.min, bounds.max),
+            self.hi.clamp(bounds.min, bounds.max),
+        )
+    }
+
+    fn width(self) -> f64 {
+        self.hi - self.lo
+    }
+
+    fn mid(self) -> f64 {
+        0.5 * (self.lo + self.hi)
+    }
+}
+
+fn dot(row: ndarray::ArrayView1<f64>, x: &[Interval]) -> Interval {
+    row.iter()
+        .zip(x)
+        .fold(Interval::new(0.0, 0.0), |acc, (&k, &xi)| {
+            acc.add(xi.scale(k))
+        })
+}
+
+fn energy_interval(x: &[Interval]) -> Interval {
+    x.iter()
+        .fold(Interval::new(0.0, 0.0), |acc, xi| acc.add(xi.sqr()))
+}
+
+/// Result of verifying one candidate level
+enum Verification {
+    Verified,
+    /// A concrete state inside the level set violating the decrease condition
+    Refuted(Array1<f64>),
+    /// Box budget exhausted before the cover was complete
+    Exhausted,
+}
+
+/// Estimated region of attraction, serializable for reports and receipts
+#[derive(Debug, Clone, Serialize)]
+pub struct RoaReport {
+    pub dimension: usize,
+    /// Largest verified level c: every state with V(x) <= c recovers
+    pub level: f64,
+    /// Radius of the verified ball, sqrt(c)
+    pub radius: f64,
+    /// Smallest V at which a sampled state violated decrease (upper estimate)
+    pub sampled_level: f64,
+    /// Largest level whose sublevel set fits inside the state constraints
+    pub constraint_level: f64,
+    pub verified: bool,
+    pub samples: usize,
+    /// Boxes processed by interval verification, over all bisection steps
+    pub boxes: usize,
+    pub decrease: DecreaseCondition,
+}
+
+impl RoaReport {
+    /// Energy limit for the `energy-bound` invariant, see
+    /// `LyapunovValidator::with_region_of_attraction`
+    pub fn energy_limit(&self) -> Result<f64> {
+        if !self.verified {
+            return Err(DeoxysError::config(
+                "region of attraction",
+                "no sublevel set could be verified",
+            ));
+        }
+        Ok(self.level)
+    }
+}
+
+/// Region-of-attraction analysis of a plant under the saturated linear planner
+pub struct RoaAnalysis {
+    plant: PlantModel,
+    planner: LinearPlanner,
+    closed_loop: Array2<f64>,
+    input_bounds: OperatorBounds,
+    state_bounds: OperatorBounds,
+    decrease: DecreaseCondition,
+    samples: usize,
+    radial_steps: usize,
+    iterations: usize,
+    precision: f64,
+    box_budget: usize,
+    seed: u64,
+}
+
+impl RoaAnalysis {
+    /// `input_bounds` saturate the planner output; the sublevel set must lie in
+    /// the `state_bounds` box, which has to contain the equilibrium x = 0
+    pub fn new(
+        plant: PlantModel,
+        planner: LinearPlanner,
+        input_bounds: OperatorBounds,
+        state_bounds: OperatorBounds,
+    ) -> Result<Self> {
+        planner.check(&plant)?;
+        if !(state_bounds.min < 0.0 && state_bounds.max > 0.0) {
+            return Err(DeoxysError::config(
+                "region of attraction state bounds",
+                format!(
+                    "[{}, {}] must contain the equilibrium",
+                    state_bounds.min, state_bounds.max
+                ),
+            ));
+        }
+        if !(input_bounds.min <= 0.0 && input_bounds.max >= 0.0) {
+            return Err(DeoxysError::config(
+                "region of attraction input bounds",
+                format!(
+                    "[{}, {}] must contain zero input",
+                    input_bounds.min, input_bounds.max
+                ),
+            ));
+        }
+        Ok(Self {
+            closed_loop: planner.closed_loop(&plant),
+            plant,
+            planner,
+            input_bounds,
+            state_bounds,
+            decrease: DecreaseCondition::default(),
+            samples: 512,
+            radial_steps: 64,
+            iterations: 24,
+            precision: 0.01,
+            box_budget: 200_000,
+            seed: 0x5EED_0A11,
+        })
+    }
+
+    /// Decrease condition the region must satisfy; its terminal neighbourhood
+    /// or tolerance must be non-zero for the interval cover to terminate
+    pub fn with_decrease(mut self, decrease: DecreaseCondition) -> Result<Self> {
+        if decrease.terminal_energy + decrease.tolerance <= 0.0 {
+            return Err(DeoxysError::config(
+                "region of attraction decrease",
+                "terminal energy or tolerance must be positive",
+            ));
+        }
+        self.decrease = decrease;
+        Ok(self)
+    }
+
+    /// Random directions sampled in addition to the coordinate axes
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Bisection stops after `iterations` steps or once the gap between the
+    /// verified and refuted levels is below `precision` relative to the latter
+    pub fn with_iterations(mut self, iterations: usize, precision: f64) -> Self {
+        self.iterations = iterations;
+        self.precision = precision;
+        self
+    }
+
+    /// Maximum boxes per verification before a level is treated as unverified
+    pub fn with_box_budget(mut self, box_budget: usize) -> Self {
+        self.box_budget = box_budget;
+        self
+    }
+
+    /// Seed of the deterministic direction sampler
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(&self) -> RoaReport {
+        let n = self.plant.states();
+        let radius = (-self.state_bounds.min).min(self.state_bounds.max);
+        let constraint_level = radius * radius;
+        let (sampled_level, samples) = self.sample(constraint_level);
+
+        let mut boxes = 0;
+        let mut verified_level = 0.0;
+        let mut upper = sampled_level;
+        match self.verify(upper, &mut boxes) {
+            Verification::Verified => verified_level = upper,
+            outcome => {
+                if let Verification::Refuted(state) = outcome {
+                    upper = energy(&state);
+                }
+                for _ in 0..self.iterations {
+                    if upper - verified_level <= self.precision * upper {
+                        break;
+                    }
+                    let candidate = 0.5 * (verified_level + upper);
+                    match self.verify(candidate, &mut boxes) {
+                        Verification::Verified => verified_level = candidate,
+                        Verification::Refuted(state) => upper = energy(&state).min(candidate),
+                        Verification::Exhausted => upper = candidate,
+                    }
+                }
+            }
+        }
+
+        RoaReport {
+            dimension: n,
+            level: verified_level,
+            radius: verified_level.sqrt(),
+            sampled_level,
+            constraint_level,
+            verified: verified_level > 0.0,
+            samples,
+            boxes,
+            decrease: self.decrease,
+        }
+    }
+
+    /// Closed-loop successor of a concrete state
+    fn successor(&self, state: &Array1<f64>) -> Array1<f64> {
+        self.plant
+            .step(state, &self.planner.propose(state, self.input_bounds))
+    }
+
+    fn decreases(&self, state: &Array1<f64>) -> bool {
+        let v = energy(state);
+        let next = energy(&self.successor(state));
+        next - v <= -self.decrease.rate * v + self.decrease.tolerance
+    }
+
+    /// Walk outward along axis and random directions; returns the smallest
+    /// energy at which decrease failed, capped by the constraint level
+    fn sample(&self, constraint_level: f64) -> (f64, usize) {
+        let n = self.plant.states();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut directions: Vec<Array1<f64>> = (0..2 * n)
+            .map(|i| {
+                let mut axis = Array1::zeros(n);
+                axis[i / 2] = if i % 2 == 0 { 1.0 } else { -1.0 };
+                axis
+            })
+            .collect();
+        for _ in 0..self.samples {
+            // Box-Muller gaussian components give uniformly distributed directions
+            let dir = Array1::from_shape_fn(n, |_| {
+                let (u1, u2): (f64, f64) = (rng.gen_range(f64::EPSILON..1.0), rng.gen());
+                (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+            });
+            let norm = energy(&dir).sqrt();
+            if norm > 0.0 {
+                directions.push(dir / norm);
+            }
+        }
+
+        let max_radius = constraint_level.sqrt();
+        let mut level = constraint_level;
+        let mut count = 0;
+        for dir in &directions {
+            for step in 1..=self.radial_steps {
+                let r = max_radius * step as f64 / self.radial_steps as f64;
+                let v = r * r;
+                if v >= level {
+                    break;
+                }
+                if v <= self.decrease.terminal_energy {
+                    continue;
+                }
+                count += 1;
+                if !self.decreases(&(dir * r)) {
+                    level = v;
+                    break;
+                }
+            }
+        }
+        (level, count)
+    }
+
+    /// Interval enclosure of the closed-loop successor of a box
+    fn image(&self, x: &[Interval]) -> Vec<Interval> {
+        let raw: Vec<Interval> = self
+            .planner
+            .gain()
+            .rows()
+            .into_iter()
+            .map(|row| dot(row, x).scale(-1.0))
+            .collect();
+        let saturates = raw
+            .iter()
+            .any(|u| u.lo < self.input_bounds.min || u.hi > self.input_bounds.max);
+        if !saturates {
+            // Linear region: A - BK avoids the dependency between x and u
+            return self
+                .closed_loop
+                .rows()
+                .into_iter()
+                .map(|row| dot(row, x))
+                .collect();
+        }
+        let input: Vec<Interval> = raw
+            .into_iter()
+            .map(|u| u.clamp(self.input_bounds))
+            .collect();
+        self.plant
+            .a()
+            .rows()
+            .into_iter()
+            .zip(self.plant.b().rows())
+            .map(|(a, b)| dot(a, x).add(dot(b, &input)))
+            .collect()
+    }
+
+    /// Branch-and-bound cover of {V <= level}: every box must satisfy decrease,
+    /// or lie in the terminal neighbourhood and map back into the level set
+    fn verify(&self, level: f64, boxes: &mut usize) -> Verification {
+        let n = self.plant.states();
+        let r = level.sqrt();
+        let slack = ROUNDING_SLACK * (1.0 + level);
+        let contraction = 1.0 - self.decrease.rate;
+        let mut stack = vec![vec![Interval::new(-r, r); n]];
+        let mut processed = 0;
+
+        while let Some(cell) = stack.pop() {
+            processed += 1;
+            if processed > self.box_budget {
+                *boxes += processed;
+                return Verification::Exhausted;
+            }
+            let v = energy_interval(&cell);
+            if v.lo > level {
+                continue;
+            }
+            let next = energy_interval(&self.image(&cell));
+            let terminal = v.hi <= self.decrease.terminal_energy;
+            let decreasing = next.hi - contraction * v.lo - self.decrease.tolerance <= -slack;
+            if (terminal && next.hi <= level - slack)
+                || (decreasing && level >= self.decrease.terminal_energy)
+            {
+                continue;
+            }
+
+            let mid = Array1::from_iter(cell.iter().map(|x| x.mid()));
+            let v_mid = energy(&mid);
+            let escapes =
+                v_mid <= self.decrease.terminal_energy && energy(&self.successor(&mid)) > level;
+            let violates = v_mid > self.decrease.terminal_energy && !self.decreases(&mid);
+            if v_mid <= level && (escapes || violates) {
+                *boxes += processed;
+                return Verification::Refuted(mid);
+            }
+            let (axis, _) = cell.iter().enumerate().fold((0, 0.0), |best, (i, x)| {
+                if x.width() > best.1 {
+                    (i, x.width())
+                } else {
+                    best
+                }
+            });
+            let split = cell[axis].mid();
+            let mut left = cell.clone();
+            let mut right = cell;
+            left[axis].hi = split;
+            right[axis].lo = split;
+            stack.push(left);
+            stack.push(right);
+        }
+        *boxes += processed;
+        Verification::Verified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::invariants::LyapunovValidator;
+
+    fn saturated_loop(state_limit: f64) -> RoaAnalysis {
+        // Open-loop unstable (1.2) plant; u = sat(-0.6 x) recovers only near the origin
+        let plant = PlantModel::new(Array2::eye(2) * 1.2, Array2::eye(2)).unwrap();
+        let planner = LinearPlanner::new(Array2::eye(2) * 0.6).unwrap();
+        let state = OperatorBounds::new(-state_limit, state_limit).unwrap();
+        RoaAnalysis::new(plant, planner, OperatorBounds::default(), state).unwrap()
+    }
+
+    #[test]
+    fn test_interval_arithmetic() {
+        let x = Interval::new(-2.0, 1.0);
+        assert_eq!(x.sqr(), Interval::new(0.0, 4.0));
+        assert_eq!(Interval::new(-3.0, -1.0).sqr(), Interval::new(1.0, 9.0));
+        assert_eq!(x.scale(-2.0), Interval::new(-2.0, 4.0));
+        assert_eq!(x.clamp(OperatorBounds::default()), Interval::new(-1.0, 1.0));
+    }
+
+    #[test]
+    fn test_constraint_limited_region() {
+        // Decrease holds throughout |x_i| <= 2, so the state box is the limit
+        let report = saturated_loop(2.0).run();
+        assert!(report.verified);
+        assert_eq!(report.constraint_level, 4.0);
+        assert_eq!(report.level, 4.0);
+        assert_eq!(report.energy_limit().unwrap(), 4.0);
+
+        let validator = LyapunovValidator::new().with_region_of_attraction(&report).unwrap();
+        assert_eq!(validator.energy_limit(), 4.0);
+    }
+
+    #[test]
+    fn test_saturation_limited_region() {
+        // Along an axis, |1.2x - 1| <= sqrt(0.95) |x| fails beyond x ~= 4.44 (V ~= 19.7)
+        let report = saturated_loop(10.0).run();
+        assert!(report.sampled_level < 21.0 && report.sampled_level > 19.0);
+        assert!(report.verified);
+        assert!(report.level <= report.sampled_level);
+        assert!(report.level > 15.0, "level {}", report.level);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["dimension"], 2);
+        assert_eq!(json["verified"], true);
+    }
+
+    #[test]
+    fn test_unstable_loop_has_no_verified_region() {
+        let plant = PlantModel::new(Array2::eye(2) * 1.2, Array2::eye(2)).unwrap();
+        let planner = LinearPlanner::new(Array2::zeros((2, 2))).unwrap();
+        let state = OperatorBounds::new(-2.0, 2.0).unwrap();
+        let report = RoaAnalysis::new(plant, planner, OperatorBounds::default(), state)
+            .unwrap()
+            .with_iterations(8, 0.01)
+            .run();
+        assert!(!report.verified);
+        assert!(report.energy_limit().is_err());
+        assert!(LyapunovValidator::new().with_region_of_attraction(&report).is_err());
+    }
+
+    #[test]
+    fn test_rejects_constraints_excluding_equilibrium() {
+        let plant = PlantModel::new(Array2::eye(2), Array2::eye(2)).unwrap();
+        let planner = LinearPlanner::new(Array2::eye(2) * 0.5).unwrap();
+        let state = OperatorBounds::new(0.5, 1.0).unwrap();
+        assert!(RoaAnalysis::new(plant, planner, OperatorBounds::default(), state).is_err());
+    }
+}
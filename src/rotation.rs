@@ -0,0 +1,287 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Provenance key rotation and revocation.
+//!
+//! A [`RotationRecord`] hands signing over from the active key to a new one
+//! and carries signatures by both: the outgoing key authorizes the successor
+//! and the incoming key proves possession. A [`RevocationRecord`], signed by
+//! the active key, marks an earlier key as compromised from a given cycle.
+//! [`KeyChain`] starts from a trusted root key and replays these records in
+//! log order, so receipts signed by any key in the rotation chain remain
+//! verifiable.
+
+use crate::canonical::{Domain, Encoder};
+use crate::crypto::{ProvenanceError, ProvenanceSigner, ProvenanceVerifier, ReceiptRecord};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Hand-over from key `from` to key `to`, taking effect after `cycle`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RotationRecord {
+    /// Number of rotations before this one
+    pub index: u64,
+    /// Last cycle signed by the outgoing key
+    pub cycle: u64,
+    /// Fingerprint of the outgoing key
+    pub from: String,
+    /// Incoming public key, hex
+    pub to_key: String,
+    pub from_signature: String,
+    pub to_signature: String,
+}
+
+impl RotationRecord {
+    fn message(index: u64, cycle: u64, from: &str, to_key: &[u8; 32]) -> [u8; 32] {
+        Encoder::new(Domain::Rotation)
+            .u64(index)
+            .u64(cycle)
+            .str(from)
+            .bytes(to_key)
+            .digest()
+    }
+
+    pub fn new(index: u64, cycle: u64, from: &ProvenanceSigner, to: &ProvenanceSigner) -> Self {
+        let to_key = to.verifying_key().to_bytes();
+        let message = Self::message(index, cycle, from.fingerprint(), &to_key);
+        Self {
+            index,
+            cycle,
+            from: from.fingerprint().to_string(),
+            to_key: hex::encode(to_key),
+            from_signature: from.sign_digest(&message),
+            to_signature: to.sign_digest(&message),
+        }
+    }
+
+    /// Incoming key, if `to_key` is a valid Ed25519 public key
+    pub fn incoming(&self) -> Option<ProvenanceVerifier> {
+        ProvenanceVerifier::from_hex(&self.to_key)
+    }
+}
+
+/// Key `fingerprint` is compromised for every cycle from `effective_from`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RevocationRecord {
+    pub fingerprint: String,
+    pub effective_from: u64,
+    pub reason: String,
+    /// Fingerprint of the key signing the revocation
+    pub signer: String,
+    pub signature: String,
+}
+
+impl RevocationRecord {
+    fn message(fingerprint: &str, effective_from: u64, reason: &str) -> [u8; 32] {
+        Encoder::new(Domain::Revocation)
+            .str(fingerprint)
+            .u64(effective_from)
+            .str(reason)
+            .digest()
+    }
+
+    pub fn new(
+        signer: &ProvenanceSigner,
+        fingerprint: &str,
+        effective_from: u64,
+        reason: impl Into<String>,
+    ) -> Self {
+        let reason = reason.into();
+        let signature = signer.sign_digest(&Self::message(fingerprint, effective_from, &reason));
+        Self {
+            fingerprint: fingerprint.to_string(),
+            effective_from,
+            reason,
+            signer: signer.fingerprint().to_string(),
+            signature,
+        }
+    }
+}
+
+/// Provenance keys known from a trusted root by following rotation records
+#[derive(Debug, Clone)]
+pub struct KeyChain {
+    keys: Vec<ProvenanceVerifier>,
+    /// Revoked fingerprints and the first cycle they may not sign
+    revoked: HashMap<String, u64>,
+}
+
+impl KeyChain {
+    pub fn new(root: ProvenanceVerifier) -> Self {
+        Self {
+            keys: vec![root],
+            revoked: HashMap::new(),
+        }
+    }
+
+    /// Key currently entitled to sign receipts
+    pub fn active(&self) -> &ProvenanceVerifier {
+        self.keys.last().expect("key chain starts from a root key")
+    }
+
+    /// Every key in rotation order, root first
+    pub fn keys(&self) -> &[ProvenanceVerifier] {
+        &self.keys
+    }
+
+    pub fn rotations(&self) -> u64 {
+        self.keys.len() as u64 - 1
+    }
+
+    pub fn revoked_from(&self, fingerprint: &str) -> Option<u64> {
+        self.revoked.get(fingerprint).copied()
+    }
+
+    /// Accept a rotation from the active key, signed by both keys
+    pub fn rotate(&mut self, rotation: &RotationRecord) -> Result<(), ProvenanceError> {
+        let invalid = ProvenanceError::BadRotation {
+            index: rotation.index,
+        };
+        let active = self.active();
+        if rotation.index != self.rotations() || rotation.from != active.fingerprint() {
+            return Err(invalid);
+        }
+        let incoming = rotation.incoming().ok_or(invalid.clone())?;
+        let message = RotationRecord::message(
+            rotation.index,
+            rotation.cycle,
+            &rotation.from,
+            incoming.verifying_key().as_bytes(),
+        );
+        if !active.verify_digest(&message, &rotation.from_signature)
+            || !incoming.verify_digest(&message, &rotation.to_signature)
+        {
+            return Err(invalid);
+        }
+        self.keys.push(incoming);
+        Ok(())
+    }
+
+    /// Accept a revocation signed by the active key. Revoking the same key
+    /// again can only move its effective cycle earlier.
+    pub fn revoke(&mut self, revocation: &RevocationRecord) -> Result<(), ProvenanceError> {
+        let active = self.active();
+        let message = RevocationRecord::message(
+            &revocation.fingerprint,
+            revocation.effective_from,
+            &revocation.reason,
+        );
+        if revocation.signer != active.fingerprint()
+            || !active.verify_digest(&message, &revocation.signature)
+        {
+            return Err(ProvenanceError::BadRevocation {
+                fingerprint: revocation.fingerprint.clone(),
+            });
+        }
+        let from = self
+            .revoked
+            .entry(revocation.fingerprint.clone())
+            .or_insert(revocation.effective_from);
+        *from = (*from).min(revocation.effective_from);
+        Ok(())
+    }
+
+    /// Check a receipt against the active key
+    pub fn verify_receipt(&self, receipt: &ReceiptRecord) -> Result<(), ProvenanceError> {
+        self.active().verify_receipt(receipt)
+    }
+
+    /// Refuse a receipt whose key is revoked for its cycle; revocations apply
+    /// to receipts recorded before them as well
+    pub fn check_revocation(&self, receipt: &ReceiptRecord) -> Result<(), ProvenanceError> {
+        match self.revoked_from(&receipt.signer) {
+            Some(effective_from) if receipt.cycle >= effective_from => {
+                Err(ProvenanceError::Revoked {
+                    fingerprint: receipt.signer.clone(),
+                    cycle: receipt.cycle,
+                    effective_from,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotation_requires_both_keys() {
+        let root = ProvenanceSigner::from_seed(&[1; 32]);
+        let next = ProvenanceSigner::from_seed(&[2; 32]);
+        let intruder = ProvenanceSigner::from_seed(&[3; 32]);
+        let mut chain = KeyChain::new(ProvenanceVerifier::from(&root));
+
+        let rotation = RotationRecord::new(0, 40, &root, &next);
+        assert_eq!(
+            rotation.incoming().unwrap().fingerprint(),
+            next.fingerprint()
+        );
+
+        // Incoming key did not sign
+        let mut unproven = rotation.clone();
+        unproven.to_signature = RotationRecord::new(0, 40, &root, &intruder).to_signature;
+        assert_eq!(
+            chain.rotate(&unproven),
+            Err(ProvenanceError::BadRotation { index: 0 })
+        );
+        // Outgoing key is not the active one
+        let hijack = RotationRecord::new(0, 40, &intruder, &next);
+        assert!(chain.rotate(&hijack).is_err());
+        // Swapped successor under the original signatures
+        let mut swapped = rotation.clone();
+        swapped.to_key = hex::encode(intruder.verifying_key().to_bytes());
+        assert!(chain.rotate(&swapped).is_err());
+
+        chain.rotate(&rotation).unwrap();
+        assert_eq!(chain.active().fingerprint(), next.fingerprint());
+        assert_eq!(chain.rotations(), 1);
+        // Replaying the same hand-over is out of sequence
+        assert!(chain.rotate(&rotation).is_err());
+        let back = RotationRecord::new(1, 90, &next, &root);
+        chain.rotate(&back).unwrap();
+        assert_eq!(chain.active().fingerprint(), root.fingerprint());
+        assert_eq!(chain.keys().len(), 3);
+    }
+
+    #[test]
+    fn test_revocation_signed_by_active_key() {
+        let root = ProvenanceSigner::from_seed(&[1; 32]);
+        let next = ProvenanceSigner::from_seed(&[2; 32]);
+        let mut chain = KeyChain::new(ProvenanceVerifier::from(&root));
+        chain
+            .rotate(&RotationRecord::new(0, 40, &root, &next))
+            .unwrap();
+
+        // The compromised key cannot revoke or un-revoke anything any more
+        let forged = RevocationRecord::new(&root, next.fingerprint(), 0, "forged");
+        assert!(matches!(
+            chain.revoke(&forged),
+            Err(ProvenanceError::BadRevocation { .. })
+        ));
+        let mut altered = RevocationRecord::new(&next, root.fingerprint(), 30, "key leaked");
+        altered.effective_from = 35;
+        assert!(chain.revoke(&altered).is_err());
+
+        chain
+            .revoke(&RevocationRecord::new(
+                &next,
+                root.fingerprint(),
+                30,
+                "key leaked",
+            ))
+            .unwrap();
+        chain
+            .revoke(&RevocationRecord::new(
+                &next,
+                root.fingerprint(),
+                50,
+                "again",
+            ))
+            .unwrap();
+        assert_eq!(chain.revoked_from(root.fingerprint()), Some(30));
+        assert_eq!(chain.revoked_from(next.fingerprint()), None);
+    }
+}
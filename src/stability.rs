@@ -0,0 +1,655 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Configuration-time analysis of the linear closed loop x_{k+1} = (A - BK) x_k:
+//! eigenvalues, controllability and observability ranks, and loop-at-a-time
+//! gain/phase margins at the RIK sample rate.
+
+use crate::control::{LinearPlanner, PlantModel};
+use crate::error::{DeoxysError, Result};
+use ndarray::{s, Array1, Array2};
+use num_complex::Complex64;
+use serde::Serialize;
+
+/// RIK sample period in seconds (20 Hz)
+pub const SAMPLE_PERIOD: f64 = 0.05;
+/// Frequency points on the upper unit semicircle used for margin search
+const FREQUENCY_POINTS: usize = 4096;
+/// QR sweeps allowed per eigenvalue before giving up
+const MAX_QR_ITERATIONS: usize = 60;
+
+/// Engine response to a design that is unstable or unobservable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StabilityPolicy {
+    /// Reject the control law
+    #[default]
+    Refuse,
+    /// Accept the control law and log the defects
+    Warn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Eigenvalue {
+    pub re: f64,
+    pub im: f64,
+    pub magnitude: f64,
+}
+
+/// Margins with loop `input` broken and all other loops closed.
+/// `None` means the corresponding crossover does not occur (infinite margin).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LoopMargin {
+    pub input: usize,
+    pub gain_margin_db: Option<f64>,
+    pub phase_crossover_hz: Option<f64>,
+    pub phase_margin_deg: Option<f64>,
+    pub gain_crossover_hz: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StabilityReport {
+    pub sample_period: f64,
+    pub states: usize,
+    pub inputs: usize,
+    pub outputs: usize,
+    /// Eigenvalues of A - BK
+    pub eigenvalues: Vec<Eigenvalue>,
+    pub spectral_radius: f64,
+    /// All closed-loop eigenvalues strictly inside the unit circle
+    pub stable: bool,
+    pub controllability_rank: usize,
+    pub observability_rank: usize,
+    pub margins: Vec<LoopMargin>,
+}
+
+impl StabilityReport {
+    pub fn analyze(
+        plant: &PlantModel,
+        planner: &LinearPlanner,
+        sample_period: f64,
+    ) -> Result<Self> {
+        planner.check(plant)?;
+        if !sample_period.is_finite() || sample_period <= 0.0 {
+            return Err(DeoxysError::config(
+                "sample period",
+                format!("{} must be finite and positive", sample_period),
+            ));
+        }
+
+        let closed_loop = planner.closed_loop(plant);
+        let eigenvalues: Vec<Eigenvalue> = eigenvalues(&closed_loop)?
+            .into_iter()
+            .map(|z| Eigenvalue {
+                re: z.re,
+                im: z.im,
+                magnitude: z.norm(),
+            })
+            .collect();
+        let spectral_radius = eigenvalues.iter().map(|e| e.magnitude).fold(0.0, f64::max);
+
+        let margins = (0..plant.inputs())
+            .map(|input| loop_margin(plant, planner, input, sample_period))
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            sample_period,
+            states: plant.states(),
+            inputs: plant.inputs(),
+            outputs: plant.outputs(),
+            eigenvalues,
+            spectral_radius,
+            stable: spectral_radius < 1.0,
+            controllability_rank: rank(&controllability(plant)),
+            observability_rank: rank(&observability(plant)),
+            margins,
+        })
+    }
+
+    pub fn controllable(&self) -> bool {
+        self.controllability_rank == self.states
+    }
+
+    pub fn observable(&self) -> bool {
+        self.observability_rank == self.states
+    }
+
+    /// Reasons the design must not run: instability or unobservable modes
+    pub fn defects(&self) -> Vec<String> {
+        let mut defects = Vec::new();
+        if !self.stable {
+            defects.push(format!(
+                "closed loop unstable: spectral radius {:.6} >= 1",
+                self.spectral_radius
+            ));
+        }
+        if !self.observable() {
+            defects.push(format!(
+                "unobservable: observability rank {} < {}",
+                self.observability_rank, self.states
+            ));
+        }
+        defects
+    }
+
+    /// Pretty-printed JSON report
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("stability report is always serializable")
+    }
+}
+
+/// [B, AB, ..., A^{n-1}B]
+fn controllability(plant: &PlantModel) -> Array2<f64> {
+    let (n, m) = (plant.states(), plant.inputs());
+    let mut matrix = Array2::zeros((n, n * m));
+    let mut block = plant.b().clone();
+    for k in 0..n {
+        matrix.slice_mut(s![.., k * m..(k + 1) * m]).assign(&block);
+        block = plant.a().dot(&block);
+    }
+    matrix
+}
+
+/// [C; CA; ...; CA^{n-1}]
+fn observability(plant: &PlantModel) -> Array2<f64> {
+    let (n, p) = (plant.states(), plant.outputs());
+    let mut matrix = Array2::zeros((n * p, n));
+    let mut block = plant.c().clone();
+    for k in 0..n {
+        matrix.slice_mut(s![k * p..(k + 1) * p, ..]).assign(&block);
+        block = block.dot(plant.a());
+    }
+    matrix
+}
+
+/// Numerical rank by Gaussian elimination with full pivoting
+fn rank(matrix: &Array2<f64>) -> usize {
+    let mut m = matrix.clone();
+    let (rows, cols) = m.dim();
+    let scale = m.iter().fold(0.0_f64, |acc, v| acc.max(v.abs()));
+    if scale == 0.0 {
+        return 0;
+    }
+    let tolerance = scale * rows.max(cols) as f64 * 1e-12;
+    let mut rank = 0;
+    while rank < rows.min(cols) {
+        let mut pivot = (rank, rank, 0.0);
+        for i in rank..rows {
+            for j in rank..cols {
+                if m[[i, j]].abs() > pivot.2 {
+                    pivot = (i, j, m[[i, j]].abs());
+                }
+            }
+        }
+        if pivot.2 <= tolerance {
+            break;
+        }
+        for j in 0..cols {
+            m.swap([rank, j], [pivot.0, j]);
+        }
+        for i in 0..rows {
+            m.swap([i, rank], [i, pivot.1]);
+        }
+        for i in rank + 1..rows {
+            let factor = m[[i, rank]] / m[[rank, rank]];
+            for j in rank..cols {
+                m[[i, j]] -= factor * m[[rank, j]];
+            }
+        }
+        rank += 1;
+    }
+    rank
+}
+
+/// Eigenvalues of a real square matrix: Hessenberg reduction by stabilized
+/// elimination, then Francis double-shift QR
+pub fn eigenvalues(matrix: &Array2<f64>) -> Result<Vec<Complex64>> {
+    let n = matrix.nrows();
+    if !matrix.is_square() {
+        return Err(DeoxysError::DimensionMismatch {
+            context: "eigenvalue matrix columns",
+            expected: n,
+            actual: matrix.ncols(),
+        });
+    }
+    // 1-based working copy keeps the QR index arithmetic readable
+    let mut a = vec![vec![0.0; n + 1]; n + 1];
+    for i in 0..n {
+        for j in 0..n {
+            a[i + 1][j + 1] = matrix[[i, j]];
+        }
+    }
+    hessenberg(&mut a, n);
+    hqr(&mut a, n)
+}
+
+// Index loops follow the 1-based textbook formulation
+#[allow(clippy::needless_range_loop)]
+fn hessenberg(a: &mut [Vec<f64>], n: usize) {
+    for m in 2..n {
+        let mut x = 0.0;
+        let mut i = m;
+        for j in m..=n {
+            if a[j][m - 1].abs() > f64::abs(x) {
+                x = a[j][m - 1];
+                i = j;
+            }
+        }
+        if i != m {
+            for j in m - 1..=n {
+                let tmp = a[i][j];
+                a[i][j] = a[m][j];
+                a[m][j] = tmp;
+            }
+            for row in a.iter_mut().skip(1) {
+                row.swap(i, m);
+            }
+        }
+        if x != 0.0 {
+            for i in m + 1..=n {
+                let mut y = a[i][m - 1];
+                if y != 0.0 {
+                    y /= x;
+                    a[i][m - 1] = y;
+                    for j in m..=n {
+                        a[i][j] -= y * a[m][j];
+                    }
+                    for j in 1..=n {
+                        a[j][m] += y * a[j][i];
+                    }
+                }
+            }
+        }
+    }
+    // Drop the elimination multipliers stored below the subdiagonal
+    for i in 3..=n {
+        for j in 1..i - 1 {
+            a[i][j] = 0.0;
+        }
+    }
+}
+
+#[allow(clippy::needless_range_loop)]
+fn hqr(a: &mut [Vec<f64>], n: usize) -> Result<Vec<Complex64>> {
+    let mut wr = vec![0.0; n + 1];
+    let mut wi = vec![0.0; n + 1];
+    let mut anorm = 0.0;
+    for i in 1..=n {
+        for j in i.saturating_sub(1).max(1)..=n {
+            anorm += a[i][j].abs();
+        }
+    }
+
+    let mut nn = n;
+    let mut t = 0.0;
+    while nn >= 1 {
+        let mut its = 0;
+        loop {
+            let mut l = nn;
+            while l >= 2 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = anorm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+            let mut x = a[nn][nn];
+            if l == nn {
+                // One root found
+                wr[nn] = x + t;
+                wi[nn] = 0.0;
+                nn -= 1;
+            } else {
+                let mut y = a[nn - 1][nn - 1];
+                let mut w = a[nn][nn - 1] * a[nn - 1][nn];
+                if l == nn - 1 {
+                    // Two roots found
+                    let p = 0.5 * (y - x);
+                    let q = p * p + w;
+                    let z = q.abs().sqrt();
+                    x += t;
+                    if q >= 0.0 {
+                        let z = p + z.copysign(p);
+                        wr[nn - 1] = x + z;
+                        wr[nn] = if z != 0.0 { x - w / z } else { x + z };
+                        wi[nn - 1] = 0.0;
+                        wi[nn] = 0.0;
+                    } else {
+                        wr[nn - 1] = x + p;
+                        wr[nn] = x + p;
+                        wi[nn - 1] = -z;
+                        wi[nn] = z;
+                    }
+                    nn -= 2;
+                } else {
+                    if its == MAX_QR_ITERATIONS {
+                        return Err(DeoxysError::config(
+                            "eigenvalue analysis",
+                            "QR iteration did not converge",
+                        ));
+                    }
+                    if its == 10 || its == 20 {
+                        // Exceptional shift
+                        t += x;
+                        for i in 1..=nn {
+                            a[i][i] -= x;
+                        }
+                        let s = a[nn][nn - 1].abs() + a[nn - 1][nn - 2].abs();
+                        x = 0.75 * s;
+                        y = x;
+                        w = -0.4375 * s * s;
+                    }
+                    its += 1;
+
+                    let (mut p, mut q, mut r): (f64, f64, f64);
+                    let mut m = nn - 2;
+                    loop {
+                        let z = a[m][m];
+                        let rr = x - z;
+                        let ss = y - z;
+                        p = (rr * ss - w) / a[m + 1][m] + a[m][m + 1];
+                        q = a[m + 1][m + 1] - z - rr - ss;
+                        r = a[m + 2][m + 1];
+                        let s = p.abs() + q.abs() + r.abs();
+                        p /= s;
+                        q /= s;
+                        r /= s;
+                        if m == l {
+                            break;
+                        }
+                        let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                        let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                        if u + v == v {
+                            break;
+                        }
+                        m -= 1;
+                    }
+                    for i in m + 2..=nn {
+                        a[i][i - 2] = 0.0;
+                        if i != m + 2 {
+                            a[i][i - 3] = 0.0;
+                        }
+                    }
+
+                    // Double QR step on rows l..nn and columns m..nn
+                    for k in m..nn {
+                        if k != m {
+                            p = a[k][k - 1];
+                            q = a[k + 1][k - 1];
+                            r = if k != nn - 1 { a[k + 2][k - 1] } else { 0.0 };
+                            x = p.abs() + q.abs() + r.abs();
+                            if x != 0.0 {
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+                        }
+                        let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                        if s == 0.0 {
+                            continue;
+                        }
+                        if k == m {
+                            if l != m {
+                                a[k][k - 1] = -a[k][k - 1];
+                            }
+                        } else {
+                            a[k][k - 1] = -s * x;
+                        }
+                        p += s;
+                        x = p / s;
+                        y = q / s;
+                        let z = r / s;
+                        q /= p;
+                        r /= p;
+                        for j in k..=nn {
+                            let mut p = a[k][j] + q * a[k + 1][j];
+                            if k != nn - 1 {
+                                p += r * a[k + 2][j];
+                                a[k + 2][j] -= p * z;
+                            }
+                            a[k + 1][j] -= p * y;
+                            a[k][j] -= p * x;
+                        }
+                        let mmin = nn.min(k + 3);
+                        for row in a.iter_mut().take(mmin + 1).skip(l) {
+                            let mut p = x * row[k] + y * row[k + 1];
+                            if k != nn - 1 {
+                                p += z * row[k + 2];
+                                row[k + 2] -= p * r;
+                            }
+                            row[k + 1] -= p * q;
+                            row[k] -= p;
+                        }
+                    }
+                }
+            }
+            if nn < 2 || l + 1 >= nn {
+                break;
+            }
+        }
+    }
+    Ok((1..=n).map(|i| Complex64::new(wr[i], wi[i])).collect())
+}
+
+/// Solve (zI - A) v = b for complex z by Gaussian elimination with partial pivoting
+fn resolvent(a: &Array2<f64>, b: &Array1<f64>, z: Complex64) -> Option<Vec<Complex64>> {
+    let n = a.nrows();
+    let mut m: Vec<Vec<Complex64>> = (0..n)
+        .map(|i| {
+            let mut row: Vec<Complex64> = (0..n)
+                .map(|j| {
+                    Complex64::new(-a[[i, j]], 0.0)
+                        + if i == j { z } else { Complex64::new(0.0, 0.0) }
+                })
+                .collect();
+            row.push(Complex64::new(b[i], 0.0));
+            row
+        })
+        .collect();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| m[i][col].norm().total_cmp(&m[j][col].norm()))?;
+        if m[pivot][col].norm() < f64::EPSILON {
+            return None;
+        }
+        m.swap(col, pivot);
+        let (upper, lower) = m.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            for (x, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+        }
+    }
+    let mut v = vec![Complex64::new(0.0, 0.0); n];
+    for i in (0..n).rev() {
+        let tail: Complex64 = (i + 1..n).map(|j| m[i][j] * v[j]).sum();
+        v[i] = (m[i][n] - tail) / m[i][i];
+    }
+    Some(v)
+}
+
+/// Break loop `input`: L(z) = k_i (zI - A_i)^{-1} b_i with A_i = A - sum_{j != i} b_j k_j
+fn loop_margin(
+    plant: &PlantModel,
+    planner: &LinearPlanner,
+    input: usize,
+    sample_period: f64,
+) -> Result<LoopMargin> {
+    let b_i = plant.b().column(input).to_owned();
+    let k_i = planner.gain().row(input).to_owned();
+    let mut others = planner.gain().clone();
+    others.row_mut(input).fill(0.0);
+    let a_i = plant.a() - &plant.b().dot(&others);
+
+    let response: Vec<(f64, Complex64)> = (1..=FREQUENCY_POINTS)
+        .filter_map(|k| {
+            let theta = std::f64::consts::PI * k as f64 / FREQUENCY_POINTS as f64;
+            let v = resolvent(&a_i, &b_i, Complex64::from_polar(1.0, theta))?;
+            let l: Complex64 = k_i.iter().zip(&v).map(|(&k, &v)| v * k).sum();
+            Some((theta, l))
+        })
+        .collect();
+    let hz = |theta: f64| theta / (std::f64::consts::TAU * sample_period);
+
+    let mut gain_margin: Option<(f64, f64)> = None;
+    let mut phase_margin: Option<(f64, f64)> = None;
+    for pair in response.windows(2) {
+        let ((t0, l0), (t1, l1)) = (pair[0], pair[1]);
+        // Phase crossover: L(z) crosses the negative real axis
+        if l0.im * l1.im < 0.0 || (l1.im == 0.0 && l0.im != 0.0) {
+            let f = l0.im / (l0.im - l1.im);
+            let re = l0.re + f * (l1.re - l0.re);
+            if re < 0.0 {
+                let margin = -20.0 * (-re).log10();
+                if gain_margin.is_none_or(|(gm, _)| margin < gm) {
+                    gain_margin = Some((margin, t0 + f * (t1 - t0)));
+                }
+            }
+        }
+        // Gain crossover: |L(z)| crosses unity
+        let (g0, g1) = (l0.norm() - 1.0, l1.norm() - 1.0);
+        if g0 * g1 < 0.0 || (g1 == 0.0 && g0 != 0.0) {
+            let f = g0 / (g0 - g1);
+            let l = l0 + (l1 - l0) * f;
+            let margin = 180.0 - l.arg().to_degrees().abs();
+            if phase_margin.is_none_or(|(pm, _)| margin < pm) {
+                phase_margin = Some((margin, t0 + f * (t1 - t0)));
+            }
+        }
+    }
+
+    // L is real at the Nyquist frequency (z = -1) for a real plant
+    if let Some(&(theta, l)) = response.last() {
+        if l.re < 0.0 && l.im.abs() <= 1e-9 * l.norm() {
+            let margin = -20.0 * (-l.re).log10();
+            if gain_margin.is_none_or(|(gm, _)| margin < gm) {
+                gain_margin = Some((margin, theta));
+            }
+        }
+    }
+
+    Ok(LoopMargin {
+        input,
+        gain_margin_db: gain_margin.map(|(gm, _)| gm),
+        phase_crossover_hz: gain_margin.map(|(_, theta)| hz(theta)),
+        phase_margin_deg: phase_margin.map(|(pm, _)| pm),
+        gain_crossover_hz: phase_margin.map(|(_, theta)| hz(theta)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn sorted(mut values: Vec<Complex64>) -> Vec<Complex64> {
+        values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        values
+    }
+
+    #[test]
+    fn test_eigenvalues_real_and_complex() {
+        let upper = array![[0.5, 2.0, 1.0], [0.0, -0.3, 4.0], [0.0, 0.0, 0.9]];
+        let values = sorted(eigenvalues(&upper).unwrap());
+        for (value, expected) in values.iter().zip([-0.3, 0.5, 0.9]) {
+            assert!((value.re - expected).abs() < 1e-10 && value.im.abs() < 1e-10);
+        }
+
+        // 0.9 * rotation by 30 degrees
+        let (c, s) = (
+            0.9 * 30f64.to_radians().cos(),
+            0.9 * 30f64.to_radians().sin(),
+        );
+        let rotation = array![[c, -s], [s, c]];
+        for value in eigenvalues(&rotation).unwrap() {
+            assert!((value.norm() - 0.9).abs() < 1e-10);
+            assert!((value.im.abs() - s).abs() < 1e-10);
+        }
+
+        // Companion matrix of (z - 0.5)(z + 0.25)(z - 1.5)(z^2 + 0.64)
+        let coeffs = [-1.75, 0.89, -0.9325, 0.16, 0.12];
+        let mut companion = Array2::zeros((5, 5));
+        for (j, c) in coeffs.iter().enumerate() {
+            companion[[0, j]] = -c;
+        }
+        for i in 1..5 {
+            companion[[i, i - 1]] = 1.0;
+        }
+        let values = sorted(eigenvalues(&companion).unwrap());
+        let expected = [
+            (-0.25, 0.0),
+            (0.0, -0.8),
+            (0.0, 0.8),
+            (0.5, 0.0),
+            (1.5, 0.0),
+        ];
+        for (value, (re, im)) in values.iter().zip(expected) {
+            assert!(
+                (value.re - re).abs() < 1e-8 && (value.im - im).abs() < 1e-8,
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_ranks() {
+        // Double integrator driven through velocity only
+        let plant = PlantModel::new(
+            array![[1.0, SAMPLE_PERIOD], [0.0, 1.0]],
+            array![[0.0], [SAMPLE_PERIOD]],
+        )
+        .unwrap()
+        .with_output(array![[1.0, 0.0]])
+        .unwrap();
+        assert_eq!(rank(&controllability(&plant)), 2);
+        assert_eq!(rank(&observability(&plant)), 2);
+
+        let blind = plant.with_output(array![[0.0, 1.0]]).unwrap();
+        assert_eq!(rank(&observability(&blind)), 1);
+    }
+
+    #[test]
+    fn test_scalar_loop_margins() {
+        // x+ = x + u, u = -0.5 x: L(z) = 0.5 / (z - 1), phase crossover at z = -1
+        let plant = PlantModel::new(array![[1.0]], array![[1.0]]).unwrap();
+        let planner = LinearPlanner::new(array![[0.5]]).unwrap();
+        let report = StabilityReport::analyze(&plant, &planner, SAMPLE_PERIOD).unwrap();
+
+        assert!(report.stable);
+        assert!((report.spectral_radius - 0.5).abs() < 1e-12);
+        let margin = &report.margins[0];
+        // |L(-1)| = 0.25 -> 12.04 dB at the Nyquist frequency (10 Hz)
+        assert!((margin.gain_margin_db.unwrap() - 20.0 * 4f64.log10()).abs() < 1e-6);
+        assert!((margin.phase_crossover_hz.unwrap() - 10.0).abs() < 1e-6);
+        // |e^{jw} - 1| = 0.5 at w = 2 asin(0.25); PM = 180 - |arg L| = 90 - w/2
+        let w = 2.0 * 0.25f64.asin();
+        assert!((margin.phase_margin_deg.unwrap() - (90.0 - w.to_degrees() / 2.0)).abs() < 0.05);
+        assert!(report.defects().is_empty());
+    }
+
+    #[test]
+    fn test_defects_and_json() {
+        let plant = PlantModel::new(array![[1.1, 0.0], [0.0, 0.5]], array![[1.0], [0.0]])
+            .unwrap()
+            .with_output(array![[0.0, 1.0]])
+            .unwrap();
+        let planner = LinearPlanner::new(array![[0.05, 0.0]]).unwrap();
+        let report = StabilityReport::analyze(&plant, &planner, SAMPLE_PERIOD).unwrap();
+
+        assert!(!report.stable);
+        assert!(!report.controllable());
+        assert!(!report.observable());
+        assert_eq!(report.defects().len(), 2);
+
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["sample_period"], 0.05);
+        assert_eq!(json["observability_rank"], 1);
+        assert_eq!(json["eigenvalues"].as_array().unwrap().len(), 2);
+    }
+}
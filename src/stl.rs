@@ -0,0 +1,874 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Signal temporal logic (bounded STL / past-time LTL) runtime monitors.
+//!
+//! Formulas are evaluated online over the per-cycle trace of belief states and
+//! controls with quantitative (robustness) semantics: a positive robustness
+//! means the formula holds with that margin, a negative one that it is violated.
+//! Time is measured in cycles.
+//!
+//! Grammar (lowest to highest precedence):
+//!
+//! ```text
+//! formula := or ("->" formula)?
+//! or      := and ("||" and)*
+//! and     := binary ("&&" binary)*
+//! binary  := unary (("U" | "S") interval unary)?
+//! unary   := "!" unary | ("G" | "F" | "H" | "O") interval unary | "(" formula ")"
+//!          | "true" | "false" | "bounds_changed" | signal cmp number
+//! signal  := "x[" n "]" | "u[" n "]" | "energy" | "variance"
+//! cmp     := "<" | "<=" | ">" | ">="
+//! interval:= "[" a "," b "]"
+//! ```
+//!
+//! `G`/`F`/`U` look forward (always, eventually, until) and delay the verdict
+//! for a cycle by the formula's horizon; `H`/`O`/`S` look backward
+//! (historically, once, since). Samples before the first cycle are false.
+//!
+//! Examples:
+//! - `bounds_changed -> F[0,40] energy < 0.1`
+//! - `!(H[0,5] x[3] > 0.8)`
+
+use crate::error::{DeoxysError, Result};
+use crate::invariants::{energy, Invariant, InvariantContext, Verdict};
+use crate::rik::OperatorBounds;
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Per-cycle observation the monitors evaluate against
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceSample {
+    pub cycle: u64,
+    pub state: Vec<f64>,
+    pub control: Vec<f64>,
+    pub energy: f64,
+    pub variance: f64,
+    pub bounds_changed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Signal {
+    State(usize),
+    Control(usize),
+    Energy,
+    Variance,
+    BoundsChanged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cmp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Interval {
+    lo: u64,
+    hi: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Formula {
+    True,
+    False,
+    Atom {
+        signal: Signal,
+        cmp: Cmp,
+        threshold: f64,
+    },
+    Not(Box<Formula>),
+    And(Box<Formula>, Box<Formula>),
+    Or(Box<Formula>, Box<Formula>),
+    Implies(Box<Formula>, Box<Formula>),
+    Always(Interval, Box<Formula>),
+    Eventually(Interval, Box<Formula>),
+    Until(Interval, Box<Formula>, Box<Formula>),
+    Historically(Interval, Box<Formula>),
+    Once(Interval, Box<Formula>),
+    Since(Interval, Box<Formula>, Box<Formula>),
+}
+
+impl Formula {
+    /// Cycles of lookahead needed before the formula can be decided
+    fn horizon(&self) -> u64 {
+        match self {
+            Formula::True | Formula::False | Formula::Atom { .. } => 0,
+            Formula::Not(f) | Formula::Historically(_, f) | Formula::Once(_, f) => f.horizon(),
+            Formula::And(a, b)
+            | Formula::Or(a, b)
+            | Formula::Implies(a, b)
+            | Formula::Since(_, a, b) => a.horizon().max(b.horizon()),
+            Formula::Always(i, f) | Formula::Eventually(i, f) => i.hi + f.horizon(),
+            Formula::Until(i, a, b) => i.hi + a.horizon().max(b.horizon()),
+        }
+    }
+
+    /// Cycles of history needed to decide the formula
+    fn depth(&self) -> u64 {
+        match self {
+            Formula::True | Formula::False | Formula::Atom { .. } => 0,
+            Formula::Not(f) | Formula::Always(_, f) | Formula::Eventually(_, f) => f.depth(),
+            Formula::And(a, b)
+            | Formula::Or(a, b)
+            | Formula::Implies(a, b)
+            | Formula::Until(_, a, b) => a.depth().max(b.depth()),
+            Formula::Historically(i, f) | Formula::Once(i, f) => i.hi + f.depth(),
+            Formula::Since(i, a, b) => i.hi + a.depth().max(b.depth()),
+        }
+    }
+
+    /// Highest state and control channel referenced, for dimension checks
+    fn channels(&self) -> (Option<usize>, Option<usize>) {
+        fn merge(
+            a: (Option<usize>, Option<usize>),
+            b: (Option<usize>, Option<usize>),
+        ) -> (Option<usize>, Option<usize>) {
+            (a.0.max(b.0), a.1.max(b.1))
+        }
+        match self {
+            Formula::True | Formula::False => (None, None),
+            Formula::Atom {
+                signal: Signal::State(i),
+                ..
+            } => (Some(*i), None),
+            Formula::Atom {
+                signal: Signal::Control(i),
+                ..
+            } => (None, Some(*i)),
+            Formula::Atom { .. } => (None, None),
+            Formula::Not(f)
+            | Formula::Always(_, f)
+            | Formula::Eventually(_, f)
+            | Formula::Historically(_, f)
+            | Formula::Once(_, f) => f.channels(),
+            Formula::And(a, b)
+            | Formula::Or(a, b)
+            | Formula::Implies(a, b)
+            | Formula::Until(_, a, b)
+            | Formula::Since(_, a, b) => merge(a.channels(), b.channels()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(f64),
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Not,
+    And,
+    Or,
+    Implies,
+    Cmp(Cmp),
+}
+
+fn parse_error(offset: usize, reason: impl fmt::Display) -> DeoxysError {
+    DeoxysError::config("stl formula", format!("{} at offset {}", reason, offset))
+}
+
+fn tokenize(src: &str) -> Result<Vec<(usize, Token)>> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i] as char;
+        let start = i;
+        let two = |next: u8| bytes.get(i + 1) == Some(&next);
+        let token = match c {
+            c if c.is_ascii_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            ',' => Token::Comma,
+            '!' => Token::Not,
+            '&' if two(b'&') => {
+                i += 1;
+                Token::And
+            }
+            '|' if two(b'|') => {
+                i += 1;
+                Token::Or
+            }
+            '-' if two(b'>') => {
+                i += 1;
+                Token::Implies
+            }
+            '<' | '>' => {
+                let inclusive = two(b'=');
+                if inclusive {
+                    i += 1;
+                }
+                Token::Cmp(match (c, inclusive) {
+                    ('<', false) => Cmp::Lt,
+                    ('<', true) => Cmp::Le,
+                    ('>', false) => Cmp::Gt,
+                    _ => Cmp::Ge,
+                })
+            }
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                i += 1;
+                while i < bytes.len() {
+                    let d = bytes[i] as char;
+                    let exponent_sign =
+                        (d == '-' || d == '+') && matches!(bytes[i - 1], b'e' | b'E');
+                    if d.is_ascii_digit() || d == '.' || d == 'e' || d == 'E' || exponent_sign {
+                        i += 1;
+                    } else {
+                        break;
+                    }
+                }
+                let text = &src[start..i];
+                let value = text
+                    .parse::<f64>()
+                    .map_err(|_| parse_error(start, format!("invalid number '{}'", text)))?;
+                tokens.push((start, Token::Number(value)));
+                continue;
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                tokens.push((start, Token::Ident(src[start..i].to_string())));
+                continue;
+            }
+            other => {
+                return Err(parse_error(
+                    start,
+                    format!("unexpected character '{}'", other),
+                ))
+            }
+        };
+        tokens.push((start, token));
+        i += 1;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(_, t)| t)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map(|(o, _)| *o)
+            .unwrap_or(self.end)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(_, t)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> Result<()> {
+        let offset = self.offset();
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(parse_error(offset, format!("expected {}", what))),
+        }
+    }
+
+    fn formula(&mut self) -> Result<Formula> {
+        let lhs = self.or()?;
+        if self.peek() == Some(&Token::Implies) {
+            self.next();
+            let rhs = self.formula()?;
+            return Ok(Formula::Implies(Box::new(lhs), Box::new(rhs)));
+        }
+        Ok(lhs)
+    }
+
+    fn or(&mut self) -> Result<Formula> {
+        let mut lhs = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.next();
+            lhs = Formula::Or(Box::new(lhs), Box::new(self.and()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and(&mut self) -> Result<Formula> {
+        let mut lhs = self.binary()?;
+        while self.peek() == Some(&Token::And) {
+            self.next();
+            lhs = Formula::And(Box::new(lhs), Box::new(self.binary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn binary(&mut self) -> Result<Formula> {
+        let lhs = self.unary()?;
+        let op = match self.peek() {
+            Some(Token::Ident(name)) if name == "U" || name == "S" => name.clone(),
+            _ => return Ok(lhs),
+        };
+        self.next();
+        let interval = self.interval()?;
+        let rhs = self.unary()?;
+        Ok(if op == "U" {
+            Formula::Until(interval, Box::new(lhs), Box::new(rhs))
+        } else {
+            Formula::Since(interval, Box::new(lhs), Box::new(rhs))
+        })
+    }
+
+    fn unary(&mut self) -> Result<Formula> {
+        let offset = self.offset();
+        match self.next() {
+            Some(Token::Not) => Ok(Formula::Not(Box::new(self.unary()?))),
+            Some(Token::LParen) => {
+                let inner = self.formula()?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(inner)
+            }
+            Some(Token::Ident(name)) => match name.as_str() {
+                "G" | "F" | "H" | "O" => {
+                    let interval = self.interval()?;
+                    let body = Box::new(self.unary()?);
+                    Ok(match name.as_str() {
+                        "G" => Formula::Always(interval, body),
+                        "F" => Formula::Eventually(interval, body),
+                        "H" => Formula::Historically(interval, body),
+                        _ => Formula::Once(interval, body),
+                    })
+                }
+                "true" => Ok(Formula::True),
+                "false" => Ok(Formula::False),
+                "bounds_changed" => Ok(Formula::Atom {
+                    signal: Signal::BoundsChanged,
+                    cmp: Cmp::Ge,
+                    threshold: 0.5,
+                }),
+                _ => {
+                    let signal = self.signal(&name, offset)?;
+                    self.atom(signal)
+                }
+            },
+            _ => Err(parse_error(offset, "expected formula")),
+        }
+    }
+
+    fn signal(&mut self, name: &str, offset: usize) -> Result<Signal> {
+        match name {
+            "energy" => Ok(Signal::Energy),
+            "variance" => Ok(Signal::Variance),
+            "x" | "u" => {
+                self.expect(Token::LBracket, "'[' after channel signal")?;
+                let index = self.index()?;
+                self.expect(Token::RBracket, "']'")?;
+                Ok(if name == "x" {
+                    Signal::State(index)
+                } else {
+                    Signal::Control(index)
+                })
+            }
+            other => Err(parse_error(offset, format!("unknown signal '{}'", other))),
+        }
+    }
+
+    fn atom(&mut self, signal: Signal) -> Result<Formula> {
+        let offset = self.offset();
+        let cmp = match self.next() {
+            Some(Token::Cmp(cmp)) => cmp,
+            _ => return Err(parse_error(offset, "expected comparison")),
+        };
+        let offset = self.offset();
+        match self.next() {
+            Some(Token::Number(threshold)) if threshold.is_finite() => Ok(Formula::Atom {
+                signal,
+                cmp,
+                threshold,
+            }),
+            _ => Err(parse_error(offset, "expected finite number")),
+        }
+    }
+
+    fn index(&mut self) -> Result<usize> {
+        let offset = self.offset();
+        match self.next() {
+            Some(Token::Number(n)) if n >= 0.0 && n.fract() == 0.0 && n <= u32::MAX as f64 => {
+                Ok(n as usize)
+            }
+            _ => Err(parse_error(offset, "expected non-negative integer")),
+        }
+    }
+
+    fn interval(&mut self) -> Result<Interval> {
+        let offset = self.offset();
+        self.expect(Token::LBracket, "'[' to open interval")?;
+        let lo = self.index()? as u64;
+        self.expect(Token::Comma, "','")?;
+        let hi = self.index()? as u64;
+        self.expect(Token::RBracket, "']' to close interval")?;
+        if lo > hi {
+            return Err(parse_error(
+                offset,
+                format!("empty interval [{}, {}]", lo, hi),
+            ));
+        }
+        Ok(Interval { lo, hi })
+    }
+}
+
+/// Parsed STL formula with its source text
+#[derive(Debug, Clone, PartialEq)]
+pub struct StlFormula {
+    source: String,
+    root: Formula,
+}
+
+impl StlFormula {
+    pub fn parse(source: &str) -> Result<Self> {
+        let tokens = tokenize(source)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: source.len(),
+        };
+        let root = parser.formula()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(parse_error(parser.offset(), "unexpected trailing input"));
+        }
+        Ok(Self {
+            source: source.trim().to_string(),
+            root,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Cycles between a sample arriving and its verdict being available
+    pub fn horizon(&self) -> u64 {
+        self.root.horizon()
+    }
+
+    /// Cycles of history the formula looks back over
+    pub fn depth(&self) -> u64 {
+        self.root.depth()
+    }
+}
+
+impl fmt::Display for StlFormula {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Resolved verdict for the formula evaluated at `cycle`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonitorVerdict {
+    pub cycle: u64,
+    pub robustness: f64,
+}
+
+impl MonitorVerdict {
+    /// Non-negative robustness is treated as satisfaction
+    pub fn satisfied(&self) -> bool {
+        self.robustness >= 0.0
+    }
+}
+
+/// Online monitor over a bounded window of the trace
+pub struct StlMonitor {
+    formula: StlFormula,
+    window: VecDeque<TraceSample>,
+    /// Trace position of `window[0]`
+    first: u64,
+    /// Number of samples observed so far
+    observed: u64,
+}
+
+impl StlMonitor {
+    pub fn new(formula: StlFormula) -> Self {
+        Self {
+            formula,
+            window: VecDeque::new(),
+            first: 0,
+            observed: 0,
+        }
+    }
+
+    pub fn formula(&self) -> &StlFormula {
+        &self.formula
+    }
+
+    /// Append a sample; returns the verdict for the position `horizon` cycles back, once decidable
+    pub fn observe(&mut self, sample: TraceSample) -> Result<Option<MonitorVerdict>> {
+        let (state, control) = self.formula.root.channels();
+        for (context, needed, actual) in [
+            ("stl state signal", state, sample.state.len()),
+            ("stl control signal", control, sample.control.len()),
+        ] {
+            if let Some(index) = needed {
+                if index >= actual {
+                    return Err(DeoxysError::DimensionMismatch {
+                        context,
+                        expected: index + 1,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        self.window.push_back(sample);
+        self.observed += 1;
+        let capacity = (self.formula.horizon() + self.formula.depth() + 1) as usize;
+        while self.window.len() > capacity {
+            self.window.pop_front();
+            self.first += 1;
+        }
+
+        let horizon = self.formula.horizon();
+        if self.observed <= horizon {
+            return Ok(None);
+        }
+        let position = self.observed - 1 - horizon;
+        let robustness = self.rho(&self.formula.root, position as i64);
+        let cycle = self.window[(position - self.first) as usize].cycle;
+        Ok(Some(MonitorVerdict { cycle, robustness }))
+    }
+
+    fn sample(&self, position: i64) -> Option<&TraceSample> {
+        if position < self.first as i64 {
+            return None;
+        }
+        self.window.get((position - self.first as i64) as usize)
+    }
+
+    fn rho(&self, formula: &Formula, t: i64) -> f64 {
+        match formula {
+            Formula::True => f64::INFINITY,
+            Formula::False => f64::NEG_INFINITY,
+            Formula::Atom {
+                signal,
+                cmp,
+                threshold,
+            } => {
+                let Some(sample) = self.sample(t) else {
+                    return f64::NEG_INFINITY;
+                };
+                let value = match *signal {
+                    Signal::State(i) => sample.state[i],
+                    Signal::Control(i) => sample.control[i],
+                    Signal::Energy => sample.energy,
+                    Signal::Variance => sample.variance,
+                    Signal::BoundsChanged => f64::from(u8::from(sample.bounds_changed)),
+                };
+                match cmp {
+                    Cmp::Lt | Cmp::Le => threshold - value,
+                    Cmp::Gt | Cmp::Ge => value - threshold,
+                }
+            }
+            Formula::Not(f) => -self.rho(f, t),
+            Formula::And(a, b) => self.rho(a, t).min(self.rho(b, t)),
+            Formula::Or(a, b) => self.rho(a, t).max(self.rho(b, t)),
+            Formula::Implies(a, b) => (-self.rho(a, t)).max(self.rho(b, t)),
+            Formula::Always(i, f) => Self::span(t, i, true)
+                .map(|s| self.rho(f, s))
+                .fold(f64::INFINITY, f64::min),
+            Formula::Eventually(i, f) => Self::span(t, i, true)
+                .map(|s| self.rho(f, s))
+                .fold(f64::NEG_INFINITY, f64::max),
+            Formula::Historically(i, f) => Self::span(t, i, false)
+                .map(|s| self.rho(f, s))
+                .fold(f64::INFINITY, f64::min),
+            Formula::Once(i, f) => Self::span(t, i, false)
+                .map(|s| self.rho(f, s))
+                .fold(f64::NEG_INFINITY, f64::max),
+            Formula::Until(i, a, b) => Self::span(t, i, true)
+                .map(|s| {
+                    let hold = (t..s).map(|r| self.rho(a, r)).fold(f64::INFINITY, f64::min);
+                    self.rho(b, s).min(hold)
+                })
+                .fold(f64::NEG_INFINITY, f64::max),
+            Formula::Since(i, a, b) => Self::span(t, i, false)
+                .map(|s| {
+                    let hold = (s + 1..=t)
+                        .map(|r| self.rho(a, r))
+                        .fold(f64::INFINITY, f64::min);
+                    self.rho(b, s).min(hold)
+                })
+                .fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+
+    /// Positions covered by an interval relative to `t`, forward or backward
+    fn span(t: i64, interval: &Interval, forward: bool) -> impl Iterator<Item = i64> {
+        let (lo, hi) = (interval.lo as i64, interval.hi as i64);
+        let (start, end) = if forward {
+            (t + lo, t + hi)
+        } else {
+            (t - hi, t - lo)
+        };
+        start..=end
+    }
+}
+
+/// Adapts an STL monitor to the invariant registry
+///
+/// Fails the cycle in which a negative verdict is resolved; with a future
+/// horizon that is later than the cycle the verdict refers to.
+pub struct StlInvariant {
+    name: String,
+    monitor: StlMonitor,
+    warn_margin: f64,
+    last_bounds: Option<OperatorBounds>,
+    last_verdict: Option<MonitorVerdict>,
+}
+
+impl StlInvariant {
+    pub fn new(name: &str, formula: StlFormula) -> Self {
+        Self {
+            name: name.to_string(),
+            monitor: StlMonitor::new(formula),
+            warn_margin: 0.0,
+            last_bounds: None,
+            last_verdict: None,
+        }
+    }
+
+    pub fn parse(name: &str, source: &str) -> Result<Self> {
+        Ok(Self::new(name, StlFormula::parse(source)?))
+    }
+
+    /// Warn when a verdict holds with robustness below `margin`
+    pub fn with_warning_margin(mut self, margin: f64) -> Self {
+        self.warn_margin = margin;
+        self
+    }
+
+    pub fn last_verdict(&self) -> Option<MonitorVerdict> {
+        self.last_verdict
+    }
+}
+
+impl Invariant for StlInvariant {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn evaluate(&mut self, ctx: &InvariantContext) -> Verdict {
+        let bounds_changed = self.last_bounds.is_some_and(|b| b != ctx.bounds);
+        self.last_bounds = Some(ctx.bounds);
+
+        let sample = TraceSample {
+            cycle: ctx.cycle,
+            state: ctx.state.to_vec(),
+            control: ctx.control.to_vec(),
+            energy: energy(ctx.state),
+            variance: ctx.state.var(0.0),
+            bounds_changed,
+        };
+        let verdict = match self.monitor.observe(sample) {
+            Ok(Some(verdict)) => verdict,
+            Ok(None) => return Verdict::Pass,
+            Err(error) => return Verdict::Fail(error),
+        };
+        self.last_verdict = Some(verdict);
+
+        let formula = self.monitor.formula();
+        if !verdict.satisfied() {
+            Verdict::Fail(DeoxysError::InvariantViolation {
+                invariant: self.name.clone(),
+                cycle: ctx.cycle,
+                detail: format!(
+                    "formula '{}' violated at cycle {} (robustness {})",
+                    formula, verdict.cycle, verdict.robustness
+                ),
+            })
+        } else if verdict.robustness < self.warn_margin {
+            Verdict::Warn(format!(
+                "formula '{}' holds at cycle {} with robustness {} (margin {})",
+                formula, verdict.cycle, verdict.robustness, self.warn_margin
+            ))
+        } else {
+            Verdict::Pass
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(cycle: u64, channel3: f64, energy: f64, bounds_changed: bool) -> TraceSample {
+        TraceSample {
+            cycle,
+            state: vec![0.0, 0.0, 0.0, channel3],
+            control: vec![0.0; 4],
+            energy,
+            variance: 0.0,
+            bounds_changed,
+        }
+    }
+
+    fn run(source: &str, trace: &[TraceSample]) -> Vec<MonitorVerdict> {
+        let mut monitor = StlMonitor::new(StlFormula::parse(source).unwrap());
+        trace
+            .iter()
+            .filter_map(|s| monitor.observe(s.clone()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_errors_report_offset() {
+        let err = StlFormula::parse("G[0,5] (x[1] < 0.5").unwrap_err();
+        assert!(err.to_string().contains("')'"), "{}", err);
+        assert!(StlFormula::parse("F[5,2] energy < 1").is_err());
+        assert!(StlFormula::parse("speed < 1").is_err());
+        assert!(StlFormula::parse("x[1] < 0.5 extra").is_err());
+        assert!(StlFormula::parse("G energy < 1").is_err());
+    }
+
+    #[test]
+    fn test_horizon_and_depth() {
+        let formula = StlFormula::parse("bounds_changed -> F[0,40] energy < 0.1").unwrap();
+        assert_eq!((formula.horizon(), formula.depth()), (40, 0));
+        let formula = StlFormula::parse("!(H[0,5] x[3] > 0.8)").unwrap();
+        assert_eq!((formula.horizon(), formula.depth()), (0, 5));
+    }
+
+    #[test]
+    fn test_atom_robustness() {
+        let verdicts = run(
+            "x[3] <= 0.8 && energy > -1e-3",
+            &[sample(1, 0.5, 0.5, false)],
+        );
+        assert_eq!(verdicts.len(), 1);
+        assert!(
+            (verdicts[0].robustness - 0.3).abs() < 1e-12,
+            "{:?}",
+            verdicts
+        );
+    }
+
+    #[test]
+    fn test_energy_settles_after_bounds_change() {
+        // Bounds change at cycle 2; energy falls below 0.1 at cycle 5 (within 3)
+        let trace: Vec<_> = [
+            (0.5, false),
+            (0.5, true),
+            (0.3, false),
+            (0.2, false),
+            (0.05, false),
+            (0.04, false),
+            (0.03, false),
+            (0.03, false),
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, &(e, changed))| sample(i as u64 + 1, 0.0, e, changed))
+        .collect();
+
+        let verdicts = run("bounds_changed -> F[0,3] energy < 0.1", &trace);
+        // Verdicts lag by the 3-cycle horizon
+        assert_eq!(verdicts.first().unwrap().cycle, 1);
+        assert!(verdicts.iter().all(|v| v.satisfied()));
+
+        let verdicts = run("bounds_changed -> F[0,2] energy < 0.1", &trace);
+        let failing: Vec<_> = verdicts.iter().filter(|v| !v.satisfied()).collect();
+        assert_eq!(failing.len(), 1);
+        assert_eq!(failing[0].cycle, 2);
+        assert!((failing[0].robustness + 0.1).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_consecutive_exceedance() {
+        // Channel 3 above 0.8 for 6 consecutive cycles (3..=8) violates at cycle 8
+        let trace: Vec<_> = (1..=10)
+            .map(|c| sample(c, if (3..=8).contains(&c) { 0.9 } else { 0.1 }, 0.0, false))
+            .collect();
+        let verdicts = run("!(H[0,5] x[3] > 0.8)", &trace);
+        assert_eq!(verdicts.len(), 10);
+        let failing: Vec<u64> = verdicts
+            .iter()
+            .filter(|v| !v.satisfied())
+            .map(|v| v.cycle)
+            .collect();
+        assert_eq!(failing, vec![8]);
+
+        // Five cycles is tolerated
+        let trace: Vec<_> = (1..=10)
+            .map(|c| sample(c, if (3..=7).contains(&c) { 0.9 } else { 0.1 }, 0.0, false))
+            .collect();
+        assert!(run("!(H[0,5] x[3] > 0.8)", &trace)
+            .iter()
+            .all(|v| v.satisfied()));
+    }
+
+    #[test]
+    fn test_until_and_since() {
+        let trace: Vec<_> = [0.1, 0.2, 0.3, 0.9, 0.1]
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| sample(i as u64 + 1, x, 0.0, false))
+            .collect();
+        // x[3] stays below 0.5 until it exceeds 0.8 within 3 cycles
+        let verdicts = run("x[3] < 0.5 U[0,3] x[3] > 0.8", &trace);
+        assert!(verdicts[0].satisfied());
+        // Since the spike, x[3] has stayed below 0.5
+        let verdicts = run("x[3] < 0.5 S[0,4] x[3] > 0.8", &trace);
+        assert!(verdicts[4].satisfied());
+        assert!(!verdicts[2].satisfied());
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let mut monitor = StlMonitor::new(StlFormula::parse("x[7] < 1").unwrap());
+        let err = monitor.observe(sample(1, 0.0, 0.0, false)).unwrap_err();
+        assert_eq!(err.code(), "dimension_mismatch");
+    }
+
+    #[test]
+    fn test_invariant_tracks_bounds_changes() {
+        use ndarray::{Array1, Array2};
+
+        let mut invariant = StlInvariant::parse("no-bounds-change", "!bounds_changed").unwrap();
+        let state = Array1::from_vec(vec![0.1; 4]);
+        let covariance = Array2::eye(4);
+        let history = VecDeque::new();
+        let mut evaluate = |cycle, bounds| {
+            invariant.evaluate(&InvariantContext {
+                cycle,
+                state: &state,
+                control: &state,
+                covariance: &covariance,
+                history: &history,
+                bounds,
+            })
+        };
+
+        assert_eq!(evaluate(1, OperatorBounds::default()), Verdict::Pass);
+        assert_eq!(evaluate(2, OperatorBounds::default()), Verdict::Pass);
+        match evaluate(3, OperatorBounds::new(-0.5, 0.5).unwrap()) {
+            Verdict::Fail(DeoxysError::InvariantViolation {
+                invariant, cycle, ..
+            }) => {
+                assert_eq!((invariant.as_str(), cycle), ("no-bounds-change", 3));
+            }
+            other => panic!("expected failure, got {:?}", other),
+        }
+    }
+}
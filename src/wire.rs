@@ -0,0 +1,410 @@
+// Copyright (c) 2025 Axiom Hive. All Rights Reserved.
+// Author: Alexis Adams <sovereign@axiomhive.net>
+// SPDX-License-Identifier: Proprietary
+
+//! Self-describing envelope for exchanging CKKS ciphertexts between agents.
+//!
+//! All integers are little-endian:
+//!
+//! ```text
+//! offset  size  field
+//!      0     4  magic "DXWE"
+//!      4     2  version (2)
+//!      6     1  parameter set code (named presets only)
+//!      7     1  level
+//!      8     8  scale (f64)
+//!     16    32  key id: SHA-256 of the encrypting public key
+//!     48     4  payload length n
+//!     52     n  payload: c0 then c1, (level + 1) residues of 2^log_degree u64 each
+//! 52 + n    32  integrity tag over bytes 0 .. 52 + n
+//! ```
+//!
+//! The tag is SHA-256 over the canonical `deoxys/wire-envelope` domain header
+//! followed by the envelope bytes, so it cannot be confused with any other
+//! digest in the system. Version 1 envelopes, tagged without a domain, are
+//! rejected.
+//!
+//! Parsing borrows the payload without copying and checks every field,
+//! including the exact payload length implied by the parameter set and level,
+//! before the tag; it never panics on arbitrary input. Coefficient ranges
+//! are checked when the payload is decoded against a context.
+
+use crate::canonical::{tagged_digest, Domain};
+use crate::ckks::{Ciphertext, CkksContext, ParameterSet};
+use crate::error::{DeoxysError, Result};
+use serde::Serialize;
+use std::fmt;
+
+pub const WIRE_MAGIC: [u8; 4] = *b"DXWE";
+pub const WIRE_VERSION: u16 = 2;
+pub const HEADER_LEN: usize = 52;
+pub const TAG_LEN: usize = 32;
+
+/// Why an envelope was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "reason", rename_all = "snake_case")]
+pub enum WireError {
+    Truncated {
+        needed: usize,
+        available: usize,
+    },
+    BadMagic,
+    UnsupportedVersion {
+        version: u16,
+    },
+    UnknownParameterSet {
+        code: u8,
+    },
+    InvalidLevel {
+        level: u8,
+        max_level: u8,
+    },
+    InvalidScale,
+    PayloadLength {
+        expected: usize,
+        actual: usize,
+    },
+    TrailingBytes {
+        count: usize,
+    },
+    IntegrityMismatch,
+    /// Envelope is for a different parameter set than the decoding context
+    ParameterMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    UnreducedCoefficient {
+        residue: usize,
+        index: usize,
+    },
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated { needed, available } => {
+                write!(f, "truncated: need {} bytes, have {}", needed, available)
+            }
+            Self::BadMagic => write!(f, "bad magic"),
+            Self::UnsupportedVersion { version } => write!(f, "unsupported version {}", version),
+            Self::UnknownParameterSet { code } => write!(f, "unknown parameter set {}", code),
+            Self::InvalidLevel { level, max_level } => {
+                write!(f, "level {} exceeds maximum {}", level, max_level)
+            }
+            Self::InvalidScale => write!(f, "scale is not finite and at least 1"),
+            Self::PayloadLength { expected, actual } => {
+                write!(f, "payload of {} bytes, expected {}", actual, expected)
+            }
+            Self::TrailingBytes { count } => write!(f, "{} trailing bytes", count),
+            Self::IntegrityMismatch => write!(f, "integrity tag mismatch"),
+            Self::ParameterMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "parameter set {} where {} was expected",
+                    actual, expected
+                )
+            }
+            Self::UnreducedCoefficient { residue, index } => {
+                write!(
+                    f,
+                    "coefficient {} of residue {} is not reduced",
+                    index, residue
+                )
+            }
+        }
+    }
+}
+
+/// Parsed view of an envelope, borrowing its payload
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WireEnvelope<'a> {
+    pub version: u16,
+    pub parameter_set: ParameterSet,
+    pub level: u8,
+    pub scale: f64,
+    pub key_id: &'a [u8; 32],
+    pub payload: &'a [u8],
+    pub tag: &'a [u8; TAG_LEN],
+}
+
+fn array<const N: usize>(bytes: &[u8], at: usize) -> &[u8; N] {
+    bytes[at..at + N].try_into().expect("length checked")
+}
+
+fn payload_len(set: ParameterSet, level: u8) -> usize {
+    2 * (level as usize + 1) * (1usize << set.log_degree()) * 8
+}
+
+fn digest(bytes: &[u8]) -> [u8; 32] {
+    tagged_digest(Domain::WireEnvelope, bytes)
+}
+
+impl<'a> WireEnvelope<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, WireError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(WireError::Truncated {
+                needed: HEADER_LEN,
+                available: bytes.len(),
+            });
+        }
+        if array::<4>(bytes, 0) != &WIRE_MAGIC {
+            return Err(WireError::BadMagic);
+        }
+        let version = u16::from_le_bytes(*array(bytes, 4));
+        if version != WIRE_VERSION {
+            return Err(WireError::UnsupportedVersion { version });
+        }
+        let code = bytes[6];
+        let parameter_set =
+            ParameterSet::from_code(code).ok_or(WireError::UnknownParameterSet { code })?;
+        let level = bytes[7];
+        let max_level = parameter_set.max_level() as u8;
+        if level > max_level {
+            return Err(WireError::InvalidLevel { level, max_level });
+        }
+        let scale = f64::from_le_bytes(*array(bytes, 8));
+        if !scale.is_finite() || scale < 1.0 {
+            return Err(WireError::InvalidScale);
+        }
+        let key_id = array::<32>(bytes, 16);
+        let declared = u32::from_le_bytes(*array(bytes, 48)) as usize;
+        let expected = payload_len(parameter_set, level);
+        if declared != expected {
+            return Err(WireError::PayloadLength {
+                expected,
+                actual: declared,
+            });
+        }
+        let total = HEADER_LEN + expected + TAG_LEN;
+        if bytes.len() < total {
+            return Err(WireError::Truncated {
+                needed: total,
+                available: bytes.len(),
+            });
+        }
+        if bytes.len() > total {
+            return Err(WireError::TrailingBytes {
+                count: bytes.len() - total,
+            });
+        }
+        let tag = array::<TAG_LEN>(bytes, HEADER_LEN + expected);
+        if &digest(&bytes[..HEADER_LEN + expected]) != tag {
+            return Err(WireError::IntegrityMismatch);
+        }
+        Ok(Self {
+            version,
+            parameter_set,
+            level,
+            scale,
+            key_id,
+            payload: &bytes[HEADER_LEN..HEADER_LEN + expected],
+            tag,
+        })
+    }
+
+    /// Encode a ciphertext; the context must use a named parameter set
+    pub fn seal(
+        context: &CkksContext,
+        ciphertext: &Ciphertext,
+        key_id: &[u8; 32],
+    ) -> Result<Vec<u8>> {
+        let set = context.params().parameter_set().ok_or_else(|| {
+            DeoxysError::crypto("wire seal", "only named parameter sets can be exchanged")
+        })?;
+        let level = ciphertext.level() as u8;
+        let len = payload_len(set, level);
+        let mut bytes = Vec::with_capacity(HEADER_LEN + len + TAG_LEN);
+        bytes.extend_from_slice(&WIRE_MAGIC);
+        bytes.extend_from_slice(&WIRE_VERSION.to_le_bytes());
+        bytes.push(set.code());
+        bytes.push(level);
+        bytes.extend_from_slice(&ciphertext.scale().to_le_bytes());
+        bytes.extend_from_slice(key_id);
+        bytes.extend_from_slice(&(len as u32).to_le_bytes());
+        for residue in ciphertext.c0.iter().chain(&ciphertext.c1) {
+            for &c in residue {
+                bytes.extend_from_slice(&c.to_le_bytes());
+            }
+        }
+        let tag = digest(&bytes);
+        bytes.extend_from_slice(&tag);
+        Ok(bytes)
+    }
+
+    /// Decode the payload against a context for the same parameter set
+    pub fn ciphertext(&self, context: &CkksContext) -> Result<Ciphertext, WireError> {
+        let expected = context.params().parameter_set().map_or(0, |set| set.code());
+        if expected != self.parameter_set.code() {
+            return Err(WireError::ParameterMismatch {
+                expected,
+                actual: self.parameter_set.code(),
+            });
+        }
+        let n = context.params().degree();
+        let level = self.level as usize;
+        let mut words = self
+            .payload
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("8-byte chunk")));
+        let mut component = |offset: usize| -> Result<Vec<Vec<u64>>, WireError> {
+            (0..=level)
+                .map(|i| {
+                    let q = context.modulus(i);
+                    (0..n)
+                        .map(|index| {
+                            let c = words.next().expect("payload length checked");
+                            if c >= q {
+                                return Err(WireError::UnreducedCoefficient {
+                                    residue: offset + i,
+                                    index,
+                                });
+                            }
+                            Ok(c)
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+        let c0 = component(0)?;
+        let c1 = component(level + 1)?;
+        Ok(Ciphertext {
+            c0,
+            c1,
+            level,
+            scale: self.scale,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ckks::{CkksParameters, KeyGenerator};
+    use rand::{Rng, SeedableRng};
+
+    fn sealed() -> (CkksContext, Vec<u8>, Ciphertext) {
+        let context = CkksContext::new(CkksParameters::default());
+        let mut keygen = KeyGenerator::new(&context);
+        let secret = keygen.secret_key();
+        let public = keygen.public_key(&secret);
+        let ct = context.encrypt(
+            &context.encode(&[0.5, -0.25], 1, 2f64.powi(40)).unwrap(),
+            &public,
+        );
+        let bytes = WireEnvelope::seal(&context, &ct, &[7; 32]).unwrap();
+        (context, bytes, ct)
+    }
+
+    #[test]
+    fn test_seal_parse_roundtrip() {
+        let (context, bytes, ct) = sealed();
+        assert_eq!(bytes.len(), HEADER_LEN + 2 * 2 * 8192 * 8 + TAG_LEN);
+        let envelope = WireEnvelope::parse(&bytes).unwrap();
+        assert_eq!(envelope.parameter_set, ParameterSet::N8192Depth2Bits128);
+        assert_eq!((envelope.level, envelope.scale), (1, 2f64.powi(40)));
+        assert_eq!(envelope.key_id, &[7; 32]);
+        // Zero-copy: the payload points into the input buffer
+        assert_eq!(envelope.payload.as_ptr(), bytes[HEADER_LEN..].as_ptr());
+        assert_eq!(envelope.ciphertext(&context).unwrap(), ct);
+
+        let custom = CkksContext::new(CkksParameters::generate(6, &[50, 30], 50, 30).unwrap());
+        assert_eq!(
+            envelope.ciphertext(&custom),
+            Err(WireError::ParameterMismatch {
+                expected: 0,
+                actual: 1
+            })
+        );
+        assert!(WireEnvelope::seal(&custom, &ct, &[0; 32]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_malformed_headers() {
+        let (_, bytes, _) = sealed();
+        let with = |at: usize, value: u8| {
+            let mut b = bytes.clone();
+            b[at] = value;
+            WireEnvelope::parse(&b).unwrap_err()
+        };
+        assert_eq!(with(0, b'X'), WireError::BadMagic);
+        assert_eq!(with(4, 1), WireError::UnsupportedVersion { version: 1 });
+        assert_eq!(with(6, 0), WireError::UnknownParameterSet { code: 0 });
+        assert_eq!(
+            with(7, 3),
+            WireError::InvalidLevel {
+                level: 3,
+                max_level: 2
+            }
+        );
+        assert_eq!(with(15, 0xff), WireError::InvalidScale);
+        // Level 0 implies a shorter payload than declared
+        assert!(matches!(with(7, 0), WireError::PayloadLength { .. }));
+        assert!(matches!(with(50, 0), WireError::PayloadLength { .. }));
+        assert_eq!(with(20, 0), WireError::IntegrityMismatch);
+        assert_eq!(with(HEADER_LEN + 9, 0xaa), WireError::IntegrityMismatch);
+
+        assert_eq!(
+            WireEnvelope::parse(&bytes[..10]),
+            Err(WireError::Truncated {
+                needed: HEADER_LEN,
+                available: 10
+            })
+        );
+        assert!(matches!(
+            WireEnvelope::parse(&bytes[..bytes.len() - 1]),
+            Err(WireError::Truncated { .. })
+        ));
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(
+            WireEnvelope::parse(&extended),
+            Err(WireError::TrailingBytes { count: 1 })
+        );
+
+        let error: DeoxysError = WireError::BadMagic.into();
+        assert_eq!(error.code(), "malformed_wire");
+        assert_eq!(
+            serde_json::to_value(&error).unwrap()["error"]["reason"],
+            "bad_magic"
+        );
+    }
+
+    #[test]
+    fn test_unreduced_payload_with_valid_tag() {
+        let (context, mut bytes, _) = sealed();
+        let at = HEADER_LEN + 8 * 8192 + 3 * 8;
+        bytes[at..at + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+        let end = bytes.len() - TAG_LEN;
+        let tag = digest(&bytes[..end]);
+        bytes[end..].copy_from_slice(&tag);
+        let envelope = WireEnvelope::parse(&bytes).unwrap();
+        assert_eq!(
+            envelope.ciphertext(&context),
+            Err(WireError::UnreducedCoefficient {
+                residue: 1,
+                index: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_random_input_never_panics() {
+        let (_, bytes, _) = sealed();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(40);
+        for _ in 0..2000 {
+            let len = rng.gen_range(0..HEADER_LEN + 64);
+            let mut noise: Vec<u8> = (0..len).map(|_| rng.gen()).collect();
+            if rng.gen_bool(0.5) && len >= 8 {
+                // Keep a plausible prefix so parsing reaches later checks
+                noise[..8].copy_from_slice(&bytes[..8]);
+            }
+            assert!(WireEnvelope::parse(&noise).is_err());
+        }
+        for _ in 0..20 {
+            let mut mutated = bytes.clone();
+            let at = rng.gen_range(0..mutated.len());
+            mutated[at] ^= 1 << rng.gen_range(0..8);
+            assert!(WireEnvelope::parse(&mutated).is_err());
+        }
+    }
+}